@@ -0,0 +1,107 @@
+//! RPC for checking that a request was signed by some member of an on-chain ring.
+//!
+//! Off-chain services hand out a nonce, the client signs
+//! `membership_message(ring_id, domain, nonce, message)` with its ring key, and the service
+//! asks the node whether the signature verifies against the ring stored in `RingGroups`.
+//! The service learns a key image scoped to its `domain`. It identifies the signer across
+//! requests to the same service, but not who the signer is, and it cannot be linked to the
+//! signer's ballots, petitions, reports or logins to other services.
+
+#![allow(clippy::too_many_arguments)]
+
+use std::{marker::PhantomData, sync::Arc};
+
+use jsonrpsee::{
+    core::RpcResult,
+    proc_macros::rpc,
+    types::error::{ErrorObject, ErrorObjectOwned},
+};
+use polkadot_sdk::*;
+use ring_sig_voting::{runtime_api::RingMembershipApi as RingMembershipRuntimeApi, RingId};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::{Bytes, H256};
+use sp_runtime::traits::Block as BlockT;
+
+/// Error code returned when the runtime API call fails.
+const RUNTIME_ERROR: i32 = 1;
+
+/// Ring membership RPC methods.
+#[rpc(server)]
+pub trait RingMembershipApi<BlockHash> {
+    /// Verify a domain-scoped ring signature over the membership message.
+    ///
+    /// `pallet_index` is the index of the voting instance holding the ring. Returns the signer's
+    /// key image for `domain` if the signature is valid, `None` otherwise.
+    #[method(name = "ringSigVoting_verifyMembership")]
+    fn verify_membership(
+        &self,
+        pallet_index: u8,
+        ring_id: RingId,
+        domain: Bytes,
+        nonce: Bytes,
+        message: Bytes,
+        challenge: H256,
+        responses: Vec<H256>,
+        key_image: H256,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<H256>>;
+}
+
+/// Provides RPC methods to verify ring membership.
+pub struct RingMembership<C, Block> {
+    client: Arc<C>,
+    _marker: PhantomData<Block>,
+}
+
+impl<C, Block> RingMembership<C, Block> {
+    /// Create new `RingMembership` with the given reference to the client.
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+impl<C, Block> RingMembershipApiServer<<Block as BlockT>::Hash> for RingMembership<C, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: RingMembershipRuntimeApi<Block>,
+{
+    fn verify_membership(
+        &self,
+        pallet_index: u8,
+        ring_id: RingId,
+        domain: Bytes,
+        nonce: Bytes,
+        message: Bytes,
+        challenge: H256,
+        responses: Vec<H256>,
+        key_image: H256,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Option<H256>> {
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.verify_membership(
+            at_hash,
+            pallet_index,
+            ring_id,
+            domain.to_vec(),
+            nonce.to_vec(),
+            message.to_vec(),
+            challenge,
+            responses,
+            key_image,
+        )
+        .map_err(|e| -> ErrorObjectOwned {
+            ErrorObject::owned(
+                RUNTIME_ERROR,
+                "Unable to verify ring membership.",
+                Some(e.to_string()),
+            )
+        })
+    }
+}
@@ -3,18 +3,60 @@ use frame::{deps::frame_benchmarking::v2::*, prelude::*};
 use scale_info::prelude::vec;
 use crate::types::simple_voting::*;
 
-#[benchmarks(
-    where
-        T: pallet::Config<
-            Vote = Vote,
-            Tally = Tally
-        >
-)]
+/// 生成一张能被计入的选票。计票和公开选票都不检查签名，签名只需结构正确
+fn gen_ballot<T: Config<I>, I: 'static>(
+    ring: &BoundedVec<BoundedVec<H256, T::NumRingLayers>, T::MaxMembersInRing>,
+    encryption_pubkey: &H256,
+) -> EncryptedVote<T, I> {
+    use curve25519_dalek::scalar::Scalar;
+    use rand_core::OsRng;
+
+    let sealed = crate::ecies::encrypt_ballot(
+        &encryption_pubkey.0,
+        &Scalar::random(&mut OsRng).to_bytes(),
+        &T::BenchmarkHelper::vote().encode(),
+        T::MaxVoteSize::get() as usize,
+    )
+    .unwrap();
+    EncryptedVote::<T, I> {
+        ephemeral_public_key: sealed.ephemeral_public_key,
+        ciphertext: sealed.ciphertext.try_into().unwrap(),
+        auth_tag: sealed.auth_tag,
+        ring_signature: CLSAGWrapper {
+            challenge: ScalarWrapper([0u8; 32]),
+            responses: BoundedVec::truncate_from(vec![ScalarWrapper([0u8; 32]); ring.len()]),
+            ring: ring
+                .iter()
+                .map(|row| {
+                    BoundedVec::truncate_from(
+                        row.iter().map(|key| CompressedRistrettoWrapper(key.0)).collect(),
+                    )
+                })
+                .collect::<Vec<_>>()
+                .try_into()
+                .unwrap(),
+            key_images: BoundedVec::truncate_from(vec![
+                CompressedRistrettoWrapper([0u8; 32]);
+                T::NumRingLayers::get() as usize
+            ]),
+        },
+    }
+}
+
+/// 生成一个以 Preimage 哈希引用的调用，超出内联长度的调用需要锁定 Preimage
+fn gen_proposal<T: Config<I>, I: 'static>() -> BoundedCallOf<T> {
+    use frame::deps::frame_support::traits::StorePreimage;
+
+    let call: CallOf<T> = frame_system::Call::<T>::remark { remark: vec![0u8; 256] }.into();
+    T::Preimages::bound(call).unwrap()
+}
+
+#[instance_benchmarks]
 mod benchmarks {
     use super::*;
     use crate::pallet::Pallet as RingSigVoting;
     use crate::{mock::*};
-    use frame::deps::frame_support::traits::Currency;
+    use frame::deps::frame_support::traits::{Currency, StorePreimage};
     use frame_system::RawOrigin;
     use curve25519_dalek::{constants::RISTRETTO_BASEPOINT_POINT, scalar::Scalar};
     use rand_core::OsRng;
@@ -25,12 +67,12 @@ mod benchmarks {
     #[benchmark]
     fn register_ring_group() {
         let caller: T::AccountId = whitelisted_caller();
-        let ring = gen_ring::<T>();
+        let ring = gen_ring::<T, I>();
 
         #[extrinsic_call]
-        RingSigVoting::register_ring_group(RawOrigin::Signed(caller), ring);
+        _(RawOrigin::Signed(caller), ring);
 
-        assert!(RingGroups::<T>::get(0).is_some());
+        assert!(RingGroups::<T, I>::get(0).is_some());
     }
 
     #[benchmark]
@@ -39,7 +81,7 @@ mod benchmarks {
         let poll_id = 0;
         let description = b"Poll 0".to_vec();
         let ring_id = 0;
-        let ring = gen_ring::<T>();
+        let ring = gen_ring::<T, I>();
 
         // 生成加密密钥对
         let mut csprng = OsRng;
@@ -50,21 +92,24 @@ mod benchmarks {
         let balance = T::Currency::minimum_balance() * BIG_ENOUGH. into();
         T::Currency::make_free_balance_be(&caller, balance);
 
-        RingSigVoting::<T>::register_ring_group(RawOrigin::Signed(caller. clone()). into(), ring)
+        RingSigVoting::<T, I>::register_ring_group(RawOrigin::Signed(caller. clone()). into(), ring)
             .unwrap();
-        assert!(RingGroups::<T>::get(ring_id).is_some());
+        assert!(RingGroups::<T, I>::get(ring_id).is_some());
 
         #[extrinsic_call]
-        RingSigVoting::create_poll(
+        _(
             RawOrigin::Signed(caller),
             description.clone().try_into().unwrap(),
             ring_id,
             None,
             None,
+            None,
             encryption_pubkey,
+            gen_poll_config(),
+            None,
         );
 
-        let poll = Polls::<T>::get(poll_id).unwrap();
+        let poll = Polls::<T, I>::get(poll_id).unwrap();
         assert_eq!(poll.description. into_inner(), description);
         assert_eq!(poll.status, PollStatus::Voting);
     }
@@ -75,7 +120,7 @@ mod benchmarks {
         let poll_id = 0;
         let description = b"Poll 0".to_vec();
         let ring_id = 0;
-        let ring = gen_ring::<T>();
+        let ring = gen_ring::<T, I>();
 
         // 生成真实的密钥对
         let mut csprng = OsRng;
@@ -84,31 +129,38 @@ mod benchmarks {
         
         let encryption_privkey: H256 = private_key_scalar.to_bytes().into();
         let encryption_pubkey: H256 = public_key_point.compress().to_bytes().into();
-        let tally = (5u32, 3u32);
+        // 没有选票，运行时要求最少投票数时不能提交私钥
+        let encryption_privkey = match T::MinBallotsForBreakdown::get() {
+            0 => Some(encryption_privkey),
+            _ => None,
+        };
 
         let balance = T::Currency::minimum_balance() * BIG_ENOUGH. into();
         T::Currency::make_free_balance_be(&caller, balance);
 
-        RingSigVoting::<T>::register_ring_group(RawOrigin::Signed(caller.clone()).into(), ring)
+        RingSigVoting::<T, I>::register_ring_group(RawOrigin::Signed(caller.clone()).into(), ring)
             . unwrap();
-        assert!(RingGroups::<T>::get(ring_id).is_some());
+        assert!(RingGroups::<T, I>::get(ring_id).is_some());
 
-        RingSigVoting::<T>::create_poll(
+        RingSigVoting::<T, I>::create_poll(
             RawOrigin::Signed(caller. clone()). into(),
             description.clone(). try_into().unwrap(),
             ring_id,
             None,
             None,
+            None,
             encryption_pubkey,
+            gen_poll_config(),
+            None,
         )
         .unwrap();
-        let poll = Polls::<T>::get(poll_id).unwrap();
+        let poll = Polls::<T, I>::get(poll_id).unwrap();
         assert_eq!(poll.status, PollStatus::Voting);
 
         #[extrinsic_call]
-        RingSigVoting::close_poll(RawOrigin::Root, poll_id, encryption_privkey, tally);
-        
-        assert_eq!(Polls::<T>::get(poll_id).unwrap().status, PollStatus::Closed);
+        _(RawOrigin::Root, poll_id, encryption_privkey);
+
+        assert_ne!(Polls::<T, I>::get(poll_id).unwrap().status, PollStatus::Voting);
     }
 
     #[benchmark]
@@ -126,12 +178,12 @@ mod benchmarks {
         
         // 模拟加密数据
         let ephemeral_pubkey: H256 = [2u8; 32].into();
-        let ciphertext: BoundedVec<u8, <T as crate::Config>::MaxVoteSize> = 
-            vec![1, 2, 3, 4].try_into().unwrap();
+        let ciphertext: BoundedVec<u8, <T as crate::Config<I>>::MaxVoteSize> = 
+            vec![1; T::MaxVoteSize::get() as usize].try_into().unwrap();
         let auth_tag: H128 = [0u8; 16].into();
         
         let vote = Vote::Yea;
-        let (challenge, responses, ring, key_images) = gen_signature_for_encrypted::<T>(
+        let (challenge, responses, ring, key_images) = gen_signature_for_encrypted::<T, I>(
             poll_id,
             vote,
             ephemeral_pubkey.0,
@@ -142,24 +194,27 @@ mod benchmarks {
         let balance = T::Currency::minimum_balance() * BIG_ENOUGH.into();
         T::Currency::make_free_balance_be(&caller, balance);
 
-        RingSigVoting::<T>::register_ring_group(RawOrigin::Signed(caller. clone()).into(), ring. clone())
+        RingSigVoting::<T, I>::register_ring_group(RawOrigin::Signed(caller. clone()).into(), ring. clone())
             .unwrap();
-        assert!(RingGroups::<T>::get(ring_id).is_some());
+        assert!(RingGroups::<T, I>::get(ring_id).is_some());
 
-        RingSigVoting::<T>::create_poll(
+        RingSigVoting::<T, I>::create_poll(
             RawOrigin::Signed(caller.clone()).into(),
             description.clone().try_into().unwrap(),
             ring_id,
             None,
             None,
+            None,
             encryption_pubkey,
+            gen_poll_config(),
+            None,
         )
         .unwrap();
-        let poll = Polls::<T>::get(poll_id).unwrap();
+        let poll = Polls::<T, I>::get(poll_id).unwrap();
         assert_eq!(poll.status, PollStatus::Voting);
 
         #[extrinsic_call]
-        RingSigVoting::anonymous_vote(
+        _(
             RawOrigin::Signed(caller),
             poll_id,
             ephemeral_pubkey,
@@ -170,9 +225,428 @@ mod benchmarks {
             key_images,
         );
 
-        let encrypted_votes = EncryptedVotes::<T>::get(poll_id);
-        assert_eq!(encrypted_votes.len(), 1);
+        assert_eq!(BallotCounts::<T, I>::get(poll_id), 1);
     }
+
+    #[benchmark]
+    fn publish_comments() {
+        let caller: T::AccountId = whitelisted_caller();
+        let poll_id = 0;
+        let description = b"Poll 0".to_vec();
+        let ring_id = 0;
+        let ring = gen_ring::<T, I>();
+
+        let mut csprng = OsRng;
+        let private_key_scalar = Scalar::random(&mut csprng);
+        let public_key_point = private_key_scalar * RISTRETTO_BASEPOINT_POINT;
+        let encryption_privkey: H256 = private_key_scalar.to_bytes().into();
+        let encryption_pubkey: H256 = public_key_point.compress().to_bytes().into();
+
+        let balance = T::Currency::minimum_balance() * BIG_ENOUGH.into();
+        T::Currency::make_free_balance_be(&caller, balance);
+
+        RingSigVoting::<T, I>::register_ring_group(
+            RawOrigin::Signed(caller.clone()).into(),
+            ring.clone(),
+        )
+        .unwrap();
+        RingSigVoting::<T, I>::create_poll(
+            RawOrigin::Signed(caller.clone()).into(),
+            description.try_into().unwrap(),
+            ring_id,
+            None,
+            None,
+            None,
+            encryption_pubkey,
+            gen_poll_config(),
+            None,
+        )
+        .unwrap();
+
+        // 选票数达到 `MinBallotsForBreakdown`，评论才能公开
+        let ballots = T::MinBallotsForBreakdown::get().max(1);
+        let ballot = gen_ballot::<T, I>(&ring, &encryption_pubkey);
+        for index in 0..ballots {
+            EncryptedVotes::<T, I>::insert(poll_id, index, ballot.clone());
+        }
+        BallotCounts::<T, I>::insert(poll_id, ballots);
+        RingSigVoting::<T, I>::close_poll(RawOrigin::Root.into(), poll_id, Some(encryption_privkey))
+            .unwrap();
+        RingSigVoting::<T, I>::tally_batch(RawOrigin::Signed(caller).into(), poll_id, ballots)
+            .unwrap();
+
+        let comments_hash = T::Preimages::note(vec![0u8; 1024].into()).unwrap();
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, poll_id, comments_hash);
+
+        assert_eq!(PollComments::<T, I>::get(poll_id), Some(comments_hash));
+    }
+
+    // 最后一批：解密并计入 n 张选票，然后计算最终结果并关闭投票
+    #[benchmark]
+    fn tally_batch(n: Linear<1, { T::MaxVotesPerPoll::get() }>) {
+        let caller: T::AccountId = whitelisted_caller();
+        let poll_id = 0;
+        let description = b"Poll 0".to_vec();
+        let ring_id = 0;
+        let ring = gen_ring::<T, I>();
+
+        let mut csprng = OsRng;
+        let private_key_scalar = Scalar::random(&mut csprng);
+        let public_key_point = private_key_scalar * RISTRETTO_BASEPOINT_POINT;
+        let encryption_privkey: H256 = private_key_scalar.to_bytes().into();
+        let encryption_pubkey: H256 = public_key_point.compress().to_bytes().into();
+
+        let balance = T::Currency::minimum_balance() * BIG_ENOUGH.into();
+        T::Currency::make_free_balance_be(&caller, balance);
+
+        RingSigVoting::<T, I>::register_ring_group(
+            RawOrigin::Signed(caller.clone()).into(),
+            ring.clone(),
+        )
+        .unwrap();
+        RingSigVoting::<T, I>::create_poll(
+            RawOrigin::Signed(caller.clone()).into(),
+            description.try_into().unwrap(),
+            ring_id,
+            None,
+            None,
+            None,
+            encryption_pubkey,
+            gen_poll_config(),
+            None,
+        )
+        .unwrap();
+
+        // 计票不再检查签名，直接写入 n 张选票
+        let ballot = gen_ballot::<T, I>(&ring, &encryption_pubkey);
+        for index in 0..n {
+            EncryptedVotes::<T, I>::insert(poll_id, index, ballot.clone());
+        }
+        BallotCounts::<T, I>::insert(poll_id, n);
+        // 直接进入计票阶段，不受 `MinBallotsForBreakdown` 影响
+        Polls::<T, I>::mutate(poll_id, |poll| {
+            let poll = poll.as_mut().unwrap();
+            poll.encryption_private_key = Some(encryption_privkey.0);
+            poll.status = PollStatus::Tallying;
+        });
+        TallyCursors::<T, I>::insert(poll_id, 0);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), poll_id, n);
+
+        assert_eq!(Polls::<T, I>::get(poll_id).unwrap().status, PollStatus::Closed);
+    }
+
+    #[benchmark]
+    fn create_topic() {
+        let caller: T::AccountId = whitelisted_caller();
+        let ring_id = 0;
+        let ring = gen_ring::<T, I>();
+
+        RingSigVoting::<T, I>::register_ring_group(RawOrigin::Signed(caller.clone()).into(), ring)
+            .unwrap();
+        let rate_limit = RateLimit {
+            posts: 1,
+            period: 10u32.into(),
+        };
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), ring_id, Some(rate_limit));
+
+        assert!(Topics::<T, I>::get(0).is_some());
+    }
+
+    // 最长的直接上链留言，话题有发帖频率限制。签名验证的开销与环的大小 `r` 成正比
+    #[benchmark]
+    fn post_message(r: Linear<2, { T::MaxMembersInRing::get() }>) {
+        let caller: T::AccountId = whitelisted_caller();
+        let topic_id = 0;
+        let ring_id = 0;
+
+        let content = MessageContent::<T, I>::Text(
+            vec![b'?'; T::MaxMessageLength::get() as usize].try_into().unwrap(),
+        );
+        let (ks, decoys) = gen_signer_and_decoys_of_size::<T, I>(r);
+        let (ring, challenge, responses, key_image) = sign_topic_message::<T, I>(
+            &ks,
+            &decoys,
+            &RingSigVoting::<T, I>::topic_scope(topic_id),
+            &content.encode(),
+        );
+
+        RingSigVoting::<T, I>::register_ring_group(RawOrigin::Signed(caller.clone()).into(), ring)
+            .unwrap();
+        RingSigVoting::<T, I>::create_topic(
+            RawOrigin::Signed(caller.clone()).into(),
+            ring_id,
+            Some(RateLimit {
+                posts: 1,
+                period: 10u32.into(),
+            }),
+        )
+        .unwrap();
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(caller),
+            topic_id,
+            content,
+            challenge,
+            responses,
+            key_image,
+        );
+
+        assert_eq!(Topics::<T, I>::get(topic_id).unwrap().posts, 1);
+    }
+
+    // 环取最大成员数：检查门槛时需要读取整个环，调用者无法事先给出环的大小。
+    // 附带的调用以 Preimage 哈希引用，需要锁定 Preimage
+    #[benchmark]
+    fn create_petition() -> Result<(), BenchmarkError> {
+        let origin =
+            T::ProposalOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let caller = T::ProposalOrigin::ensure_origin(origin.clone())
+            .map_err(|_| BenchmarkError::Weightless)?;
+        let description = b"Petition 0".to_vec();
+        let ring_id = 0;
+        let ring = gen_ring::<T, I>();
+
+        RingSigVoting::<T, I>::register_ring_group(RawOrigin::Signed(caller).into(), ring)
+            .unwrap();
+        let proposal = gen_proposal::<T, I>();
+
+        #[extrinsic_call]
+        _(
+            origin as T::RuntimeOrigin,
+            description.try_into().unwrap(),
+            ring_id,
+            1,
+            Some(proposal),
+        );
+
+        assert!(Petitions::<T, I>::get(0).is_some());
+        Ok(())
+    }
+
+    // 第一个签名即达到门槛，并把附带的调用交给调度器。签名验证的开销与环的大小 `r` 成正比
+    #[benchmark]
+    fn sign_petition(r: Linear<2, { T::MaxMembersInRing::get() }>) -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        let origin =
+            T::ProposalOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let petition_id = 0;
+        let description = b"Petition 0".to_vec();
+        let ring_id = 0;
+
+        let (ks, decoys) = gen_signer_and_decoys_of_size::<T, I>(r);
+        let (challenge, responses, ring, key_images) = sign_message::<T, I>(
+            ks,
+            decoys,
+            &RingSigVoting::<T, I>::petition_message(petition_id),
+        );
+
+        RingSigVoting::<T, I>::register_ring_group(RawOrigin::Signed(caller.clone()).into(), ring)
+            .unwrap();
+        let proposal = gen_proposal::<T, I>();
+        RingSigVoting::<T, I>::create_petition(
+            origin,
+            description.try_into().unwrap(),
+            ring_id,
+            1,
+            Some(proposal),
+        )
+        .unwrap();
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(caller),
+            petition_id,
+            challenge,
+            responses,
+            key_images,
+        );
+
+        assert!(Petitions::<T, I>::get(petition_id).unwrap().reached_at.is_some());
+        Ok(())
+    }
+
+    #[benchmark]
+    fn set_committee_key() {
+        let committee_key: H256 = (Scalar::random(&mut OsRng) * RISTRETTO_BASEPOINT_POINT)
+            .compress()
+            .to_bytes()
+            .into();
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, committee_key);
+
+        assert_eq!(CommitteeKey::<T, I>::get(), Some(committee_key.0));
+    }
+
+    // 最长的举报，提交后该成员未回执的举报数达到上限。签名验证的开销与环的大小 `r` 成正比
+    #[benchmark]
+    fn submit_report(r: Linear<2, { T::MaxMembersInRing::get() }>) {
+        let caller: T::AccountId = whitelisted_caller();
+        let ring_id = 0;
+
+        let committee_key = (Scalar::random(&mut OsRng) * RISTRETTO_BASEPOINT_POINT)
+            .compress()
+            .to_bytes();
+        let reply_public_key: H256 = (Scalar::random(&mut OsRng) * RISTRETTO_BASEPOINT_POINT)
+            .compress()
+            .to_bytes()
+            .into();
+        let content = EncryptedMessage::<T, I> {
+            ephemeral_public_key: [2u8; 32],
+            ciphertext: vec![1; T::MaxReportSize::get() as usize].try_into().unwrap(),
+            auth_tag: [0u8; 16],
+        };
+        let (ks, decoys) = gen_signer_and_decoys_of_size::<T, I>(r);
+        let (ring, challenge, responses, key_image) = sign_topic_message::<T, I>(
+            &ks,
+            &decoys,
+            &RingSigVoting::<T, I>::report_scope(ring_id),
+            &RingSigVoting::<T, I>::report_message(
+                ring_id,
+                &committee_key,
+                &content,
+                &reply_public_key.0,
+            ),
+        );
+
+        RingSigVoting::<T, I>::register_ring_group(RawOrigin::Signed(caller.clone()).into(), ring)
+            .unwrap();
+        CommitteeKey::<T, I>::put(committee_key);
+        let open_key = CompressedRistrettoWrapper(key_image.0);
+        OpenReports::<T, I>::insert(&open_key, T::MaxOpenReports::get() - 1);
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(caller),
+            ring_id,
+            content,
+            reply_public_key,
+            challenge,
+            responses,
+            key_image,
+        );
+
+        assert!(Reports::<T, I>::get(0).is_some());
+        assert_eq!(OpenReports::<T, I>::get(&open_key), T::MaxOpenReports::get());
+    }
+
+    #[benchmark]
+    fn acknowledge_report() {
+        let report_id = 0;
+        let key_image = CompressedRistrettoWrapper([3u8; 32]);
+        let message = || EncryptedMessage::<T, I> {
+            ephemeral_public_key: [2u8; 32],
+            ciphertext: vec![1; T::MaxReportSize::get() as usize].try_into().unwrap(),
+            auth_tag: [0u8; 16],
+        };
+
+        // 回执不检查签名，直接写入一条举报
+        Reports::<T, I>::insert(
+            report_id,
+            Report {
+                ring_id: 0,
+                key_image: key_image.clone(),
+                committee_key: [4u8; 32],
+                content: message(),
+                reply_public_key: [5u8; 32],
+                submitted_at: 1u32.into(),
+            },
+        );
+        OpenReports::<T, I>::insert(&key_image, 2);
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, report_id, message());
+
+        assert!(Reports::<T, I>::get(report_id).is_none());
+        assert_eq!(OpenReports::<T, I>::get(&key_image), 1);
+    }
+
+    // 升级前仍在进行、已收到 n 张旧选票的投票：迁移全部旧选票后按旧的方式关闭
+    #[benchmark]
+    fn close_legacy_poll(n: Linear<0, { T::MaxVotesPerPoll::get() }>) {
+        let caller: T::AccountId = whitelisted_caller();
+        let poll_id = 0;
+        let ring = gen_ring::<T, I>();
+
+        let private_key_scalar = Scalar::random(&mut OsRng);
+        let encryption_privkey: H256 = private_key_scalar.to_bytes().into();
+        let encryption_pubkey: H256 = (private_key_scalar * RISTRETTO_BASEPOINT_POINT)
+            .compress()
+            .to_bytes()
+            .into();
+
+        let balance = T::Currency::minimum_balance() * BIG_ENOUGH.into();
+        T::Currency::make_free_balance_be(&caller, balance);
+
+        RingSigVoting::<T, I>::register_ring_group(
+            RawOrigin::Signed(caller.clone()).into(),
+            ring.clone(),
+        )
+        .unwrap();
+        RingSigVoting::<T, I>::create_poll(
+            RawOrigin::Signed(caller).into(),
+            b"Poll 0".to_vec().try_into().unwrap(),
+            0,
+            None,
+            None,
+            None,
+            encryption_pubkey,
+            gen_poll_config(),
+            None,
+        )
+        .unwrap();
+
+        // 旧选票按投票整体存放
+        let ballot = gen_ballot::<T, I>(&ring, &encryption_pubkey);
+        crate::migrations::v0::EncryptedVotes::<T, I>::insert(
+            poll_id,
+            BoundedVec::truncate_from(vec![ballot; n as usize]),
+        );
+        BallotCounts::<T, I>::insert(poll_id, n);
+        LegacyPolls::<T, I>::insert(poll_id, ());
+        let tally = T::TallyHandler::new_tally(&gen_poll_config()).unwrap();
+        // 选票数不足 `MinBallotsForBreakdown` 时不能公开私钥
+        let encryption_privkey =
+            (n >= T::MinBallotsForBreakdown::get()).then_some(encryption_privkey);
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, poll_id, encryption_privkey, tally);
+
+        assert_eq!(Polls::<T, I>::get(poll_id).unwrap().status, PollStatus::Closed);
+        assert_eq!(EncryptedVotes::<T, I>::iter_prefix(poll_id).count() as u32, n);
+    }
+
+    // 升级前已关闭、有 n 张旧选票的投票
+    #[benchmark]
+    fn migrate_legacy_ballots(n: Linear<1, { T::MaxVotesPerPoll::get() }>) {
+        let caller: T::AccountId = whitelisted_caller();
+        let poll_id = 0;
+        let ring = gen_ring::<T, I>();
+        let encryption_pubkey: H256 = (Scalar::random(&mut OsRng) * RISTRETTO_BASEPOINT_POINT)
+            .compress()
+            .to_bytes()
+            .into();
+
+        let ballot = gen_ballot::<T, I>(&ring, &encryption_pubkey);
+        crate::migrations::v0::EncryptedVotes::<T, I>::insert(
+            poll_id,
+            BoundedVec::truncate_from(vec![ballot; n as usize]),
+        );
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), poll_id);
+
+        assert_eq!(EncryptedVotes::<T, I>::iter_prefix(poll_id).count() as u32, n);
+    }
+
+    impl_benchmark_test_suite!(RingSigVoting, crate::mock::new_test_ext(), crate::mock::Test);
 }
 
 // #[benchmarks(
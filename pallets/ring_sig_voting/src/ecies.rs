@@ -0,0 +1,169 @@
+//! 选票加密方案（ECIES），客户端加密与链上解密共用
+//!
+//! - 密钥：投票的加密私钥 `x` 为 32 字节标量（按 `from_bytes_mod_order` 归约），公钥 `X = x·G`，
+//!   与 `close_poll` 校验私钥的方式一致
+//! - 密钥协商：客户端选一次性标量 `r`，临时公钥 `R = r·G`，共享点 `S = r·X = x·R`（Ristretto255）
+//! - 密钥派生：HKDF-SHA512，`salt = R ‖ X`，`ikm = S`，`info = DOMAIN`，输出 44 字节：
+//!   前 32 字节为对称密钥，后 12 字节为 nonce。每个临时公钥只用一次，nonce 不会重复
+//! - 对称加密：ChaCha20-Poly1305，无附加数据，认证标签单独存放在 `EncryptedVote::auth_tag`
+//! - 填充：选票加密前按 ISO/IEC 7816-4 填充到投票规定的固定长度（明文后接 `0x80`，再补 `0x00`），
+//!   同一投票的所有密文长度相同，密文长度不会泄露选项或评论长度
+//!
+//! 所有点均以 32 字节压缩 Ristretto 编码传输。测试向量见 `tests.rs` 中的 `ecies_test_vectors`。
+
+use crate::BallotCipher;
+use chacha20poly1305::{aead::AeadInPlace, ChaCha20Poly1305, Key, KeyInit, Nonce, Tag};
+use curve25519_dalek::{
+    constants::RISTRETTO_BASEPOINT_POINT,
+    ristretto::{CompressedRistretto, RistrettoPoint},
+    scalar::Scalar,
+    traits::IsIdentity,
+};
+use hkdf::Hkdf;
+use scale_info::prelude::vec::Vec;
+use sha2::Sha512;
+
+/// 密钥派生的域分隔标签
+pub const DOMAIN: &[u8] = b"ring-sig-voting/ecies/v1";
+
+/// 填充的起始标记
+const PADDING_MARKER: u8 = 0x80;
+
+/// 加密后的选票，各字段与 `EncryptedVote` 对应
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Sealed {
+    pub ephemeral_public_key: [u8; 32],
+    pub ciphertext: Vec<u8>,
+    pub auth_tag: [u8; 16],
+}
+
+/// 用投票的加密公钥加密选票。`ephemeral_secret` 必须是每张选票新生成的随机数，
+/// 公钥无效或标量为零时返回 `None`
+pub fn encrypt(
+    public_key: &[u8; 32],
+    ephemeral_secret: &[u8; 32],
+    plaintext: &[u8],
+) -> Option<Sealed> {
+    let public_point = decompress(public_key)?;
+    let secret = Scalar::from_bytes_mod_order(*ephemeral_secret);
+    if secret == Scalar::ZERO {
+        return None;
+    }
+    let ephemeral_public_key = (secret * RISTRETTO_BASEPOINT_POINT).compress().to_bytes();
+
+    let (cipher, nonce) = derive_cipher(secret * public_point, &ephemeral_public_key, public_key)?;
+    let mut ciphertext = plaintext.to_vec();
+    let tag = cipher
+        .encrypt_in_place_detached(&nonce, &[], &mut ciphertext)
+        .ok()?;
+
+    Some(Sealed {
+        ephemeral_public_key,
+        ciphertext,
+        auth_tag: tag.into(),
+    })
+}
+
+/// 用投票的加密私钥解密选票，临时公钥无效或认证失败时返回 `None`
+pub fn decrypt(
+    private_key: &[u8; 32],
+    ephemeral_public_key: &[u8; 32],
+    ciphertext: &[u8],
+    auth_tag: &[u8; 16],
+) -> Option<Vec<u8>> {
+    let ephemeral_point = decompress(ephemeral_public_key)?;
+    let secret = Scalar::from_bytes_mod_order(*private_key);
+    let public_key = (secret * RISTRETTO_BASEPOINT_POINT).compress().to_bytes();
+
+    let (cipher, nonce) =
+        derive_cipher(secret * ephemeral_point, ephemeral_public_key, &public_key)?;
+    let mut plaintext = ciphertext.to_vec();
+    cipher
+        .decrypt_in_place_detached(&nonce, &[], &mut plaintext, Tag::from_slice(auth_tag))
+        .ok()?;
+
+    Some(plaintext)
+}
+
+/// 把选票填充到 `length` 字节，至少需要 1 字节填充，选票过长时返回 `None`
+pub fn pad(ballot: &[u8], length: usize) -> Option<Vec<u8>> {
+    if ballot.len() >= length {
+        return None;
+    }
+    let mut padded = Vec::with_capacity(length);
+    padded.extend_from_slice(ballot);
+    padded.push(PADDING_MARKER);
+    padded.resize(length, 0);
+    Some(padded)
+}
+
+/// 去掉填充，填充格式不正确时返回 `None`
+pub fn unpad(padded: &[u8]) -> Option<&[u8]> {
+    let marker = padded.iter().rposition(|byte| *byte != 0)?;
+    (padded[marker] == PADDING_MARKER).then(|| &padded[..marker])
+}
+
+/// 填充并加密一张选票，密文长度恰好为 `length`
+pub fn encrypt_ballot(
+    public_key: &[u8; 32],
+    ephemeral_secret: &[u8; 32],
+    ballot: &[u8],
+    length: usize,
+) -> Option<Sealed> {
+    encrypt(public_key, ephemeral_secret, &pad(ballot, length)?)
+}
+
+/// 解密一张选票并去掉填充
+pub fn decrypt_ballot(
+    private_key: &[u8; 32],
+    ephemeral_public_key: &[u8; 32],
+    ciphertext: &[u8],
+    auth_tag: &[u8; 16],
+) -> Option<Vec<u8>> {
+    let padded = decrypt(private_key, ephemeral_public_key, ciphertext, auth_tag)?;
+    unpad(&padded).map(<[u8]>::to_vec)
+}
+
+/// 链上计票使用的加密方案
+pub struct Ecies;
+
+impl BallotCipher for Ecies {
+    fn decrypt(
+        private_key: &[u8; 32],
+        ephemeral_public_key: &[u8; 32],
+        ciphertext: &[u8],
+        auth_tag: &[u8; 16],
+    ) -> Option<Vec<u8>> {
+        decrypt_ballot(private_key, ephemeral_public_key, ciphertext, auth_tag)
+    }
+}
+
+/// 解码压缩点，拒绝无效编码和单位元
+fn decompress(bytes: &[u8; 32]) -> Option<RistrettoPoint> {
+    CompressedRistretto(*bytes)
+        .decompress()
+        .filter(|point| !point.is_identity())
+}
+
+/// 由共享点派生对称密钥和 nonce
+fn derive_cipher(
+    shared: RistrettoPoint,
+    ephemeral_public_key: &[u8; 32],
+    public_key: &[u8; 32],
+) -> Option<(ChaCha20Poly1305, Nonce)> {
+    if shared.is_identity() {
+        return None;
+    }
+
+    let mut salt = [0u8; 64];
+    salt[..32].copy_from_slice(ephemeral_public_key);
+    salt[32..].copy_from_slice(public_key);
+
+    let mut okm = [0u8; 44];
+    Hkdf::<Sha512>::new(Some(&salt), shared.compress().as_bytes())
+        .expand(DOMAIN, &mut okm)
+        .ok()?;
+
+    let cipher = ChaCha20Poly1305::new(Key::from_slice(&okm[..32]));
+    Some((cipher, *Nonce::from_slice(&okm[32..])))
+}
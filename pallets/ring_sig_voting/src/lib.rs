@@ -14,37 +14,117 @@ mod benchmarking;
 pub mod weights;
 use weights::WeightInfo;
 
+pub mod runtime_api;
+
+pub mod ecies;
+
+pub mod topic_sig;
+
+pub mod migrations;
+
 mod types;
 pub use types::*;
 
 #[frame::pallet]
+#[allow(clippy::too_many_arguments)]
 pub mod pallet {
     use super::*;
     use crate::types::BalanceOf;
-    use codec::{Codec, EncodeLike};
+    use codec::{Codec, DecodeAll, EncodeLike};
     use frame::deps::frame_support::traits::{
-        Currency, EnsureOrigin, Get, QueryPreimage, ReservableCurrency, StorePreimage,
+        schedule::{v3::Anon as ScheduleAnon, DispatchTime, LOWEST_PRIORITY},
+        Currency, EnsureOrigin, Get, PalletInfoAccess, QueryPreimage, ReservableCurrency,
+        StorePreimage, Time,
     };
+    use frame::deps::sp_runtime::traits::{Saturating, Zero};
     use frame::prelude::*;
-    use scale_info::prelude::vec::Vec;
     use primitive_types::H128;
+    use scale_info::prelude::vec::Vec;
 
     use nazgul::{clsag::CLSAG, traits::Verify};
     use sha2::Sha512;
 
+    /// 计票逻辑。每个方法都可以读取该投票的配置 (PollConfig)
     pub trait TallyLogic<Vote, Tally> {
-        /// 根据一张选票 (Vote) 更新计票结果 (Tally)
-        fn update_tally(vote: &Vote, tally: &mut Tally) -> DispatchResult;
+        /// 根据投票配置（如问卷结构）创建初始计票结果。配置不适用于该计票方式时返回错误
+        fn new_tally(_config: &PollConfig) -> Result<Tally, DispatchError>
+        where
+            Tally: Default,
+        {
+            Ok(Tally::default())
+        }
+
+        /// 检查一张选票是否符合投票配置
+        fn validate(_config: &PollConfig, _vote: &Vote) -> DispatchResult {
+            Ok(())
+        }
+
+        /// 根据一张选票 (Vote) 更新计票结果 (Tally)。选票无效或计数溢出时返回错误，且不改变计票结果
+        fn update_tally(config: &PollConfig, vote: &Vote, tally: &mut Tally) -> DispatchResult;
+
+        /// 把另一部分选票的计票结果合并进来，用于分片计票。失败时不改变计票结果
+        fn merge(config: &PollConfig, tally: &mut Tally, other: &Tally) -> DispatchResult;
+
+        /// 检查累计的计票结果是否符合投票配置，在计算最终结果前调用
+        fn validate_tally(_config: &PollConfig, _tally: &Tally) -> DispatchResult {
+            Ok(())
+        }
+
+        /// 计票逻辑能否判定投票是否通过。不能判定的计票方式不接受通过门槛，也不能附带调用
+        fn decides_outcome() -> bool {
+            false
+        }
+
+        /// 关闭投票时根据累计的计票结果计算最终结果（如排序投票的各轮淘汰），
+        /// 并给出能由计票结果判定的部分（是否通过）。投票率和法定人数由 pallet 填写
+        fn finalize(_config: &PollConfig, _tally: &mut Tally) -> Result<Outcome, DispatchError> {
+            Ok(Outcome::default())
+        }
+    }
+
+    /// 选票的加密方式。链上计票时用投票关闭时公开的私钥解密每张选票
+    pub trait BallotCipher {
+        /// 解密一张选票，认证失败时返回 `None`
+        fn decrypt(
+            private_key: &[u8; 32],
+            ephemeral_public_key: &[u8; 32],
+            ciphertext: &[u8],
+            auth_tag: &[u8; 16],
+        ) -> Option<Vec<u8>>;
+    }
+
+    /// 不加密：密文就是填充后的 SCALE 编码选票，仅用于测试
+    impl BallotCipher for () {
+        fn decrypt(
+            _private_key: &[u8; 32],
+            _ephemeral_public_key: &[u8; 32],
+            ciphertext: &[u8],
+            _auth_tag: &[u8; 16],
+        ) -> Option<Vec<u8>> {
+            crate::ecies::unpad(ciphertext).map(<[u8]>::to_vec)
+        }
     }
 
+    /// 为基准测试提供一张能被计入的选票
+    #[cfg(feature = "runtime-benchmarks")]
+    pub trait BenchmarkHelper<Vote> {
+        /// 符合 `mock::gen_poll_config` 的选票
+        fn vote() -> Vote;
+    }
+
+    /// 存储版本。存储结构变化时递增，并在 `migrations` 中添加对应的迁移
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
     #[pallet::pallet]
-    pub struct Pallet<T>(_);
+    #[pallet::storage_version(STORAGE_VERSION)]
+    pub struct Pallet<T, I = ()>(_);
 
     // Configuration trait for the pallet.
     #[pallet::config]
-    pub trait Config: frame_system::Config {
+    pub trait Config<I: 'static = ()>: frame_system::Config {
         // Defines the event type for the pallet.
-        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+        type RuntimeEvent: From<Event<Self, I>>
+            + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
         /// 货币系统，用于处理押金
         type Currency: ReservableCurrency<Self::AccountId> + Currency<Self::AccountId>;
@@ -52,19 +132,43 @@ pub mod pallet {
         /// Preimage 存储，用于元数据
         type Preimages: QueryPreimage<H = Self::Hashing> + StorePreimage;
 
+        /// 链上时间（通常为 `pallet_timestamp`），用于按时间戳开放和截止投票
+        type Time: Time;
+
         /// 提交一个投票所需的押金，以防止垃圾信息
         #[pallet::constant]
-        type SubmissionDeposit: Get<BalanceOf<Self>>;
+        type SubmissionDeposit: Get<BalanceOf<Self, I>>;
 
         /// 谁有权创建新的投票
         type CreatePollOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Self::AccountId>;
 
+        /// 谁有权创建附带调用的投票或请愿。附带的调用以 `EnactmentOrigin` 执行，应限制为治理机构
+        type ProposalOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Self::AccountId>;
+
         /// 谁有权关闭一个投票
         type ClosePollOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
         /// 谁有权注册和管理公钥环
         type RingAdminOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Self::AccountId>;
 
+        /// 审查委员会：设置举报加密公钥并回执举报
+        type ReportCommitteeOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// 调度器，投票通过后延迟执行附带的调用
+        type Scheduler: ScheduleAnon<
+            BlockNumberFor<Self>,
+            CallOf<Self>,
+            PalletsOriginOf<Self>,
+            Hasher = Self::Hashing,
+        >;
+
+        /// 执行附带调用时使用的 origin
+        type EnactmentOrigin: Get<PalletsOriginOf<Self>>;
+
+        /// 投票通过到执行附带调用之间的区块数
+        #[pallet::constant]
+        type EnactmentDelay: Get<BlockNumberFor<Self>>;
+
         /// 投票的数据结构。
         /// Runtime 可以将其定义为 Enum (赞成/反对) 或 Vec (评分)
         type Vote: Codec + EncodeLike + TypeInfo + Clone + Eq + Debug + MaxEncodedLen;
@@ -83,6 +187,9 @@ pub mod pallet {
         /// 实现了计票逻辑的类型
         type TallyHandler: TallyLogic<Self::Vote, Self::Tally>;
 
+        /// 链上计票时解密选票的方式
+        type BallotCipher: BallotCipher;
+
         /// 提案描述的最大长度
         #[pallet::constant]
         type MaxDescriptionLength: Get<u32>;
@@ -97,7 +204,7 @@ pub mod pallet {
 
         // 让第一个触发关闭的用户获得一些补偿
         #[pallet::constant]
-        type ClosureIncentive: Get<BalanceOf<Self>>;
+        type ClosureIncentive: Get<BalanceOf<Self, I>>;
 
         /// 单个投票的最大加密尺寸
         #[pallet::constant]
@@ -107,11 +214,31 @@ pub mod pallet {
         #[pallet::constant]
         type MaxVotesPerPoll: Get<u32>;
 
+        /// 公开分项结果所需的最少投票数。投票人数不足时只公布投票率，
+        /// 不公开计票结果和解密私钥，避免小班级的答案被逐一推断
+        #[pallet::constant]
+        type MinBallotsForBreakdown: Get<u32>;
+
+        /// 匿名留言直接上链的最大长度，更长的内容以 Preimage 哈希引用
+        #[pallet::constant]
+        type MaxMessageLength: Get<u32>;
+
+        /// 举报和回执密文的最大长度
+        #[pallet::constant]
+        type MaxReportSize: Get<u32>;
+
+        /// 每个密钥镜像最多同时有多少条未回执的举报
+        #[pallet::constant]
+        type MaxOpenReports: Get<u32>;
+
         type WeightInfo: WeightInfo;
+
+        #[cfg(feature = "runtime-benchmarks")]
+        type BenchmarkHelper: BenchmarkHelper<Self::Vote>;
     }
 
     #[pallet::error]
-    pub enum Error<T> {
+    pub enum Error<T, I = ()> {
         /// 签名验证失败。
         InvalidSignature,
         /// 提供的元数据格式错误。
@@ -130,6 +257,10 @@ pub mod pallet {
         RingGroupNotFound,
         /// 截止日期无效（必须在未来）
         InvalidDeadline,
+        /// 开放时间无效（必须早于截止日期，且与截止日期使用相同的计时方式）
+        InvalidOpeningTime,
+        /// 投票尚未开始
+        PollNotStarted,
         /// 投票已过期
         PollExpired,
         /// 投票数量已达上限
@@ -140,16 +271,65 @@ pub mod pallet {
         InvalidPollStatus,
         /// 私钥与公钥不匹配
         InvalidPrivateKey,
+        /// 附带调用需要通过门槛来判定是否执行
+        ProposalRequiresThreshold,
+        /// 该计票方式不能判定是否通过，不能设置通过门槛
+        ThresholdNotSupported,
+        /// 问卷结构无效，或不适用于该计票方式
+        InvalidBallotSchema,
+        /// 计票结果与投票配置不符
+        InvalidTally,
+        /// 该投票的评论已公布
+        CommentsAlreadyPublished,
+        /// 投票人数不足，不能公开私钥、计票结果和评论
+        BreakdownWithheld,
+        /// 密文长度与投票规定的选票长度不符，或规定的长度超出 `MaxVoteSize`
+        InvalidBallotLength,
+        /// 话题未找到
+        TopicNotFound,
+        /// 发帖频率限制无效（条数和周期都必须大于零）
+        InvalidRateLimit,
+        /// 该密钥镜像在当前周期内的留言数已达上限
+        RateLimited,
+        /// 请愿未找到
+        PetitionNotFound,
+        /// 请愿门槛无效（必须大于零且不超过环成员数）
+        InvalidPetitionThreshold,
+        /// 该成员已签署此请愿 (密钥镜像已使用)
+        AlreadySigned,
+        /// 审查委员会尚未设置举报加密公钥
+        CommitteeKeyNotSet,
+        /// 审查委员会公钥不是有效的 Ristretto 点
+        InvalidCommitteeKey,
+        /// 举报未找到（或已回执）
+        ReportNotFound,
+        /// 该成员未回执的举报数已达上限
+        TooManyOpenReports,
+        /// 回执公钥已被其他举报使用
+        ReplyKeyUsed,
+        /// 升级前创建的投票不接受新的选票，只能用 `close_legacy_poll` 关闭
+        LegacyPoll,
+        /// 不是升级前创建、仍在进行的投票
+        NotLegacyPoll,
+        /// 该投票没有按旧布局存放的选票
+        NoLegacyBallots,
     }
 
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
-    pub enum Event<T: Config> {
-        /// 一张加密选票已成功提交
+    pub enum Event<T: Config<I>, I: 'static = ()> {
+        /// 一张加密选票已成功提交。提交时间本身已由交易公开，事件不额外泄露信息
         EncryptedVoteCast {
             poll_id: PollId,
             key_image: CompressedRistrettoWrapper,
         },
+        /// 一张加密选票替换了同一投票人之前的选票，仅在投票开启 `announce_revisions` 时发出
+        EncryptedVoteReplaced {
+            poll_id: PollId,
+            key_image: CompressedRistrettoWrapper,
+            /// 该密钥镜像的修订次数，首次替换为 1
+            revision: u32,
+        },
         /// 一个新投票已创建
         PollCreated {
             poll_id: PollId,
@@ -157,17 +337,87 @@ pub mod pallet {
             creator: T::AccountId,
             encryption_pubkey: [u8; 32],
         },
+        /// 投票已关闭，私钥已公开，开始链上计票
+        TallyStarted { poll_id: PollId, ballots: u32 },
+        /// 一批选票已解密并计入
+        TallyBatchProcessed {
+            poll_id: PollId,
+            processed: u32,
+            /// 本批中被判为无效的选票数
+            spoiled: u32,
+            remaining: u32,
+        },
+        /// 计票结果与投票配置不符，或计票逻辑无法得出最终结果，投票按未通过关闭
+        TallyFailed { poll_id: PollId },
         /// 投票已关闭且计票完成
         PollClosed {
             poll_id: PollId,
             tally: T::Tally,
-            private_key_revealed: [u8; 32],
+            /// 投票率、是否达到法定人数以及是否通过
+            outcome: Outcome,
+            /// 投票人数不足时不公开私钥
+            private_key_revealed: Option<[u8; 32]>,
+        },
+        /// 投票通过，附带的调用已交给调度器
+        ProposalScheduled { poll_id: PollId },
+        /// 投票通过，但附带的调用未能交给调度器
+        ProposalSchedulingFailed { poll_id: PollId },
+        /// 投票关闭后公布了评论
+        CommentsPublished {
+            poll_id: PollId,
+            comments_hash: T::Hash,
         },
         /// 一个新的公钥环被注册
         RingGroupRegistered {
             ring_id: RingId,
             admin: T::AccountId,
         },
+        /// 一个新的留言话题已创建
+        TopicCreated {
+            topic_id: TopicId,
+            ring_id: RingId,
+            creator: T::AccountId,
+        },
+        /// 一条匿名留言已发布。同一成员在同一话题中的密钥镜像相同，在不同话题中互不相关
+        AnonymousMessagePosted {
+            topic_id: TopicId,
+            ring_id: RingId,
+            key_image: CompressedRistrettoWrapper,
+            content: MessageContent<T, I>,
+        },
+        /// 一个新请愿已创建
+        PetitionCreated {
+            petition_id: PetitionId,
+            ring_id: RingId,
+            creator: T::AccountId,
+            threshold: u32,
+        },
+        /// 请愿收到一个匿名签名
+        PetitionSigned {
+            petition_id: PetitionId,
+            key_image: CompressedRistrettoWrapper,
+            signatures: u32,
+        },
+        /// 请愿的签名数达到门槛。附带调用时给出是否已交给调度器
+        PetitionThresholdReached {
+            petition_id: PetitionId,
+            signatures: u32,
+            proposal_scheduled: Option<bool>,
+        },
+        /// 审查委员会更换了举报加密公钥
+        CommitteeKeySet { public_key: [u8; 32] },
+        /// 收到一条匿名举报
+        ReportSubmitted {
+            report_id: ReportId,
+            ring_id: RingId,
+            key_image: CompressedRistrettoWrapper,
+        },
+        /// 审查委员会回执了举报，回执加密给举报人的一次性公钥
+        ReportAcknowledged {
+            report_id: ReportId,
+            key_image: CompressedRistrettoWrapper,
+            acknowledgement: EncryptedMessage<T, I>,
+        },
     }
 
     /// 提案 ID
@@ -176,27 +426,44 @@ pub mod pallet {
     /// 可重用公钥环的 ID
     pub type RingId = u64;
 
+    /// 留言话题的 ID
+    pub type TopicId = u64;
+
+    /// 请愿的 ID
+    pub type PetitionId = u64;
+
+    /// 举报的 ID
+    pub type ReportId = u64;
+
     /// 提案计数器，用于生成新的 PollId
     #[pallet::storage]
     #[pallet::getter(fn poll_count)]
-    pub type PollCount<T: Config> = StorageValue<_, PollId, ValueQuery>;
+    pub type PollCount<T: Config<I>, I: 'static = ()> = StorageValue<_, PollId, ValueQuery>;
 
     /// 存储所有投票的详细信息
     #[pallet::storage]
     #[pallet::getter(fn polls)]
-    pub type Polls<T: Config> = StorageMap<_, Twox64Concat, PollId, Poll<T>, OptionQuery>;
+    pub type Polls<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Twox64Concat, PollId, Poll<T, I>, OptionQuery>;
 
     /// 投票的计票结果
     #[pallet::storage]
     #[pallet::getter(fn poll_votes)]
-    pub type PollVotes<T: Config> = StorageMap<_, Twox64Concat, PollId, T::Tally, ValueQuery>;
+    pub type PollVotes<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Twox64Concat, PollId, T::Tally, ValueQuery>;
+
+    /// 投票关闭时判定的结果
+    #[pallet::storage]
+    #[pallet::getter(fn poll_outcomes)]
+    pub type PollOutcomes<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Twox64Concat, PollId, Outcome, OptionQuery>;
 
     /// 存储已使用的密钥镜像 (Key Images)，用于防止双花。
     /// Key: (PollId, KeyImage)
     /// Value: ()
     #[pallet::storage]
     #[pallet::getter(fn used_key_images)]
-    pub type UsedKeyImages<T: Config> = StorageDoubleMap<
+    pub type UsedKeyImages<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
         _,
         Twox64Concat,
         PollId,
@@ -206,31 +473,45 @@ pub mod pallet {
         OptionQuery,
     >;
 
+    /// 允许重新投票的投票中，每个密钥镜像对应的选票位置和修订次数
+    #[pallet::storage]
+    #[pallet::getter(fn ballot_slots)]
+    pub type BallotSlots<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        PollId,
+        Blake2_128,
+        CompressedRistrettoWrapper,
+        BallotSlot,
+        OptionQuery,
+    >;
+
     /// 公钥环计数器
     #[pallet::storage]
     #[pallet::getter(fn ring_group_count)]
-    pub type RingGroupCount<T: Config> = StorageValue<_, RingId, ValueQuery>;
+    pub type RingGroupCount<T: Config<I>, I: 'static = ()> = StorageValue<_, RingId, ValueQuery>;
 
     /// 存储可重用的公钥环
     #[pallet::storage]
     #[pallet::getter(fn ring_groups)]
-    pub type RingGroups<T: Config> = StorageMap<
+    pub type RingGroups<T: Config<I>, I: 'static = ()> = StorageMap<
         _,
         Twox64Concat,
         RingId,
-        RingMatrix<T>, // 公钥矩阵
+        RingMatrix<T, I>, // 公钥矩阵
         OptionQuery,
     >;
 
     /// 存储每个投票 *所使用* 的公钥环 ID
     #[pallet::storage]
     #[pallet::getter(fn poll_ring_id)]
-    pub type PollRingId<T: Config> = StorageMap<_, Twox64Concat, PollId, RingId, OptionQuery>;
+    pub type PollRingId<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Twox64Concat, PollId, RingId, OptionQuery>;
 
     /// 存储投票的元数据哈希
     #[pallet::storage]
     #[pallet::getter(fn poll_metadata)]
-    pub type PollMetadata<T: Config> = StorageMap<
+    pub type PollMetadata<T: Config<I>, I: 'static = ()> = StorageMap<
         _,
         Twox64Concat,
         PollId,
@@ -238,19 +519,146 @@ pub mod pallet {
         OptionQuery,
     >;
 
-    /// 存储加密的投票（密文池）
+    /// 投票通过后要执行的调用（内联或 Preimage 哈希）
     #[pallet::storage]
-    #[pallet::getter(fn encrypted_votes)]
-    pub type EncryptedVotes<T: Config> = StorageMap<
+    #[pallet::getter(fn poll_proposals)]
+    pub type PollProposals<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Twox64Concat, PollId, BoundedCallOf<T>, OptionQuery>;
+
+    /// 投票关闭后公布的评论，以 Preimage 哈希引用（格式见 `publish_comments`），
+    /// 避免大段文本占用计票结果的存储
+    #[pallet::storage]
+    #[pallet::getter(fn poll_comments)]
+    pub type PollComments<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Twox64Concat, PollId, T::Hash, OptionQuery>;
+
+    /// 链上计票的进度：下一张待计票的选票在 `EncryptedVotes` 中的位置，只在计票阶段存在
+    #[pallet::storage]
+    #[pallet::getter(fn tally_cursors)]
+    pub type TallyCursors<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Twox64Concat, PollId, u32, OptionQuery>;
+
+    /// 计票时被判为无效的选票及原因，按选票位置排列
+    #[pallet::storage]
+    #[pallet::getter(fn spoiled_ballots)]
+    pub type SpoiledBallots<T: Config<I>, I: 'static = ()> = StorageMap<
         _,
         Twox64Concat,
         PollId,
-        BoundedVec<EncryptedVote<T>, T::MaxVotesPerPoll>,
+        BoundedVec<SpoiledBallot, T::MaxVotesPerPoll>,
         ValueQuery,
     >;
 
+    /// 存储加密的投票（密文池），按收到的顺序编号。
+    /// 每张选票单独存储，计票时只读取本批的选票
+    #[pallet::storage]
+    #[pallet::getter(fn encrypted_votes)]
+    pub type EncryptedVotes<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        PollId,
+        Twox64Concat,
+        u32,
+        EncryptedVote<T, I>,
+        OptionQuery,
+    >;
+
+    /// 每个投票已收到的选票数，即下一张选票的编号
+    #[pallet::storage]
+    #[pallet::getter(fn ballot_counts)]
+    pub type BallotCounts<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Twox64Concat, PollId, u32, ValueQuery>;
+
+    /// 升级到存储版本 1 时仍在进行的投票。这些投票的选票按旧格式签名和加密，
+    /// 不接受新的选票，只能由 `close_legacy_poll` 按旧的方式关闭
+    #[pallet::storage]
+    pub type LegacyPolls<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Twox64Concat, PollId, (), OptionQuery>;
+
+    /// 话题计数器
+    #[pallet::storage]
+    #[pallet::getter(fn topic_count)]
+    pub type TopicCount<T: Config<I>, I: 'static = ()> = StorageValue<_, TopicId, ValueQuery>;
+
+    /// 匿名留言板的话题
+    #[pallet::storage]
+    #[pallet::getter(fn topics)]
+    pub type Topics<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Twox64Concat, TopicId, TopicOf<T>, OptionQuery>;
+
+    /// 有发帖频率限制的话题中，每个密钥镜像在当前周期内的发帖情况
+    #[pallet::storage]
+    #[pallet::getter(fn post_windows)]
+    pub type PostWindows<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        TopicId,
+        Blake2_128,
+        CompressedRistrettoWrapper,
+        PostWindow<BlockNumberFor<T>>,
+        OptionQuery,
+    >;
+
+    /// 请愿计数器
+    #[pallet::storage]
+    #[pallet::getter(fn petition_count)]
+    pub type PetitionCount<T: Config<I>, I: 'static = ()> = StorageValue<_, PetitionId, ValueQuery>;
+
+    /// 存储所有请愿
+    #[pallet::storage]
+    #[pallet::getter(fn petitions)]
+    pub type Petitions<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Twox64Concat, PetitionId, Petition<T, I>, OptionQuery>;
+
+    /// 请愿达到门槛后要执行的调用（内联或 Preimage 哈希）
+    #[pallet::storage]
+    #[pallet::getter(fn petition_proposals)]
+    pub type PetitionProposals<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Twox64Concat, PetitionId, BoundedCallOf<T>, OptionQuery>;
+
+    /// 已签署请愿的密钥镜像，用于去重
+    #[pallet::storage]
+    #[pallet::getter(fn petition_signers)]
+    pub type PetitionSigners<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        PetitionId,
+        Blake2_128,
+        CompressedRistrettoWrapper,
+        (),
+        OptionQuery,
+    >;
+
+    /// 审查委员会的举报加密公钥
+    #[pallet::storage]
+    #[pallet::getter(fn committee_key)]
+    pub type CommitteeKey<T: Config<I>, I: 'static = ()> = StorageValue<_, [u8; 32], OptionQuery>;
+
+    /// 举报计数器
+    #[pallet::storage]
+    #[pallet::getter(fn report_count)]
+    pub type ReportCount<T: Config<I>, I: 'static = ()> = StorageValue<_, ReportId, ValueQuery>;
+
+    /// 尚未回执的举报，回执后删除
+    #[pallet::storage]
+    #[pallet::getter(fn reports)]
+    pub type Reports<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Twox64Concat, ReportId, Report<T, I>, OptionQuery>;
+
+    /// 每个密钥镜像未回执的举报数
+    #[pallet::storage]
+    #[pallet::getter(fn open_reports)]
+    pub type OpenReports<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128, CompressedRistrettoWrapper, u32, ValueQuery>;
+
+    /// 已使用的回执公钥。每条举报使用新的一次性公钥，防止重放已签名的举报
+    #[pallet::storage]
+    #[pallet::getter(fn used_reply_keys)]
+    pub type UsedReplyKeys<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128, [u8; 32], (), OptionQuery>;
+
     #[pallet::call]
-    impl<T: Config> Pallet<T> {
+    impl<T: Config<I>, I: 'static> Pallet<T, I> {
         /// 注册一个可重用的公钥环
         #[pallet::call_index(0)]
         // #[pallet::weight(0)]
@@ -263,10 +671,10 @@ pub mod pallet {
             let admin = T::RingAdminOrigin::ensure_origin(origin)?;
 
             // 2. 获取新 ID
-            let ring_id = <RingGroupCount<T>>::get();
+            let ring_id = <RingGroupCount<T, I>>::get();
 
             // 3. 存储
-            let ring: RingMatrix<T> = ring
+            let ring: RingMatrix<T, I> = ring
                 .into_iter()
                 .map(|row| {
                     row.into_iter()
@@ -278,10 +686,10 @@ pub mod pallet {
                 .collect::<Vec<_>>()
                 .try_into()
                 .unwrap();
-            <RingGroups<T>>::insert(ring_id, ring);
+            <RingGroups<T, I>>::insert(ring_id, ring);
 
             // 4. 递增 ID
-            <RingGroupCount<T>>::put(ring_id.saturating_add(1));
+            <RingGroupCount<T, I>>::put(ring_id.saturating_add(1));
 
             // 5. 发送事件
             Self::deposit_event(Event::RingGroupRegistered { ring_id, admin });
@@ -298,32 +706,72 @@ pub mod pallet {
             description: BoundedVec<u8, T::MaxDescriptionLength>,
             ring_id: RingId,
             metadata_hash: Option<T::Hash>,
-            deadline: Option<BlockNumberFor<T>>,
+            opens_at: Option<PollMomentOf<T, I>>,
+            deadline: Option<PollMomentOf<T, I>>,
             encryption_public_key: H256,
+            config: PollConfig,
+            proposal: Option<BoundedCallOf<T>>,
         ) -> DispatchResult {
-            // 1. 权限检查
-            let creator = T::CreatePollOrigin::ensure_origin(origin)?;
+            // 1. 权限检查，附带调用需要更高的权限
+            let creator = match proposal {
+                Some(_) => T::ProposalOrigin::ensure_origin(origin)?,
+                None => T::CreatePollOrigin::ensure_origin(origin)?,
+            };
 
-            // 2. 验证截止日期
-            if let Some(deadline_block) = deadline {
-                let current_block = <frame_system::Pallet<T>>::block_number();
-                ensure!(deadline_block > current_block, Error::<T>::InvalidDeadline);
+            // 2. 验证开放时间与截止日期
+            if let Some(deadline) = &deadline {
+                ensure!(!Self::has_reached(deadline), Error::<T, I>::InvalidDeadline);
+            }
+            if let (Some(opens_at), Some(deadline)) = (&opens_at, &deadline) {
+                ensure!(
+                    opens_at.is_before(deadline) == Some(true),
+                    Error::<T, I>::InvalidOpeningTime
+                );
             }
 
             // 3. 验证元数据哈希
             if let Some(hash) = metadata_hash {
                 ensure!(
                     T::Preimages::len(&hash).is_some(),
-                    Error::<T>::PreimageNotExist
+                    Error::<T, I>::PreimageNotExist
                 );
             }
 
+            // 附带的调用必须可取回，且只有能判定通过的投票才能附带调用
+            if let Some(call) = &proposal {
+                ensure!(T::Preimages::have(call), Error::<T, I>::PreimageNotExist);
+                ensure!(
+                    config.threshold.is_some(),
+                    Error::<T, I>::ProposalRequiresThreshold
+                );
+            }
+
+            // 只有能判定是否通过的计票方式才接受通过门槛
+            ensure!(
+                config.threshold.is_none() || T::TallyHandler::decides_outcome(),
+                Error::<T, I>::ThresholdNotSupported
+            );
+
             // 4. 检查 RingId 是否存在
             ensure!(
-                <RingGroups<T>>::contains_key(ring_id),
-                Error::<T>::RingGroupNotFound
+                <RingGroups<T, I>>::contains_key(ring_id),
+                Error::<T, I>::RingGroupNotFound
+            );
+
+            ensure!(
+                config
+                    .ballot_length
+                    .is_none_or(|length| length > 0 && length <= T::MaxVoteSize::get()),
+                Error::<T, I>::InvalidBallotLength
             );
 
+            // 按问卷结构初始化计票结果
+            if let Some(schema) = &config.schema {
+                ensure!(schema.is_valid(), Error::<T, I>::InvalidBallotSchema);
+            }
+            let tally = T::TallyHandler::new_tally(&config)
+                .map_err(|_| Error::<T, I>::InvalidBallotSchema)?;
+
             // 5. 收取押金
             let deposit_amount = T::SubmissionDeposit::get();
             T::Currency::reserve(&creator, deposit_amount)?;
@@ -333,7 +781,7 @@ pub mod pallet {
             };
 
             // 6. 获取新 ID
-            let poll_id = <PollCount<T>>::get();
+            let poll_id = <PollCount<T, I>>::get();
 
             // 7. 创建投票对象
             let encryption_public_key = encryption_public_key.0;
@@ -342,22 +790,27 @@ pub mod pallet {
                 description,
                 status: PollStatus::Voting,
                 submission_deposit,
+                opens_at,
                 deadline,
                 encryption_public_key: Some(encryption_public_key),
                 encryption_private_key: None,
+                config,
             };
 
             // 8. 存储
-            <Polls<T>>::insert(poll_id, new_poll);
-            <PollVotes<T>>::insert(poll_id, T::Tally::default());
-            <PollRingId<T>>::insert(poll_id, ring_id);
-            <EncryptedVotes<T>>::insert(poll_id, BoundedVec::default());
+            <Polls<T, I>>::insert(poll_id, new_poll);
+            <PollVotes<T, I>>::insert(poll_id, tally);
+            <PollRingId<T, I>>::insert(poll_id, ring_id);
             if let Some(hash) = metadata_hash {
-                <PollMetadata<T>>::insert(poll_id, hash);
+                <PollMetadata<T, I>>::insert(poll_id, hash);
+            }
+            if let Some(call) = proposal {
+                Self::request_proposal(&call);
+                <PollProposals<T, I>>::insert(poll_id, call);
             }
 
             // 9.  递增 ID 计数器
-            <PollCount<T>>::put(poll_id.saturating_add(1));
+            <PollCount<T, I>>::put(poll_id.saturating_add(1));
 
             // 10. 发送事件
             Self::deposit_event(Event::PollCreated {
@@ -370,58 +823,65 @@ pub mod pallet {
             Ok(())
         }
 
-        /// 关闭投票并提交计票结果
+        /// 关闭投票并公开私钥，进入计票阶段，之后由 `tally_batch` 分批在链上解密和计票。
+        /// 投票人数低于 `MinBallotsForBreakdown` 时不得提交私钥，投票直接关闭且只公布投票率
         #[pallet::call_index(2)]
         // #[pallet::weight(0)]
         #[pallet::weight(T::WeightInfo::close_poll())]
         pub fn close_poll(
             origin: OriginFor<T>,
             poll_id: PollId,
-            encryption_private_key: H256,
-            tally: T::Tally,
+            encryption_private_key: Option<H256>,
         ) -> DispatchResult {
-            let mut poll = <Polls<T>>::get(poll_id).ok_or(Error::<T>::PollNotFound)?;
+            let mut poll = <Polls<T, I>>::get(poll_id).ok_or(Error::<T, I>::PollNotFound)?;
 
             // 1. 检查状态
             ensure!(
                 poll.status == PollStatus::Voting,
-                Error::<T>::PollAlreadyClosed
+                Error::<T, I>::PollAlreadyClosed
+            );
+            ensure!(
+                !<LegacyPolls<T, I>>::contains_key(poll_id),
+                Error::<T, I>::LegacyPoll
             );
 
             // 2. 权限检查
             T::ClosePollOrigin::ensure_origin(origin)?;
 
-            // 3.  验证私钥是否匹配公钥
-            let encryption_private_key = encryption_private_key.0;
+            // 3. 投票人数不足时只公布投票率，不公开私钥，也不计票
+            let ballots = Self::turnout(poll_id);
+            if ballots < T::MinBallotsForBreakdown::get() {
+                ensure!(
+                    encryption_private_key.is_none(),
+                    Error::<T, I>::BreakdownWithheld
+                );
+                let tally = T::TallyHandler::new_tally(&poll.config)
+                    .map_err(|_| Error::<T, I>::InvalidTally)?;
+                let verdict = Outcome {
+                    breakdown_withheld: true,
+                    ..Default::default()
+                };
+                Self::conclude(poll_id, poll, tally, verdict);
+                return Ok(());
+            }
+
+            // 4. 验证私钥是否匹配公钥
+            let encryption_private_key = encryption_private_key
+                .ok_or(Error::<T, I>::InvalidPrivateKey)?
+                .0;
             let derived_pubkey = Self::derive_public_key(&encryption_private_key);
             ensure!(
                 Some(derived_pubkey) == poll.encryption_public_key,
-                Error::<T>::InvalidPrivateKey
+                Error::<T, I>::InvalidPrivateKey
             );
 
-            // 4. 存储计票结果
-            <PollVotes<T>>::insert(poll_id, tally.clone());
-
-            // 5. 公开私钥
+            // 5. 公开私钥，进入计票阶段
             poll.encryption_private_key = Some(encryption_private_key);
+            poll.status = PollStatus::Tallying;
+            <Polls<T, I>>::insert(poll_id, poll);
+            <TallyCursors<T, I>>::insert(poll_id, 0);
 
-            // 6. 退还押金
-            T::Currency::unreserve(&poll.creator, poll.submission_deposit.amount);
-
-            // 7.  更新状态为 Closed
-            poll.status = PollStatus::Closed;
-            <Polls<T>>::insert(poll_id, poll);
-
-            // 8. 清理存储
-            <PollRingId<T>>::remove(poll_id);
-            <PollMetadata<T>>::remove(poll_id);
-
-            // 9. 发出"计票完成"事件
-            Self::deposit_event(Event::PollClosed {
-                poll_id,
-                tally,
-                private_key_revealed: encryption_private_key,
-            });
+            Self::deposit_event(Event::TallyStarted { poll_id, ballots });
 
             Ok(())
         }
@@ -443,47 +903,826 @@ pub mod pallet {
             let _who = ensure_signed(origin.clone())?;
 
             // 1. 检查投票状态
-            let poll = <Polls<T>>::get(poll_id).ok_or(Error::<T>::PollNotFound)?;
-            ensure!(poll.status == PollStatus::Voting, Error::<T>::PollNotOpen);
+            let poll = <Polls<T, I>>::get(poll_id).ok_or(Error::<T, I>::PollNotFound)?;
+            ensure!(
+                poll.status == PollStatus::Voting,
+                Error::<T, I>::PollNotOpen
+            );
+            ensure!(
+                !<LegacyPolls<T, I>>::contains_key(poll_id),
+                Error::<T, I>::LegacyPoll
+            );
 
-            // 2.  检查是否过期，如果过期则返回错误
+            // 2.  检查是否已开始、是否过期
+            ensure!(Self::is_poll_started(&poll), Error::<T, I>::PollNotStarted);
             if Self::is_poll_expired(&poll) {
-                return Err(Error::<T>::PollExpired.into());
+                return Err(Error::<T, I>::PollExpired.into());
+            }
+            ensure!(
+                ciphertext.len() as u32 == Self::ballot_length(&poll.config),
+                Error::<T, I>::InvalidBallotLength
+            );
+
+            // 3. 构建待签名消息
+            let ephemeral_public_key = ephemeral_public_key.0;
+            let auth_tag = auth_tag.0;
+            let message =
+                Self::vote_message(poll_id, &ephemeral_public_key, &ciphertext, &auth_tag);
+
+            // 4. 用投票的公钥环验证签名
+            let ring_id = <PollRingId<T, I>>::get(poll_id).ok_or(Error::<T, I>::PollNotFound)?;
+            let (signature, main_key_image) =
+                Self::verify_ring_signature(ring_id, &message, challenge, responses, key_images)?;
+
+            // 5. 检查双重投票（允许重新投票时，相同密钥镜像的新选票替换旧选票）
+            let already_voted = <UsedKeyImages<T, I>>::contains_key(poll_id, &main_key_image);
+            ensure!(
+                !already_voted || poll.config.ballot_replacement,
+                Error::<T, I>::AlreadyVoted
+            );
+
+            // 6. 存储加密投票
+            let encrypted_vote = EncryptedVote {
+                ephemeral_public_key,
+                ciphertext,
+                auth_tag,
+                ring_signature: signature,
+            };
+
+            if already_voted {
+                let mut slot = <BallotSlots<T, I>>::get(poll_id, &main_key_image)
+                    .ok_or(Error::<T, I>::AlreadyVoted)?;
+                ensure!(
+                    <EncryptedVotes<T, I>>::contains_key(poll_id, slot.index),
+                    Error::<T, I>::AlreadyVoted
+                );
+                <EncryptedVotes<T, I>>::insert(poll_id, slot.index, encrypted_vote);
+
+                if poll.config.announce_revisions {
+                    slot.revision = slot.revision.saturating_add(1);
+                    <BallotSlots<T, I>>::insert(poll_id, &main_key_image, &slot);
+                    Self::deposit_event(Event::EncryptedVoteReplaced {
+                        poll_id,
+                        key_image: main_key_image,
+                        revision: slot.revision,
+                    });
+                }
+            } else {
+                let index = <BallotCounts<T, I>>::get(poll_id);
+                ensure!(
+                    index < T::MaxVotesPerPoll::get(),
+                    Error::<T, I>::TooManyVotes
+                );
+                <UsedKeyImages<T, I>>::insert(poll_id, &main_key_image, ());
+                <EncryptedVotes<T, I>>::insert(poll_id, index, encrypted_vote);
+                <BallotCounts<T, I>>::insert(poll_id, index.saturating_add(1));
+                if poll.config.ballot_replacement {
+                    <BallotSlots<T, I>>::insert(
+                        poll_id,
+                        &main_key_image,
+                        BallotSlot { index, revision: 0 },
+                    );
+                }
+
+                Self::deposit_event(Event::EncryptedVoteCast {
+                    poll_id,
+                    key_image: main_key_image,
+                });
+            }
+
+            Ok(())
+        }
+
+        /// 投票关闭后公布从选票中解密出的评论。评论先作为 Preimage 上链，这里只记录其哈希。
+        /// Preimage 为 SCALE 编码的 `Vec<(u32, Vec<Vec<u8>>)>`：按位置升序列出每张带评论的有效选票
+        /// 在 `EncryptedVotes` 中的位置及其评论，无效选票不列出。链上不解析 Preimage，
+        /// 但私钥和密文都已公开，任何人都可以重新解密选票核对评论是否完整、未被篡改
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::publish_comments())]
+        pub fn publish_comments(
+            origin: OriginFor<T>,
+            poll_id: PollId,
+            comments_hash: T::Hash,
+        ) -> DispatchResult {
+            T::ClosePollOrigin::ensure_origin(origin)?;
+
+            let poll = <Polls<T, I>>::get(poll_id).ok_or(Error::<T, I>::PollNotFound)?;
+            ensure!(
+                poll.status == PollStatus::Closed,
+                Error::<T, I>::InvalidPollStatus
+            );
+            ensure!(
+                !<PollComments<T, I>>::contains_key(poll_id),
+                Error::<T, I>::CommentsAlreadyPublished
+            );
+            // 投票人数不足时逐张公布评论同样会暴露投票人
+            ensure!(
+                !<PollOutcomes<T, I>>::get(poll_id).is_some_and(|o| o.breakdown_withheld),
+                Error::<T, I>::BreakdownWithheld
+            );
+            ensure!(
+                T::Preimages::len(&comments_hash).is_some(),
+                Error::<T, I>::PreimageNotExist
+            );
+
+            <PollComments<T, I>>::insert(poll_id, comments_hash);
+
+            Self::deposit_event(Event::CommentsPublished {
+                poll_id,
+                comments_hash,
+            });
+
+            Ok(())
+        }
+
+        /// 解密并计入下一批选票，每次最多 `max_items` 张，任何账户都可以推进计票。
+        /// 全部选票处理完后计算最终结果并关闭投票
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::tally_batch((*max_items).min(T::MaxVotesPerPoll::get())))]
+        #[allow(clippy::useless_conversion)]
+        pub fn tally_batch(
+            origin: OriginFor<T>,
+            poll_id: PollId,
+            max_items: u32,
+        ) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+
+            let poll = <Polls<T, I>>::get(poll_id).ok_or(Error::<T, I>::PollNotFound)?;
+            ensure!(
+                poll.status == PollStatus::Tallying,
+                Error::<T, I>::InvalidPollStatus
+            );
+            let private_key = poll
+                .encryption_private_key
+                .ok_or(Error::<T, I>::InvalidPrivateKey)?;
+
+            // 1. 确定本批选票的范围，只读取范围内的选票
+            let total = Self::turnout(poll_id);
+            let cursor = <TallyCursors<T, I>>::get(poll_id).unwrap_or(0);
+            let end = cursor.saturating_add(max_items).min(total);
+
+            // 2. 解密并计票。无法解密、无法解码或不符合投票配置的选票记为无效，不计入
+            let mut tally = <PollVotes<T, I>>::get(poll_id);
+            let mut spoiled = <SpoiledBallots<T, I>>::get(poll_id);
+            let spoiled_before = spoiled.len() as u32;
+            for index in cursor..end {
+                let Some(ballot) = <EncryptedVotes<T, I>>::get(poll_id, index) else {
+                    continue;
+                };
+                if let Err(reason) =
+                    Self::count_ballot(&poll.config, &private_key, &ballot, &mut tally)
+                {
+                    // 无效选票不会多于选票总数，不会超出上限
+                    let _ = spoiled.try_push(SpoiledBallot { index, reason });
+                }
+            }
+
+            let processed = end.saturating_sub(cursor);
+            let remaining = total.saturating_sub(end);
+            Self::deposit_event(Event::TallyBatchProcessed {
+                poll_id,
+                processed,
+                spoiled: (spoiled.len() as u32).saturating_sub(spoiled_before),
+                remaining,
+            });
+            if !spoiled.is_empty() {
+                <SpoiledBallots<T, I>>::insert(poll_id, spoiled);
+            }
+
+            // 3. 全部处理完后计算最终结果并关闭投票，否则保存进度
+            if remaining == 0 {
+                // 计票结果不符合投票配置或无法得出最终结果时仍关闭投票并判定为未通过，
+                // 避免投票停留在计票阶段
+                let verdict = T::TallyHandler::validate_tally(&poll.config, &tally)
+                    .and_then(|_| T::TallyHandler::finalize(&poll.config, &mut tally))
+                    .unwrap_or_else(|_| {
+                        Self::deposit_event(Event::TallyFailed { poll_id });
+                        Outcome::default()
+                    });
+                Self::conclude(poll_id, poll, tally, verdict);
+            } else {
+                <PollVotes<T, I>>::insert(poll_id, tally);
+                <TallyCursors<T, I>>::insert(poll_id, end);
+            }
+
+            Ok(Some(T::WeightInfo::tally_batch(processed)).into())
+        }
+
+        /// 为某个公钥环创建匿名留言话题，可选设置发帖频率限制
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::create_topic())]
+        pub fn create_topic(
+            origin: OriginFor<T>,
+            ring_id: RingId,
+            rate_limit: Option<RateLimit<BlockNumberFor<T>>>,
+        ) -> DispatchResult {
+            let creator = T::CreatePollOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                <RingGroups<T, I>>::contains_key(ring_id),
+                Error::<T, I>::RingGroupNotFound
+            );
+            ensure!(
+                rate_limit
+                    .as_ref()
+                    .is_none_or(|limit| limit.posts > 0 && !limit.period.is_zero()),
+                Error::<T, I>::InvalidRateLimit
+            );
+
+            let topic_id = <TopicCount<T, I>>::get();
+            <Topics<T, I>>::insert(
+                topic_id,
+                Topic {
+                    creator: creator.clone(),
+                    ring_id,
+                    rate_limit,
+                    posts: 0,
+                },
+            );
+            <TopicCount<T, I>>::put(topic_id.saturating_add(1));
+
+            Self::deposit_event(Event::TopicCreated {
+                topic_id,
+                ring_id,
+                creator,
+            });
+
+            Ok(())
+        }
+
+        /// 以公钥环成员的身份匿名留言。签名见 `topic_sig`，
+        /// 签名的消息为 SCALE 编码的留言内容，话题由 `topic_scope` 给出
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::post_message(responses.len() as u32))]
+        pub fn post_message(
+            origin: OriginFor<T>,
+            topic_id: TopicId,
+            content: MessageContent<T, I>,
+            challenge: H256,
+            responses: BoundedVec<H256, T::MaxMembersInRing>,
+            key_image: H256,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            let mut topic = <Topics<T, I>>::get(topic_id).ok_or(Error::<T, I>::TopicNotFound)?;
+            if let MessageContent::Preimage(hash) = &content {
+                ensure!(
+                    T::Preimages::len(hash).is_some(),
+                    Error::<T, I>::PreimageNotExist
+                );
+            }
+
+            // 1. 用话题的公钥环验证签名
+            Self::verify_topic_signature(
+                topic.ring_id,
+                &Self::topic_scope(topic_id),
+                &content.encode(),
+                challenge,
+                &responses,
+                key_image,
+            )?;
+
+            // 2. 检查发帖频率
+            let key_image = CompressedRistrettoWrapper(key_image.0);
+            if let Some(limit) = &topic.rate_limit {
+                let now = <frame_system::Pallet<T>>::block_number();
+                <PostWindows<T, I>>::try_mutate(topic_id, &key_image, |window| {
+                    let current = window
+                        .take()
+                        .filter(|w| now < w.started.saturating_add(limit.period))
+                        .unwrap_or(PostWindow {
+                            started: now,
+                            posts: 0,
+                        });
+                    ensure!(current.posts < limit.posts, Error::<T, I>::RateLimited);
+                    *window = Some(PostWindow {
+                        posts: current.posts + 1,
+                        ..current
+                    });
+                    Ok::<_, Error<T, I>>(())
+                })?;
+            }
+
+            topic.posts = topic.posts.saturating_add(1);
+            let ring_id = topic.ring_id;
+            <Topics<T, I>>::insert(topic_id, topic);
+
+            Self::deposit_event(Event::AnonymousMessagePosted {
+                topic_id,
+                ring_id,
+                key_image,
+                content,
+            });
+
+            Ok(())
+        }
+
+        /// 创建一个请愿。签名数达到 `threshold` 时发出事件，并执行附带的调用（如有）。
+        /// 附带调用需要 `ProposalOrigin` 权限
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::create_petition())]
+        pub fn create_petition(
+            origin: OriginFor<T>,
+            description: BoundedVec<u8, T::MaxDescriptionLength>,
+            ring_id: RingId,
+            threshold: u32,
+            proposal: Option<BoundedCallOf<T>>,
+        ) -> DispatchResult {
+            // 任何环都可以达到门槛，附带调用需要更高的权限
+            let creator = match proposal {
+                Some(_) => T::ProposalOrigin::ensure_origin(origin)?,
+                None => T::CreatePollOrigin::ensure_origin(origin)?,
+            };
+
+            let members = <RingGroups<T, I>>::decode_len(ring_id)
+                .ok_or(Error::<T, I>::RingGroupNotFound)? as u32;
+            ensure!(
+                threshold > 0 && threshold <= members,
+                Error::<T, I>::InvalidPetitionThreshold
+            );
+            if let Some(call) = &proposal {
+                ensure!(T::Preimages::have(call), Error::<T, I>::PreimageNotExist);
+            }
+
+            let petition_id = <PetitionCount<T, I>>::get();
+            <Petitions<T, I>>::insert(
+                petition_id,
+                Petition {
+                    creator: creator.clone(),
+                    description,
+                    ring_id,
+                    threshold,
+                    signatures: 0,
+                    reached_at: None,
+                },
+            );
+            if let Some(call) = proposal {
+                Self::request_proposal(&call);
+                <PetitionProposals<T, I>>::insert(petition_id, call);
+            }
+            <PetitionCount<T, I>>::put(petition_id.saturating_add(1));
+
+            Self::deposit_event(Event::PetitionCreated {
+                petition_id,
+                ring_id,
+                creator,
+                threshold,
+            });
+
+            Ok(())
+        }
+
+        /// 匿名签署请愿。签名的消息由 `petition_message` 给出，每个密钥镜像只计一次
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::sign_petition(responses.len() as u32))]
+        pub fn sign_petition(
+            origin: OriginFor<T>,
+            petition_id: PetitionId,
+            challenge: H256,
+            responses: BoundedVec<H256, T::MaxMembersInRing>,
+            key_images: BoundedVec<H256, T::NumRingLayers>,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            let mut petition =
+                <Petitions<T, I>>::get(petition_id).ok_or(Error::<T, I>::PetitionNotFound)?;
+
+            // 1. 用请愿的公钥环验证签名
+            let (_, key_image) = Self::verify_ring_signature(
+                petition.ring_id,
+                &Self::petition_message(petition_id),
+                challenge,
+                responses,
+                key_images,
+            )?;
+
+            // 2. 按密钥镜像去重
+            ensure!(
+                !<PetitionSigners<T, I>>::contains_key(petition_id, &key_image),
+                Error::<T, I>::AlreadySigned
+            );
+            <PetitionSigners<T, I>>::insert(petition_id, &key_image, ());
+
+            // 3. 计数，首次达到门槛时触发
+            petition.signatures = petition.signatures.saturating_add(1);
+            let signatures = petition.signatures;
+            let reached = petition.reached_at.is_none() && signatures >= petition.threshold;
+            if reached {
+                petition.reached_at = Some(<frame_system::Pallet<T>>::block_number());
+            }
+            <Petitions<T, I>>::insert(petition_id, petition);
+
+            Self::deposit_event(Event::PetitionSigned {
+                petition_id,
+                key_image,
+                signatures,
+            });
+            if reached {
+                let proposal_scheduled = <PetitionProposals<T, I>>::take(petition_id).map(|call| {
+                    let scheduled = Self::schedule_call(call.clone());
+                    Self::release_proposal(&call);
+                    scheduled
+                });
+                Self::deposit_event(Event::PetitionThresholdReached {
+                    petition_id,
+                    signatures,
+                    proposal_scheduled,
+                });
             }
 
-            // 3. 从存储中获取权威的公钥环
-            let ring_id = <PollRingId<T>>::get(poll_id).ok_or(Error::<T>::PollNotFound)?;
-            let ring_matrix = <RingGroups<T>>::get(ring_id).ok_or(Error::<T>::RingGroupNotFound)?;
+            Ok(())
+        }
+
+        /// 设置审查委员会的举报加密公钥。已提交的举报仍记录其加密时使用的公钥
+        #[pallet::call_index(10)]
+        #[pallet::weight(T::WeightInfo::set_committee_key())]
+        pub fn set_committee_key(origin: OriginFor<T>, public_key: H256) -> DispatchResult {
+            T::ReportCommitteeOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                curve25519_dalek::ristretto::CompressedRistretto(public_key.0)
+                    .decompress()
+                    .is_some(),
+                Error::<T, I>::InvalidCommitteeKey
+            );
+            <CommitteeKey<T, I>>::put(public_key.0);
+
+            Self::deposit_event(Event::CommitteeKeySet {
+                public_key: public_key.0,
+            });
+
+            Ok(())
+        }
+
+        /// 以公钥环成员的身份提交匿名举报。举报内容加密给审查委员会。签名见 `topic_sig`，
+        /// 签名的消息由 `report_message` 给出，话题由 `report_scope` 给出
+        #[pallet::call_index(11)]
+        #[pallet::weight(T::WeightInfo::submit_report(responses.len() as u32))]
+        pub fn submit_report(
+            origin: OriginFor<T>,
+            ring_id: RingId,
+            content: EncryptedMessage<T, I>,
+            reply_public_key: H256,
+            challenge: H256,
+            responses: BoundedVec<H256, T::MaxMembersInRing>,
+            key_image: H256,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            let committee_key =
+                <CommitteeKey<T, I>>::get().ok_or(Error::<T, I>::CommitteeKeyNotSet)?;
+
+            // 1. 验证签名。密钥镜像只在举报中使用，不能与投票、请愿关联
+            Self::verify_topic_signature(
+                ring_id,
+                &Self::report_scope(ring_id),
+                &Self::report_message(ring_id, &committee_key, &content, &reply_public_key.0),
+                challenge,
+                &responses,
+                key_image,
+            )?;
+            let key_image = CompressedRistrettoWrapper(key_image.0);
+
+            // 2. 回执公钥只能使用一次，并限制每个成员未回执的举报数
+            ensure!(
+                !<UsedReplyKeys<T, I>>::contains_key(reply_public_key.0),
+                Error::<T, I>::ReplyKeyUsed
+            );
+            <UsedReplyKeys<T, I>>::insert(reply_public_key.0, ());
+            <OpenReports<T, I>>::try_mutate(&key_image, |open| {
+                ensure!(
+                    *open < T::MaxOpenReports::get(),
+                    Error::<T, I>::TooManyOpenReports
+                );
+                *open += 1;
+                Ok::<_, Error<T, I>>(())
+            })?;
+
+            // 3. 存储，直到审查委员会回执
+            let report_id = <ReportCount<T, I>>::get();
+            <Reports<T, I>>::insert(
+                report_id,
+                Report {
+                    ring_id,
+                    key_image: key_image.clone(),
+                    committee_key,
+                    content,
+                    reply_public_key: reply_public_key.0,
+                    submitted_at: <frame_system::Pallet<T>>::block_number(),
+                },
+            );
+            <ReportCount<T, I>>::put(report_id.saturating_add(1));
+
+            Self::deposit_event(Event::ReportSubmitted {
+                report_id,
+                ring_id,
+                key_image,
+            });
+
+            Ok(())
+        }
 
-            // 4. 验证输入长度
+        /// 审查委员会回执举报：删除举报，并发布加密给举报人一次性公钥的回执
+        #[pallet::call_index(12)]
+        #[pallet::weight(T::WeightInfo::acknowledge_report())]
+        pub fn acknowledge_report(
+            origin: OriginFor<T>,
+            report_id: ReportId,
+            acknowledgement: EncryptedMessage<T, I>,
+        ) -> DispatchResult {
+            T::ReportCommitteeOrigin::ensure_origin(origin)?;
+
+            let report = <Reports<T, I>>::take(report_id).ok_or(Error::<T, I>::ReportNotFound)?;
+            <OpenReports<T, I>>::mutate_exists(&report.key_image, |open| {
+                *open = open.map(|n| n.saturating_sub(1)).filter(|n| *n > 0);
+            });
+
+            Self::deposit_event(Event::ReportAcknowledged {
+                report_id,
+                key_image: report.key_image,
+                acknowledgement,
+            });
+
+            Ok(())
+        }
+
+        /// 按升级前的方式关闭升级前创建的投票：公开私钥并提交链下统计的计票结果。
+        /// 旧选票不能在链上解密计票，计票结果只检查是否符合投票配置。
+        /// 投票人数低于 `MinBallotsForBreakdown` 时与 `close_poll` 相同，私钥必须为空，
+        /// 只公布投票率。旧选票同时按位置逐张存入 `EncryptedVotes`
+        #[pallet::call_index(13)]
+        #[pallet::weight(T::WeightInfo::close_legacy_poll(T::MaxVotesPerPoll::get()))]
+        #[allow(clippy::useless_conversion)]
+        pub fn close_legacy_poll(
+            origin: OriginFor<T>,
+            poll_id: PollId,
+            encryption_private_key: Option<H256>,
+            mut tally: T::Tally,
+        ) -> DispatchResultWithPostInfo {
+            let mut poll = <Polls<T, I>>::get(poll_id).ok_or(Error::<T, I>::PollNotFound)?;
+            ensure!(
+                <LegacyPolls<T, I>>::contains_key(poll_id),
+                Error::<T, I>::NotLegacyPoll
+            );
+
+            T::ClosePollOrigin::ensure_origin(origin)?;
+
+            // 1. 投票人数不足时不公开私钥和计票结果
+            if Self::turnout(poll_id) < T::MinBallotsForBreakdown::get() {
+                ensure!(
+                    encryption_private_key.is_none(),
+                    Error::<T, I>::BreakdownWithheld
+                );
+                let tally = T::TallyHandler::new_tally(&poll.config)
+                    .map_err(|_| Error::<T, I>::InvalidTally)?;
+                let ballots = Self::move_legacy_ballots(poll_id);
+                <LegacyPolls<T, I>>::remove(poll_id);
+                let verdict = Outcome {
+                    breakdown_withheld: true,
+                    ..Default::default()
+                };
+                Self::conclude(poll_id, poll, tally, verdict);
+                return Ok(Some(T::WeightInfo::close_legacy_poll(ballots)).into());
+            }
+
+            // 2. 验证私钥是否匹配公钥
+            let encryption_private_key = encryption_private_key
+                .ok_or(Error::<T, I>::InvalidPrivateKey)?
+                .0;
+            let derived_pubkey = Self::derive_public_key(&encryption_private_key);
+            ensure!(
+                Some(derived_pubkey) == poll.encryption_public_key,
+                Error::<T, I>::InvalidPrivateKey
+            );
+
+            // 3. 检查提交的计票结果
+            T::TallyHandler::validate_tally(&poll.config, &tally)
+                .map_err(|_| Error::<T, I>::InvalidTally)?;
+
+            // 4. 迁移旧选票，公开私钥并关闭投票
+            let ballots = Self::move_legacy_ballots(poll_id);
+            <LegacyPolls<T, I>>::remove(poll_id);
+            poll.encryption_private_key = Some(encryption_private_key);
+            let verdict =
+                T::TallyHandler::finalize(&poll.config, &mut tally).unwrap_or_else(|_| {
+                    Self::deposit_event(Event::TallyFailed { poll_id });
+                    Outcome::default()
+                });
+            Self::conclude(poll_id, poll, tally, verdict);
+
+            Ok(Some(T::WeightInfo::close_legacy_poll(ballots)).into())
+        }
+
+        /// 把升级前已关闭的投票的选票按位置逐张存入 `EncryptedVotes`。
+        /// 升级时不迁移选票，每次调用只处理一个投票，任何人都可以调用
+        #[pallet::call_index(14)]
+        #[pallet::weight(T::WeightInfo::migrate_legacy_ballots(T::MaxVotesPerPoll::get()))]
+        #[allow(clippy::useless_conversion)]
+        pub fn migrate_legacy_ballots(
+            origin: OriginFor<T>,
+            poll_id: PollId,
+        ) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+
+            ensure!(
+                !<LegacyPolls<T, I>>::contains_key(poll_id),
+                Error::<T, I>::LegacyPoll
+            );
+            ensure!(
+                crate::migrations::v0::EncryptedVotes::<T, I>::contains_key(poll_id),
+                Error::<T, I>::NoLegacyBallots
+            );
+            let ballots = Self::move_legacy_ballots(poll_id);
+
+            Ok(Some(T::WeightInfo::migrate_legacy_ballots(ballots)).into())
+        }
+    }
+
+    impl<T: Config<I>, I: 'static> Pallet<T, I> {
+        /// 检查投票是否已过期
+        fn is_poll_expired(poll: &Poll<T, I>) -> bool {
+            match &poll.deadline {
+                Some(PollMoment::Block(deadline_block)) => {
+                    <frame_system::Pallet<T>>::block_number() > *deadline_block
+                }
+                Some(PollMoment::Timestamp(deadline_time)) => T::Time::now() > *deadline_time,
+                None => false,
+            }
+        }
+
+        /// 检查投票是否已到开放时间
+        fn is_poll_started(poll: &Poll<T, I>) -> bool {
+            poll.opens_at
+                .as_ref()
+                .is_none_or(|opens_at| Self::has_reached(opens_at))
+        }
+
+        /// 检查当前区块号或时间戳是否已到达给定时间点
+        fn has_reached(moment: &PollMomentOf<T, I>) -> bool {
+            match moment {
+                PollMoment::Block(block) => <frame_system::Pallet<T>>::block_number() >= *block,
+                PollMoment::Timestamp(time) => T::Time::now() >= *time,
+            }
+        }
+
+        /// 将通过的提案交给调度器，在 `EnactmentDelay` 个区块后执行
+        fn schedule_proposal(poll_id: PollId, call: BoundedCallOf<T>) {
+            if Self::schedule_call(call) {
+                Self::deposit_event(Event::ProposalScheduled { poll_id });
+            } else {
+                Self::deposit_event(Event::ProposalSchedulingFailed { poll_id });
+            }
+        }
+
+        /// 把按旧布局存放的选票逐张存入 `EncryptedVotes`，返回选票数。
+        /// `BallotCounts` 已在升级时写入
+        fn move_legacy_ballots(poll_id: PollId) -> u32 {
+            let ballots = crate::migrations::v0::EncryptedVotes::<T, I>::take(poll_id);
+            let count = ballots.len() as u32;
+            for (index, ballot) in ballots.into_iter().enumerate() {
+                <EncryptedVotes<T, I>>::insert(poll_id, index as u32, ballot);
+            }
+            count
+        }
+
+        /// 锁定附带调用的 Preimage，在投票结束或请愿达到门槛前不能被撤回
+        fn request_proposal(call: &BoundedCallOf<T>) {
+            if let Some(hash) = call.lookup_hash() {
+                T::Preimages::request(&hash);
+            }
+        }
+
+        /// 释放 `request_proposal` 的锁定。交给调度器的调用由调度器另行锁定
+        fn release_proposal(call: &BoundedCallOf<T>) {
+            if let Some(hash) = call.lookup_hash() {
+                T::Preimages::unrequest(&hash);
+            }
+        }
+
+        /// 将调用交给调度器，在 `EnactmentDelay` 个区块后以 `EnactmentOrigin` 执行
+        fn schedule_call(call: BoundedCallOf<T>) -> bool {
+            T::Scheduler::schedule(
+                DispatchTime::After(T::EnactmentDelay::get()),
+                None,
+                LOWEST_PRIORITY,
+                T::EnactmentOrigin::get(),
+                call,
+            )
+            .is_ok()
+        }
+
+        /// 计票完成后关闭投票：存储结果、退还押金、清理存储，并执行通过的提案
+        fn conclude(poll_id: PollId, mut poll: Poll<T, I>, tally: T::Tally, verdict: Outcome) {
+            // 1. 存储计票结果，并根据法定人数和通过门槛判定结果
+            let outcome = Self::compute_outcome(poll_id, &poll.config, verdict);
+            <PollVotes<T, I>>::insert(poll_id, tally.clone());
+            <PollOutcomes<T, I>>::insert(poll_id, outcome.clone());
+
+            // 2. 退还押金
+            T::Currency::unreserve(&poll.creator, poll.submission_deposit.amount);
+
+            // 3. 更新状态为 Closed
+            poll.status = PollStatus::Closed;
+            let private_key_revealed = poll.encryption_private_key;
+            <Polls<T, I>>::insert(poll_id, poll);
+
+            // 4. 清理存储
+            <PollRingId<T, I>>::remove(poll_id);
+            <PollMetadata<T, I>>::remove(poll_id);
+            <TallyCursors<T, I>>::remove(poll_id);
+
+            // 5. 发出"计票完成"事件
+            let passed = outcome.passed == Some(true);
+            Self::deposit_event(Event::PollClosed {
+                poll_id,
+                tally,
+                outcome,
+                private_key_revealed,
+            });
+
+            // 6. 投票通过时，经过延迟后执行附带的调用
+            if let Some(call) = <PollProposals<T, I>>::take(poll_id) {
+                if passed {
+                    Self::schedule_proposal(poll_id, call.clone());
+                }
+                Self::release_proposal(&call);
+            }
+        }
+
+        /// 根据投票率、法定人数和计票逻辑给出的判定 (`verdict`) 得出投票结果
+        fn compute_outcome(poll_id: PollId, config: &PollConfig, verdict: Outcome) -> Outcome {
+            let turnout = Self::turnout(poll_id);
+            let electorate = <PollRingId<T, I>>::get(poll_id)
+                .and_then(<RingGroups<T, I>>::get)
+                .map_or(0, |ring| ring.len() as u32);
+
+            let quorum_met = config
+                .quorum
+                .is_none_or(|quorum| turnout >= quorum.mul_ceil(electorate));
+            let passed = config
+                .threshold
+                .as_ref()
+                .map(|_| quorum_met && verdict.passed == Some(true));
+
+            Outcome {
+                turnout,
+                electorate,
+                quorum_met,
+                passed,
+                spoiled: <SpoiledBallots<T, I>>::decode_len(poll_id).unwrap_or(0) as u32,
+                ..verdict
+            }
+        }
+
+        /// 解密一张选票并计入计票结果，失败时返回无效原因
+        fn count_ballot(
+            config: &PollConfig,
+            private_key: &[u8; 32],
+            ballot: &EncryptedVote<T, I>,
+            tally: &mut T::Tally,
+        ) -> Result<(), SpoilReason> {
+            let plaintext = T::BallotCipher::decrypt(
+                private_key,
+                &ballot.ephemeral_public_key,
+                &ballot.ciphertext,
+                &ballot.auth_tag,
+            )
+            .ok_or(SpoilReason::Undecryptable)?;
+            let vote =
+                T::Vote::decode_all(&mut &plaintext[..]).map_err(|_| SpoilReason::Undecodable)?;
+            T::TallyHandler::update_tally(config, &vote, tally).map_err(|_| SpoilReason::Rejected)
+        }
+
+        /// 用存储中的公钥环验证 CLSAG 签名，返回签名和第一层的密钥镜像
+        fn verify_ring_signature(
+            ring_id: RingId,
+            message: &[u8],
+            challenge: H256,
+            responses: BoundedVec<H256, T::MaxMembersInRing>,
+            key_images: BoundedVec<H256, T::NumRingLayers>,
+        ) -> Result<(CLSAGWrapper<T, I>, CompressedRistrettoWrapper), Error<T, I>> {
+            // 1. 从存储中获取权威的公钥环
+            let ring_matrix =
+                <RingGroups<T, I>>::get(ring_id).ok_or(Error::<T, I>::RingGroupNotFound)?;
+
+            // 2. 验证输入长度
             ensure!(
                 responses.len() as u32 == ring_matrix.len() as u32,
-                Error::<T>::BadMetadata
+                Error::<T, I>::BadMetadata
             );
-            ensure!(ring_matrix.len() as u32 > 0, Error::<T>::BadMetadata);
+            ensure!(ring_matrix.len() as u32 > 0, Error::<T, I>::BadMetadata);
             for row in &ring_matrix {
                 ensure!(
                     row.len() as u32 == T::NumRingLayers::get(),
-                    Error::<T>::BadMetadata
+                    Error::<T, I>::BadMetadata
                 );
             }
             ensure!(
                 key_images.len() as u32 == T::NumRingLayers::get(),
-                Error::<T>::BadMetadata
+                Error::<T, I>::BadMetadata
             );
 
-            // 5. 构建待签名消息：hash(R || Cipher || Tag)
-            let ephemeral_public_key = ephemeral_public_key.0;
-            let auth_tag = auth_tag.0;
-            let message = {
-                let mut msg = Vec::new();
-                msg.extend_from_slice(&ephemeral_public_key);
-                msg.extend_from_slice(&ciphertext);
-                msg.extend_from_slice(&auth_tag);
-                msg
-            };
-
-            // 6.  转换类型 (Responses, Ring, KeyImages)
+            // 3. 转换类型 (Responses, Ring, KeyImages)
             let challenge = ScalarWrapper(challenge.0);
 
             let responses: BoundedVec<ScalarWrapper, T::MaxMembersInRing> = responses
@@ -491,7 +1730,7 @@ pub mod pallet {
                 .map(|h| ScalarWrapper(h.0))
                 .collect::<Vec<ScalarWrapper>>()
                 .try_into()
-                .map_err(|_| Error::<T>::BadMetadata)?;
+                .map_err(|_| Error::<T, I>::BadMetadata)?;
 
             let ring: BoundedVec<
                 BoundedVec<CompressedRistrettoWrapper, T::NumRingLayers>,
@@ -503,61 +1742,191 @@ pub mod pallet {
                 .map(|h| CompressedRistrettoWrapper(h.0))
                 .collect::<Vec<CompressedRistrettoWrapper>>()
                 .try_into()
-                .map_err(|_| Error::<T>::BadMetadata)?;
+                .map_err(|_| Error::<T, I>::BadMetadata)?;
 
             let main_key_image = key_images[0].clone();
 
-            let signature: CLSAGWrapper<T> = CLSAGWrapper {
+            let signature: CLSAGWrapper<T, I> = CLSAGWrapper {
                 challenge,
                 responses,
                 ring,
                 key_images,
             };
 
-            // 7. 验证签名
+            // 4. 验证签名
             let signature_clsag = CLSAG::from(signature.clone());
-            let is_valid = CLSAG::verify::<Sha512>(signature_clsag, &message);
-            ensure!(is_valid, Error::<T>::InvalidSignature);
+            let is_valid = CLSAG::verify::<Sha512>(signature_clsag, message);
+            ensure!(is_valid, Error::<T, I>::InvalidSignature);
 
-            // 8. 检查双重投票
-            ensure!(
-                !<UsedKeyImages<T>>::contains_key(&poll_id, &main_key_image),
-                Error::<T>::AlreadyVoted
-            );
-            <UsedKeyImages<T>>::insert(&poll_id, &main_key_image, ());
+            Ok((signature, main_key_image))
+        }
 
-            // 9.  存储加密投票
-            let encrypted_vote = EncryptedVote {
+        /// 投票规定的选票密文长度
+        fn ballot_length(config: &PollConfig) -> u32 {
+            config.ballot_length.unwrap_or_else(T::MaxVoteSize::get)
+        }
+
+        /// 已提交的选票数（重新投票替换的选票只计一次）
+        fn turnout(poll_id: PollId) -> u32 {
+            <BallotCounts<T, I>>::get(poll_id)
+        }
+
+        /// 话题签名使用的话题，包含 pallet 在 runtime 中的位置，不同实例的同一话题 ID 互不可链接
+        pub fn topic_scope(topic_id: TopicId) -> Vec<u8> {
+            (
+                b"topic",
+                <Self as PalletInfoAccess>::index() as u32,
+                topic_id,
+            )
+                .encode()
+        }
+
+        /// 投票时签名的消息，绑定投票和选票密文，包含 pallet 在 runtime 中的位置，
+        /// 不能与其他投票、请愿、举报或链下身份验证的签名混用
+        pub fn vote_message(
+            poll_id: PollId,
+            ephemeral_public_key: &[u8; 32],
+            ciphertext: &[u8],
+            auth_tag: &[u8; 16],
+        ) -> Vec<u8> {
+            (
+                b"vote",
+                <Self as PalletInfoAccess>::index() as u32,
+                poll_id,
                 ephemeral_public_key,
                 ciphertext,
                 auth_tag,
-                ring_signature: signature,
-            };
+            )
+                .encode()
+        }
 
-            <EncryptedVotes<T>>::try_mutate(poll_id, |votes| {
-                votes
-                    .try_push(encrypted_vote)
-                    .map_err(|_| Error::<T>::TooManyVotes)
-            })?;
+        /// 签署请愿时签名的消息，包含 pallet 在 runtime 中的位置，不能与选票或其他请愿的签名混用
+        pub fn petition_message(petition_id: PetitionId) -> Vec<u8> {
+            (
+                b"petition",
+                <Self as PalletInfoAccess>::index() as u32,
+                petition_id,
+            )
+                .encode()
+        }
 
-            Self::deposit_event(Event::EncryptedVoteCast {
-                poll_id,
-                key_image: main_key_image,
-            });
+        /// 举报签名的话题。举报人在同一公钥环中的举报共用一个密钥镜像，
+        /// 用于限制未回执的举报数，但与全局密钥镜像无关
+        pub fn report_scope(ring_id: RingId) -> Vec<u8> {
+            (
+                b"report",
+                <Self as PalletInfoAccess>::index() as u32,
+                ring_id,
+            )
+                .encode()
+        }
+
+        /// 提交举报时签名的消息，绑定举报内容、回执公钥和加密时使用的审查委员会公钥
+        pub fn report_message(
+            ring_id: RingId,
+            committee_key: &[u8; 32],
+            content: &EncryptedMessage<T, I>,
+            reply_public_key: &[u8; 32],
+        ) -> Vec<u8> {
+            (
+                b"report",
+                <Self as PalletInfoAccess>::index() as u32,
+                ring_id,
+                committee_key,
+                content,
+                reply_public_key,
+            )
+                .encode()
+        }
+
+        /// 链下服务验证环成员身份时签名的消息。`domain` 标识服务，`nonce` 为服务下发的一次性挑战，
+        /// 签名不能在其他服务、其他挑战或链上交易中重放
+        pub fn membership_message(
+            ring_id: RingId,
+            domain: &[u8],
+            nonce: &[u8],
+            message: &[u8],
+        ) -> Vec<u8> {
+            (
+                b"membership",
+                <Self as PalletInfoAccess>::index() as u32,
+                ring_id,
+                domain,
+                nonce,
+                message,
+            )
+                .encode()
+        }
+
+        /// 链下身份验证签名使用的话题。密钥镜像只在同一服务内可链接，
+        /// 与投票、请愿、举报和其他服务中的密钥镜像互不可链接
+        pub fn membership_scope(ring_id: RingId, domain: &[u8]) -> Vec<u8> {
+            (
+                b"membership",
+                <Self as PalletInfoAccess>::index() as u32,
+                ring_id,
+                domain,
+            )
+                .encode()
+        }
 
+        /// 验证签名者是环中的某个成员（签名见 `topic_sig`，话题由 `membership_scope` 给出），
+        /// 签名有效时返回该服务中的密钥镜像，否则为 `None`
+        pub fn verify_membership(
+            ring_id: RingId,
+            domain: &[u8],
+            nonce: &[u8],
+            message: &[u8],
+            challenge: H256,
+            responses: Vec<H256>,
+            key_image: H256,
+        ) -> Option<H256> {
+            Self::verify_topic_signature(
+                ring_id,
+                &Self::membership_scope(ring_id, domain),
+                &Self::membership_message(ring_id, domain, nonce, message),
+                challenge,
+                &responses,
+                key_image,
+            )
+            .ok()?;
+            Some(key_image)
+        }
+
+        /// 用公钥环中每个成员的第一个公钥验证话题签名（见 `topic_sig`）
+        fn verify_topic_signature(
+            ring_id: RingId,
+            topic: &[u8],
+            message: &[u8],
+            challenge: H256,
+            responses: &[H256],
+            key_image: H256,
+        ) -> Result<(), Error<T, I>> {
+            let ring_matrix =
+                <RingGroups<T, I>>::get(ring_id).ok_or(Error::<T, I>::RingGroupNotFound)?;
+            let ring = ring_matrix
+                .iter()
+                .map(|row| row.first().map(|pk| pk.0))
+                .collect::<Option<Vec<_>>>()
+                .ok_or(Error::<T, I>::BadMetadata)?;
+            ensure!(responses.len() == ring.len(), Error::<T, I>::BadMetadata);
+
+            let signature = topic_sig::TopicSignature {
+                challenge: challenge.0,
+                responses: responses.iter().map(|r| r.0).collect(),
+                key_image: key_image.0,
+            };
+            ensure!(
+                topic_sig::verify(&ring, topic, message, &signature),
+                Error::<T, I>::InvalidSignature
+            );
             Ok(())
         }
-    }
 
-    impl<T: Config> Pallet<T> {
-        /// 检查投票是否已过期
-        fn is_poll_expired(poll: &Poll<T>) -> bool {
-            if let Some(deadline_block) = poll.deadline {
-                let current_block = <frame_system::Pallet<T>>::block_number();
-                current_block > deadline_block
-            } else {
-                false
-            }
+        /// 已关闭投票的计票结果，投票尚未关闭时为 `None`
+        pub fn closed_tally(poll_id: PollId) -> Option<T::Tally> {
+            let poll = <Polls<T, I>>::get(poll_id)?;
+            (poll.status == PollStatus::Closed).then(|| <PollVotes<T, I>>::get(poll_id))
         }
 
         /// 从私钥派生公钥
@@ -0,0 +1,189 @@
+//! 存储迁移
+
+use crate::*;
+use frame::deps::frame_support::{
+    migrations::VersionedMigration, storage_alias, traits::UncheckedOnRuntimeUpgrade,
+};
+use frame::prelude::*;
+use scale_info::prelude::vec::Vec;
+
+#[cfg(feature = "try-runtime")]
+use frame::try_runtime::TryRuntimeError;
+
+/// 版本 0：只有第一版的投票结构，所有选票存放在同一个 `BoundedVec` 中
+pub mod v0 {
+    use super::*;
+
+    /// 版本 0 的投票：截止时间只能按区块号计时，没有开放时间和投票配置
+    #[derive(Clone, Debug, Encode, Decode, TypeInfo, PartialEq, Eq, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T, I))]
+    pub struct Poll<T: Config<I>, I: 'static = ()> {
+        pub creator: T::AccountId,
+        pub description: BoundedVec<u8, T::MaxDescriptionLength>,
+        pub status: PollStatus,
+        pub submission_deposit: DepositOf<T, I>,
+        pub deadline: Option<BlockNumberFor<T>>,
+        pub encryption_public_key: Option<[u8; 32]>,
+        pub encryption_private_key: Option<[u8; 32]>,
+    }
+
+    #[storage_alias]
+    pub type Polls<T: Config<I>, I: 'static> =
+        StorageMap<Pallet<T, I>, Twox64Concat, PollId, Poll<T, I>, OptionQuery>;
+
+    /// 版本 0 的选票。升级时不迁移，由 `close_legacy_poll` 或 `migrate_legacy_ballots`
+    /// 按投票逐个移入 `EncryptedVotes`
+    #[storage_alias]
+    pub type EncryptedVotes<T: Config<I>, I: 'static> = StorageMap<
+        Pallet<T, I>,
+        Twox64Concat,
+        PollId,
+        BoundedVec<EncryptedVote<T, I>, <T as Config<I>>::MaxVotesPerPoll>,
+        ValueQuery,
+    >;
+}
+
+/// 版本 1：投票增加开放时间、按时间戳计时和投票配置，选票按位置单独存储
+pub mod v1 {
+    use super::*;
+
+    /// 把版本 0 的计票结果 (`PollVotes`) 转换为当前的计票结果
+    pub trait TallyUpgrade<Tally> {
+        /// 版本 0 的计票结果
+        type Old: Decode;
+
+        /// 转换一个计票结果。`closed` 表示投票已关闭，需要得出最终统计
+        fn upgrade(old: Self::Old, closed: bool) -> Option<Tally>;
+    }
+
+    /// 计票结果的编码没有变化
+    impl<Tally: Decode> TallyUpgrade<Tally> for () {
+        type Old = Tally;
+
+        fn upgrade(old: Tally, _closed: bool) -> Option<Tally> {
+            Some(old)
+        }
+    }
+
+    /// 评分投票：版本 0 的每个问题只有五个评分等级的计数，没有统计量
+    pub struct EvaluativeTally;
+
+    impl TallyUpgrade<evaluative_voting::Tally> for EvaluativeTally {
+        type Old = Vec<[u32; 5]>;
+
+        fn upgrade(old: Vec<[u32; 5]>, closed: bool) -> Option<evaluative_voting::Tally> {
+            let mut tally: evaluative_voting::Tally = old
+                .into_iter()
+                .map(evaluative_voting::QuestionStats::from_distribution)
+                .collect::<Vec<_>>()
+                .try_into()
+                .ok()?;
+            if closed {
+                evaluative_voting::TallyHandler::finalize(&PollConfig::default(), &mut tally)
+                    .ok()?;
+            }
+            Some(tally)
+        }
+    }
+
+    /// 把版本 0 的投票和计票结果迁移到版本 1 的布局，不经过版本检查。
+    ///
+    /// 读写次数与投票数成正比，不读取选票本身：仍在进行的投票记入 `LegacyPolls`，
+    /// 之后按旧的方式关闭；选票留在旧布局中，关闭或调用 `migrate_legacy_ballots`
+    /// 时再按投票逐个迁移
+    pub struct UncheckedMigrateV0ToV1<T, I = (), U = ()>(PhantomData<(T, I, U)>);
+
+    impl<T: Config<I>, I: 'static, U: TallyUpgrade<T::Tally>> UncheckedOnRuntimeUpgrade
+        for UncheckedMigrateV0ToV1<T, I, U>
+    {
+        fn on_runtime_upgrade() -> Weight {
+            let mut reads = 0u64;
+            let mut writes = 0u64;
+
+            // 1. 旧投票的截止时间按区块号计时，使用默认配置。仍在进行的投票标记为旧投票，
+            //    并根据旧选票的数量写入 `BallotCounts`
+            Polls::<T, I>::translate::<v0::Poll<T, I>, _>(|poll_id, old| {
+                reads += 2;
+                writes += 1;
+                if old.status == PollStatus::Voting {
+                    LegacyPolls::<T, I>::insert(poll_id, ());
+                    writes += 1;
+                }
+                if let Some(count) = v0::EncryptedVotes::<T, I>::decode_len(poll_id) {
+                    BallotCounts::<T, I>::insert(poll_id, count as u32);
+                    writes += 1;
+                }
+                Some(Poll {
+                    creator: old.creator,
+                    description: old.description,
+                    status: old.status,
+                    submission_deposit: old.submission_deposit,
+                    opens_at: None,
+                    deadline: old.deadline.map(PollMoment::Block),
+                    encryption_public_key: old.encryption_public_key,
+                    encryption_private_key: old.encryption_private_key,
+                    config: PollConfig::default(),
+                })
+            });
+
+            // 2. 计票结果转换为当前的编码，已关闭投票的计票结果同时得出最终统计
+            PollVotes::<T, I>::translate::<U::Old, _>(|poll_id, old| {
+                reads += 2;
+                writes += 1;
+                let closed = Polls::<T, I>::get(poll_id)
+                    .is_some_and(|poll| poll.status == PollStatus::Closed);
+                U::upgrade(old, closed)
+            });
+
+            T::DbWeight::get().reads_writes(reads, writes)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            let polls = v0::Polls::<T, I>::iter_values().collect::<Vec<_>>();
+            let voting = polls
+                .iter()
+                .filter(|poll| poll.status == PollStatus::Voting)
+                .count() as u32;
+            let ballots = v0::Polls::<T, I>::iter_keys()
+                .filter_map(v0::EncryptedVotes::<T, I>::decode_len)
+                .sum::<usize>() as u32;
+            let tallies = PollVotes::<T, I>::iter_keys().count() as u32;
+            Ok((polls.len() as u32, voting, ballots, tallies).encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let (polls, voting, ballots, tallies): (u32, u32, u32, u32) =
+                Decode::decode(&mut &state[..])
+                    .map_err(|_| TryRuntimeError::Other("cannot decode pre-upgrade state"))?;
+
+            ensure!(
+                Polls::<T, I>::iter().count() as u32 == polls,
+                "some polls failed to migrate"
+            );
+            ensure!(
+                LegacyPolls::<T, I>::iter_keys().count() as u32 == voting,
+                "some ongoing polls were not marked as legacy"
+            );
+            ensure!(
+                BallotCounts::<T, I>::iter_values().sum::<u32>() == ballots,
+                "ballot counts do not match the legacy ballots"
+            );
+            ensure!(
+                PollVotes::<T, I>::iter().count() as u32 == tallies,
+                "some tallies failed to migrate"
+            );
+            Ok(())
+        }
+    }
+
+    /// 存储版本为 0 时迁移到版本 1，并更新存储版本。`U` 给出计票结果的转换方式
+    pub type MigrateV0ToV1<T, I = (), U = ()> = VersionedMigration<
+        0,
+        1,
+        UncheckedMigrateV0ToV1<T, I, U>,
+        Pallet<T, I>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
@@ -1,6 +1,6 @@
-use crate::{types::simple_voting::*, PollId};
+use crate::{types::simple_voting::*, BallotSchema, PollConfig, PollId, Question};
 use frame::prelude::*;
-use scale_info::prelude::vec::Vec;
+use scale_info::prelude::{vec, vec::Vec};
 
 use curve25519_dalek::ristretto::RistrettoPoint;
 use curve25519_dalek::scalar::Scalar;
@@ -9,21 +9,275 @@ use nazgul::traits::{Sign, Verify};
 use rand_core::OsRng;
 use sha2::Sha512;
 
+/// 由成员公钥组成的公钥环
+pub type Ring<T, I> = BoundedVec<
+    BoundedVec<H256, <T as crate::pallet::Config<I>>::NumRingLayers>,
+    <T as crate::pallet::Config<I>>::MaxMembersInRing,
+>;
+
+/// CLSAG 签名的挑战、响应、公钥环和密钥镜像
+pub type SignatureParts<T, I> = (
+    H256,
+    BoundedVec<H256, <T as crate::pallet::Config<I>>::MaxMembersInRing>,
+    Ring<T, I>,
+    BoundedVec<H256, <T as crate::pallet::Config<I>>::NumRingLayers>,
+);
+
+/// 话题签名的公钥环、挑战、响应和密钥镜像
+pub type TopicSignatureParts<T, I> = (
+    Ring<T, I>,
+    H256,
+    BoundedVec<H256, <T as crate::pallet::Config<I>>::MaxMembersInRing>,
+    H256,
+);
+
 #[cfg(test)]
 pub use tests::*;
 
+pub fn gen_ring<T: crate::pallet::Config<I>, I: 'static>() -> Ring<T, I> {
+    let mut csprng = OsRng;
+    let nr = T::MaxMembersInRing::get() as usize;
+    let nc = T::NumRingLayers::get() as usize;
+
+    let ring: Vec<Vec<RistrettoPoint>> = (0..nr)
+        .map(|_| {
+            (0..nc)
+                .map(|_| RistrettoPoint::random(&mut csprng))
+                .collect()
+        })
+        .collect();
+
+    let ring: Ring<T, I> = ring
+        .iter()
+        .map(|layer| {
+            layer
+                .iter()
+                .map(|pk| pk.compress().to_bytes().into())
+                .collect::<Vec<H256>>()
+                .try_into()
+                .unwrap()
+        })
+        .collect::<Vec<BoundedVec<H256, T::NumRingLayers>>>()
+        .try_into()
+        .unwrap();
+
+    ring
+}
+
+/// 带问卷结构的投票配置：一道三选一的单选题和一道 1 到 5 分的评分题
+pub fn gen_poll_config() -> PollConfig {
+    let questions = vec![
+        Question::Choice { options: 3 },
+        Question::Range { min: 1, max: 5 },
+    ];
+    PollConfig {
+        schema: Some(BallotSchema {
+            questions: questions.try_into().unwrap(),
+        }),
+        ..Default::default()
+    }
+}
+
+/// 用投票的加密公钥加密选票并填充到 `MaxVoteSize`，每次使用新的临时密钥
+#[cfg(test)]
+pub fn seal(encryption_pubkey: &H256, ballot: &[u8]) -> crate::ecies::Sealed {
+    let mut csprng = OsRng;
+    let ephemeral_secret = Scalar::random(&mut csprng).to_bytes();
+    let length = <<Test as crate::Config>::MaxVoteSize as Get<u32>>::get() as usize;
+    crate::ecies::encrypt_ballot(&encryption_pubkey.0, &ephemeral_secret, ballot, length).unwrap()
+}
+
+/// 为加密投票生成签名（对加密数据签名）
+pub fn gen_signature_for_encrypted<T: crate::pallet::Config<I>, I: 'static>(
+    poll_id: PollId,
+    _vote: Vote, // 实际不用于消息，只是为了保持接口一致
+    ephemeral_pubkey: [u8; 32],
+    ciphertext: &[u8],
+    auth_tag: [u8; 16],
+) -> SignatureParts<T, I> {
+    let (ks, decoys) = gen_signer_and_decoys::<T, I>();
+    gen_signature_for_encrypted_with::<T, I>(
+        poll_id,
+        ks,
+        decoys,
+        ephemeral_pubkey,
+        ciphertext,
+        auth_tag,
+    )
+}
+
+/// 生成签名者私钥和其余环成员（诱饵）的公钥
+pub fn gen_signer_and_decoys<T: crate::pallet::Config<I>, I: 'static>(
+) -> (Vec<Scalar>, Vec<Vec<RistrettoPoint>>) {
+    gen_signer_and_decoys_of_size::<T, I>(T::MaxMembersInRing::get())
+}
+
+/// 生成签名者私钥和其余环成员（诱饵）的公钥，环中共有 `members` 个成员
+pub fn gen_signer_and_decoys_of_size<T: crate::pallet::Config<I>, I: 'static>(
+    members: u32,
+) -> (Vec<Scalar>, Vec<Vec<RistrettoPoint>>) {
+    let mut csprng = OsRng;
+    let nr = members as usize;
+    let nc = T::NumRingLayers::get() as usize;
+
+    let ks: Vec<Scalar> = (0..nc).map(|_| Scalar::random(&mut csprng)).collect();
+    let decoys: Vec<Vec<RistrettoPoint>> = (0..(nr - 1))
+        .map(|_| {
+            (0..nc)
+                .map(|_| RistrettoPoint::random(&mut csprng))
+                .collect()
+        })
+        .collect();
+
+    (ks, decoys)
+}
+
+/// 使用给定的签名者私钥为加密投票生成签名，同一私钥多次签名得到相同的密钥镜像
+pub fn gen_signature_for_encrypted_with<T: crate::pallet::Config<I>, I: 'static>(
+    poll_id: PollId,
+    ks: Vec<Scalar>,
+    decoys: Vec<Vec<RistrettoPoint>>,
+    ephemeral_pubkey: [u8; 32],
+    ciphertext: &[u8],
+    auth_tag: [u8; 16],
+) -> SignatureParts<T, I> {
+    let message =
+        crate::Pallet::<T, I>::vote_message(poll_id, &ephemeral_pubkey, ciphertext, &auth_tag);
+
+    sign_message::<T, I>(ks, decoys, &message)
+}
+
+/// 以环中第 1 个位置的成员身份对任意消息生成 CLSAG 签名
+pub fn sign_message<T: crate::pallet::Config<I>, I: 'static>(
+    ks: Vec<Scalar>,
+    decoys: Vec<Vec<RistrettoPoint>>,
+    message: &[u8],
+) -> SignatureParts<T, I> {
+    sign_message_at::<T, I>(ks, decoys, 1, message)
+}
+
+/// 以环中第 `secret_index` 个位置的成员身份对任意消息生成 CLSAG 签名，
+/// 签名者的公钥插入到 `decoys` 的该位置
+pub fn sign_message_at<T: crate::pallet::Config<I>, I: 'static>(
+    ks: Vec<Scalar>,
+    decoys: Vec<Vec<RistrettoPoint>>,
+    secret_index: usize,
+    message: &[u8],
+) -> SignatureParts<T, I> {
+    let message = message.to_vec();
+
+    let signature = CLSAG::sign::<Sha512, OsRng>(ks, decoys, secret_index, &message);
+    let result = CLSAG::verify::<Sha512>(signature.clone(), &message);
+    assert!(result);
+
+    let challenge: H256 = signature.challenge.to_bytes().into();
+
+    let responses: BoundedVec<H256, T::MaxMembersInRing> = signature
+        .responses
+        .iter()
+        .map(|r| r.to_bytes().into())
+        .collect::<Vec<H256>>()
+        .try_into()
+        .unwrap();
+
+    let ring: Ring<T, I> = signature
+        .ring
+        .iter()
+        .map(|layer| {
+            layer
+                .iter()
+                .map(|pk| pk.compress().to_bytes().into())
+                .collect::<Vec<H256>>()
+                .try_into()
+                .unwrap()
+        })
+        .collect::<Vec<BoundedVec<H256, T::NumRingLayers>>>()
+        .try_into()
+        .unwrap();
+
+    let key_images: BoundedVec<H256, T::NumRingLayers> = signature
+        .key_images
+        .iter()
+        .map(|ki| ki.compress().to_bytes().into())
+        .collect::<Vec<H256>>()
+        .try_into()
+        .unwrap();
+
+    (challenge, responses, ring, key_images)
+}
+
+/// 以环中第 1 个位置的成员身份在话题中签名（见 `topic_sig`），签名只用到每个成员的第一个公钥。
+/// 返回可直接注册的公钥环，以及挑战、响应和密钥镜像
+pub fn sign_topic_message<T: crate::pallet::Config<I>, I: 'static>(
+    ks: &[Scalar],
+    decoys: &[Vec<RistrettoPoint>],
+    topic: &[u8],
+    message: &[u8],
+) -> TopicSignatureParts<T, I> {
+    use curve25519_dalek::constants::RISTRETTO_BASEPOINT_POINT;
+
+    let secret_index = 1;
+    let mut rows = decoys.to_vec();
+    rows.insert(
+        secret_index,
+        ks.iter().map(|k| k * RISTRETTO_BASEPOINT_POINT).collect(),
+    );
+
+    let ring: Ring<T, I> = rows
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|pk| pk.compress().to_bytes().into())
+                .collect::<Vec<H256>>()
+                .try_into()
+                .unwrap()
+        })
+        .collect::<Vec<BoundedVec<H256, T::NumRingLayers>>>()
+        .try_into()
+        .unwrap();
+    let keys: Vec<[u8; 32]> = ring.iter().map(|row| row[0].0).collect();
+
+    let signature = crate::topic_sig::sign(
+        &mut OsRng,
+        &keys,
+        secret_index,
+        &ks[0].to_bytes(),
+        topic,
+        message,
+    )
+    .unwrap();
+    assert!(crate::topic_sig::verify(&keys, topic, message, &signature));
+
+    let responses: BoundedVec<H256, T::MaxMembersInRing> = signature
+        .responses
+        .iter()
+        .map(|r| (*r).into())
+        .collect::<Vec<H256>>()
+        .try_into()
+        .unwrap();
+
+    (
+        ring,
+        signature.challenge.into(),
+        responses,
+        signature.key_image.into(),
+    )
+}
+
 #[cfg(test)]
 pub mod tests {
     use super::*;
     use crate as ring_sig_voting;
-    use crate::RingId;
+    use crate::schema_voting;
+    use frame::deps::frame_support::traits::EqualPrivilegeOnly;
     use frame::{runtime::prelude::*, testing_prelude::*};
-    use polkadot_sdk::{pallet_balances, pallet_preimage};
+    use polkadot_sdk::{pallet_balances, pallet_preimage, pallet_scheduler, pallet_timestamp};
 
     pub const ALICE: u64 = 1;
     pub const BOB: u64 = 2;
     pub const INITIAL_BALANCE: u64 = 1_000_000_000_000_000;
 
+    type Balance = u64;
     type Block = frame_system::mocking::MockBlock<Test>;
 
     // Configure a mock runtime to test the pallet.
@@ -54,6 +308,15 @@ pub mod tests {
 
         #[runtime::pallet_index(3)]
         pub type RingSigVoting = ring_sig_voting;
+
+        #[runtime::pallet_index(4)]
+        pub type Timestamp = pallet_timestamp;
+
+        #[runtime::pallet_index(5)]
+        pub type Scheduler = pallet_scheduler;
+
+        #[runtime::pallet_index(6)]
+        pub type CourseEvaluation = ring_sig_voting<Instance1>;
     }
 
     // System pallet configuration
@@ -76,30 +339,119 @@ pub mod tests {
         type Consideration = ();
     }
 
+    #[derive_impl(pallet_timestamp::config_preludes::TestDefaultConfig)]
+    impl pallet_timestamp::Config for Test {}
+
+    parameter_types! {
+        pub MaximumSchedulerWeight: Weight = Weight::MAX;
+    }
+
+    impl pallet_scheduler::Config for Test {
+        type RuntimeEvent = RuntimeEvent;
+        type RuntimeOrigin = RuntimeOrigin;
+        type PalletsOrigin = OriginCaller;
+        type RuntimeCall = RuntimeCall;
+        type MaximumWeight = MaximumSchedulerWeight;
+        type ScheduleOrigin = EnsureRoot<u64>;
+        type MaxScheduledPerBlock = ConstU32<10>;
+        type WeightInfo = ();
+        type OriginPrivilegeCmp = EqualPrivilegeOnly;
+        type Preimages = Preimage;
+        type BlockNumberProvider = System;
+    }
 
     parameter_types! {
 	      pub const SubmissionDeposit: Balance = 10;
 	      pub const ClosureIncentive: Balance = 1000;
+	      pub EnactmentOrigin: OriginCaller = frame_system::RawOrigin::Root.into();
+	      pub static MinBallotsForBreakdown: u32 = 0;
+    }
+
+    ord_parameter_types! {
+        pub const Proposer: u64 = ALICE;
     }
 
     impl ring_sig_voting::Config for Test {
         type RuntimeEvent = RuntimeEvent;
         type Currency = Balances;
         type Preimages = pallet_preimage::Pallet<Self>;
+        type Time = Timestamp;
         type SubmissionDeposit = SubmissionDeposit;
         type CreatePollOrigin = frame_system::EnsureSigned<Self::AccountId>;
+        type ProposalOrigin = EnsureSignedBy<Proposer, u64>;
         type ClosePollOrigin = EnsureRoot<u64>;
         type RingAdminOrigin = frame_system::EnsureSigned<Self::AccountId>;
+        type ReportCommitteeOrigin = EnsureRoot<u64>;
+        type Scheduler = Scheduler;
+        type EnactmentOrigin = EnactmentOrigin;
+        type EnactmentDelay = ConstU64<2>;
         type Vote = Vote;
         type Tally = Tally;
         type TallyHandler = TallyHandler;
+        type BallotCipher = ring_sig_voting::ecies::Ecies;
         type MaxDescriptionLength = ConstU32<256>;
         type MaxMembersInRing = ConstU32<128>;
         type NumRingLayers = ConstU32<1>;
         type ClosureIncentive = ClosureIncentive;
         type MaxVoteSize = ConstU32<64>;
         type MaxVotesPerPoll = ConstU32<1000>;
+        type MinBallotsForBreakdown = MinBallotsForBreakdown;
+        type MaxMessageLength = ConstU32<512>;
+        type MaxReportSize = ConstU32<256>;
+        type MaxOpenReports = ConstU32<2>;
         type WeightInfo = ();
+        #[cfg(feature = "runtime-benchmarks")]
+        type BenchmarkHelper = VoteHelper;
+    }
+
+    // 第二个实例：课程评价，每个投票按自己的问卷计票
+    impl ring_sig_voting::Config<ring_sig_voting::Instance1> for Test {
+        type RuntimeEvent = RuntimeEvent;
+        type Currency = Balances;
+        type Preimages = pallet_preimage::Pallet<Self>;
+        type Time = Timestamp;
+        type SubmissionDeposit = SubmissionDeposit;
+        type CreatePollOrigin = frame_system::EnsureSigned<Self::AccountId>;
+        type ProposalOrigin = EnsureSignedBy<Proposer, u64>;
+        type ClosePollOrigin = EnsureRoot<u64>;
+        type RingAdminOrigin = frame_system::EnsureSigned<Self::AccountId>;
+        type ReportCommitteeOrigin = EnsureRoot<u64>;
+        type Scheduler = Scheduler;
+        type EnactmentOrigin = EnactmentOrigin;
+        type EnactmentDelay = ConstU64<2>;
+        type Vote = schema_voting::Vote;
+        type Tally = schema_voting::Tally;
+        type TallyHandler = schema_voting::TallyHandler;
+        type BallotCipher = ring_sig_voting::ecies::Ecies;
+        type MaxDescriptionLength = ConstU32<256>;
+        type MaxMembersInRing = ConstU32<128>;
+        type NumRingLayers = ConstU32<1>;
+        type ClosureIncentive = ClosureIncentive;
+        type MaxVoteSize = ConstU32<64>;
+        type MaxVotesPerPoll = ConstU32<1000>;
+        type MinBallotsForBreakdown = ConstU32<0>;
+        type MaxMessageLength = ConstU32<512>;
+        type MaxReportSize = ConstU32<256>;
+        type MaxOpenReports = ConstU32<2>;
+        type WeightInfo = ();
+        #[cfg(feature = "runtime-benchmarks")]
+        type BenchmarkHelper = VoteHelper;
+    }
+
+    /// 基准测试用的选票，符合 `gen_poll_config`
+    #[cfg(feature = "runtime-benchmarks")]
+    pub struct VoteHelper;
+    #[cfg(feature = "runtime-benchmarks")]
+    impl ring_sig_voting::BenchmarkHelper<Vote> for VoteHelper {
+        fn vote() -> Vote {
+            Vote::Yea
+        }
+    }
+    #[cfg(feature = "runtime-benchmarks")]
+    impl ring_sig_voting::BenchmarkHelper<schema_voting::Vote> for VoteHelper {
+        fn vote() -> schema_voting::Vote {
+            vec![0, 1].try_into().unwrap()
+        }
     }
 
     // Test externalities initialization
@@ -118,179 +470,3 @@ pub mod tests {
         storage.into()
     }
 }
-
-pub fn gen_ring<T: crate::pallet::Config>(
-) -> BoundedVec<BoundedVec<H256, T::NumRingLayers>, T::MaxMembersInRing> {
-    let mut csprng = OsRng;
-    let nr = T::MaxMembersInRing::get() as usize;
-    let nc = T::NumRingLayers::get() as usize;
-
-    let ring: Vec<Vec<RistrettoPoint>> = (0..nr)
-        .map(|_| {
-            (0..nc)
-                .map(|_| RistrettoPoint::random(&mut csprng))
-                .collect()
-        })
-        .collect();
-
-    let ring: BoundedVec<BoundedVec<H256, T::NumRingLayers>, T::MaxMembersInRing> = ring
-        .iter()
-        .map(|layer| {
-            layer
-                .iter()
-                .map(|pk| pk.compress().to_bytes().into())
-                .collect::<Vec<H256>>()
-                .try_into()
-                .unwrap()
-        })
-        .collect::<Vec<BoundedVec<H256, T::NumRingLayers>>>()
-        .try_into()
-        .unwrap();
-
-    ring
-}
-
-pub fn gen_signature<T: crate::pallet::Config>(
-    poll_id: PollId,
-    vote: Vote,
-) -> (
-    H256,
-    BoundedVec<H256, T::MaxMembersInRing>,
-    BoundedVec<BoundedVec<H256, T::NumRingLayers>, T::MaxMembersInRing>,
-    BoundedVec<H256, T::NumRingLayers>,
-) {
-    let mut csprng = OsRng;
-    let secret_index = 1;
-    let nr = T::MaxMembersInRing::get() as usize;
-    let nc = T::NumRingLayers::get() as usize;
-
-    let ks: Vec<Scalar> = (0..nc).map(|_| Scalar::random(&mut csprng)).collect();
-    let ring: Vec<Vec<RistrettoPoint>> = (0..(nr - 1))
-        .map(|_| {
-            (0..nc)
-                .map(|_| RistrettoPoint::random(&mut csprng))
-                .collect()
-        })
-        .collect();
-
-    let message = {
-        let mut msg = poll_id.encode();
-        msg.extend(vote.encode());
-        msg
-    };
-
-    let signature = CLSAG::sign::<Sha512, OsRng>(ks.clone(), ring.clone(), secret_index, &message);
-    let result = CLSAG::verify::<Sha512>(signature.clone(), &message);
-    assert!(result);
-
-    let challenge: H256 = signature.challenge.to_bytes().into();
-
-    let responses: BoundedVec<H256, T::MaxMembersInRing> = signature
-        .responses
-        .iter()
-        .map(|r| r.to_bytes().into())
-        .collect::<Vec<H256>>()
-        .try_into()
-        .unwrap();
-
-    let ring: BoundedVec<BoundedVec<H256, T::NumRingLayers>, T::MaxMembersInRing> = signature
-        .ring
-        .iter()
-        .map(|layer| {
-            layer
-                .iter()
-                .map(|pk| pk.compress().to_bytes().into())
-                .collect::<Vec<H256>>()
-                .try_into()
-                .unwrap()
-        })
-        .collect::<Vec<BoundedVec<H256, T::NumRingLayers>>>()
-        .try_into()
-        .unwrap();
-
-    let key_images: BoundedVec<H256, T::NumRingLayers> = signature
-        .key_images
-        .iter()
-        .map(|ki| ki.compress().to_bytes().into())
-        .collect::<Vec<H256>>()
-        .try_into()
-        .unwrap();
-
-    (challenge, responses, ring, key_images)
-}
-
-/// 为加密投票生成签名（对加密数据签名）
-pub fn gen_signature_for_encrypted<T: crate::pallet::Config>(
-    poll_id: PollId,
-    _vote: Vote, // 实际不用于消息，只是为了保持接口一致
-    ephemeral_pubkey: [u8; 32],
-    ciphertext: &[u8],
-    auth_tag: [u8; 16],
-) -> (
-    H256,
-    BoundedVec<H256, T::MaxMembersInRing>,
-    BoundedVec<BoundedVec<H256, T::NumRingLayers>, T::MaxMembersInRing>,
-    BoundedVec<H256, T::NumRingLayers>,
-) {
-    let mut csprng = OsRng;
-    let secret_index = 1;
-    let nr = T::MaxMembersInRing::get() as usize;
-    let nc = T::NumRingLayers::get() as usize;
-
-    let ks: Vec<Scalar> = (0..nc). map(|_| Scalar::random(&mut csprng)).collect();
-    let ring: Vec<Vec<RistrettoPoint>> = (0..(nr - 1))
-        .map(|_| {
-            (0..nc)
-                .map(|_| RistrettoPoint::random(&mut csprng))
-                .collect()
-        })
-        .collect();
-
-    // 构建加密数据的消息：hash(R || Cipher || Tag)
-    let message = {
-        let mut msg = Vec::new();
-        msg.extend_from_slice(&ephemeral_pubkey);
-        msg.extend_from_slice(ciphertext);
-        msg.extend_from_slice(&auth_tag);
-        msg
-    };
-
-    let signature = CLSAG::sign::<Sha512, OsRng>(ks. clone(), ring.clone(), secret_index, &message);
-    let result = CLSAG::verify::<Sha512>(signature. clone(), &message);
-    assert!(result);
-
-    let challenge: H256 = signature.challenge. to_bytes().into();
-
-    let responses: BoundedVec<H256, T::MaxMembersInRing> = signature
-        .responses
-        .iter()
-        .map(|r| r.to_bytes().into())
-        .collect::<Vec<H256>>()
-        .try_into()
-        .unwrap();
-
-    let ring: BoundedVec<BoundedVec<H256, T::NumRingLayers>, T::MaxMembersInRing> = signature
-        .ring
-        .iter()
-        .map(|layer| {
-            layer
-                .iter()
-                .map(|pk| pk.compress().to_bytes().into())
-                .collect::<Vec<H256>>()
-                .try_into()
-                .unwrap()
-        })
-        .collect::<Vec<BoundedVec<H256, T::NumRingLayers>>>()
-        .try_into()
-        .unwrap();
-
-    let key_images: BoundedVec<H256, T::NumRingLayers> = signature
-        .key_images
-        . iter()
-        .map(|ki| ki.compress().to_bytes().into())
-        .collect::<Vec<H256>>()
-        .try_into()
-        .unwrap();
-
-    (challenge, responses, ring, key_images)
-}
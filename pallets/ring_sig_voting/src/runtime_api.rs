@@ -0,0 +1,41 @@
+//! 查询投票结果和验证环成员身份的运行时 API
+
+#![allow(clippy::too_many_arguments)]
+
+use crate::{Outcome, PollId, RingId};
+use codec::Codec;
+use frame::prelude::H256;
+use scale_info::prelude::vec::Vec;
+
+frame::deps::sp_api::decl_runtime_apis! {
+    /// 供前端和报表查询已关闭投票的结果。runtime 中可以有多个投票实例，
+    /// `pallet_index` 为实例在 runtime 中的位置，位置不对应任何实例时返回 `None`
+    pub trait RingSigVotingApi<Tally>
+    where
+        Tally: Codec,
+    {
+        /// 已关闭投票的计票结果。评分投票的计票结果包含每个问题的统计量
+        fn poll_tally(pallet_index: u8, poll_id: PollId) -> Option<Tally>;
+
+        /// 已关闭投票的判定结果
+        fn poll_outcome(pallet_index: u8, poll_id: PollId) -> Option<Outcome>;
+    }
+
+    /// 供链下服务把链上的公钥环当作匿名凭证，确认请求来自某个环的成员而不知道是谁
+    pub trait RingMembershipApi {
+        /// 用 `RingGroups` 中的公钥环验证对 `membership_message(ring_id, domain, nonce, message)`
+        /// 的话题签名（话题为 `membership_scope(ring_id, domain)`），有效时返回该服务中的密钥镜像，
+        /// 否则为 `None`。该密钥镜像与链上投票、请愿和举报中的密钥镜像互不可链接。
+        /// `pallet_index` 选择公钥环所在的实例
+        fn verify_membership(
+            pallet_index: u8,
+            ring_id: RingId,
+            domain: Vec<u8>,
+            nonce: Vec<u8>,
+            message: Vec<u8>,
+            challenge: H256,
+            responses: Vec<H256>,
+            key_image: H256,
+        ) -> Option<H256>;
+    }
+}
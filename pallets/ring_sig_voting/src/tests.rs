@@ -1,11 +1,13 @@
 use crate::{mock::*, types::simple_voting::*, *};
+use frame::deps::frame_support::traits::StorePreimage;
+use frame::deps::sp_runtime::Perbill;
 use frame::testing_prelude::*;
 use curve25519_dalek::{constants::RISTRETTO_BASEPOINT_POINT, scalar::Scalar};
 use rand_core::OsRng;
 
 #[test]
 fn register_ring_group() {
-    let ring = gen_ring::<Test>();
+    let ring = gen_ring::<Test, ()>();
 
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
@@ -23,7 +25,7 @@ fn create_poll() {
     let poll_id = 0;
     let description = b"Poll 0". to_vec();
     let ring_id = 0;
-    let ring = gen_ring::<Test>();
+    let ring = gen_ring::<Test, ()>();
     
     // 生成加密密钥对
     let mut csprng = OsRng;
@@ -46,7 +48,10 @@ fn create_poll() {
             ring_id,
             None,
             None,
+            None,
             encryption_pubkey,
+            PollConfig::default(),
+            None,
         ));
 
         let poll = Polls::<Test>::get(poll_id).unwrap();
@@ -61,7 +66,7 @@ fn close_poll() {
     let poll_id = 0;
     let description = b"Poll 0".to_vec();
     let ring_id = 0;
-    let ring = gen_ring::<Test>();
+    let ring = gen_ring::<Test, ()>();
     
     // 生成真实的密钥对
     let mut csprng = OsRng;
@@ -70,8 +75,6 @@ fn close_poll() {
     
     let encryption_privkey: H256 = private_key_scalar.to_bytes().into();
     let encryption_pubkey: H256 = public_key_point.compress().to_bytes().into();
-    
-    let tally = (5u32, 3u32); // 模拟计票结果
 
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
@@ -88,7 +91,10 @@ fn close_poll() {
             ring_id,
             None,
             None,
+            None,
             encryption_pubkey,
+            PollConfig::default(),
+            None,
         ));
         let poll = Polls::<Test>::get(poll_id).unwrap();
         assert_eq!(poll.description.into_inner(), description);
@@ -97,14 +103,20 @@ fn close_poll() {
         assert_ok!(RingSigVoting::close_poll(
             RuntimeOrigin::root(),
             poll_id,
-            encryption_privkey,
-            tally
+            Some(encryption_privkey)
         ));
-        
+
+        // 公开私钥后进入计票阶段，没有选票时一批即可完成计票
+        let tallying_poll = Polls::<Test>::get(poll_id).unwrap();
+        assert_eq!(tallying_poll.status, PollStatus::Tallying);
+        assert_eq!(tallying_poll.encryption_private_key, Some(encryption_privkey.0));
+        assert_ok!(RingSigVoting::tally_batch(RuntimeOrigin::signed(BOB), poll_id, 10));
+
         let closed_poll = Polls::<Test>::get(poll_id).unwrap();
         assert_eq!(closed_poll.status, PollStatus::Closed);
         assert_eq!(closed_poll. encryption_private_key, Some(encryption_privkey.0));
-        assert_eq!(PollVotes::<Test>::get(poll_id), tally);
+        assert_eq!(PollVotes::<Test>::get(poll_id), (0, 0));
+        assert!(TallyCursors::<Test>::get(poll_id).is_none());
     });
 }
 
@@ -123,12 +135,12 @@ fn anonymous_vote_encrypted() {
     // 模拟加密数据
     let ephemeral_pubkey: H256 = [2u8; 32].into();
     let ciphertext: BoundedVec<u8, <Test as crate::Config>::MaxVoteSize> = 
-        vec![1, 2, 3, 4]. try_into().unwrap();
+        vec![1; 64].try_into().unwrap();
     let auth_tag: primitive_types::H128 = [0u8; 16].into();
     
     // 生成环签名（需要对加密数据签名）
     let vote = Vote::Yea;
-    let (challenge, responses, ring, key_images) = gen_signature_for_encrypted::<Test>(
+    let (challenge, responses, ring, key_images) = gen_signature_for_encrypted::<Test, ()>(
         poll_id,
         vote,
         ephemeral_pubkey. 0,
@@ -151,7 +163,10 @@ fn anonymous_vote_encrypted() {
             ring_id,
             None,
             None,
+            None,
             encryption_pubkey,
+            PollConfig::default(),
+            None,
         ));
         let poll = Polls::<Test>::get(poll_id).unwrap();
         assert_eq!(poll.status, PollStatus::Voting);
@@ -167,8 +182,120 @@ fn anonymous_vote_encrypted() {
             key_images,
         ));
 
-        let encrypted_votes = EncryptedVotes::<Test>::get(poll_id);
-        assert_eq!(encrypted_votes.len(), 1);
+        assert_eq!(BallotCounts::<Test>::get(poll_id), 1);
+        assert!(EncryptedVotes::<Test>::get(poll_id, 0).is_some());
+    });
+}
+
+#[test]
+fn ballot_length_is_fixed_per_poll() {
+    let poll_id = 0;
+    let description = b"Poll 0".to_vec();
+    let ring_id = 0;
+
+    let mut csprng = OsRng;
+    let private_key_scalar = Scalar::random(&mut csprng);
+    let public_key_point = private_key_scalar * RISTRETTO_BASEPOINT_POINT;
+    let encryption_privkey: H256 = private_key_scalar.to_bytes().into();
+    let encryption_pubkey: H256 = public_key_point.compress().to_bytes().into();
+
+    // 该投票规定选票填充到 32 字节
+    let ephemeral_secret = Scalar::random(&mut csprng).to_bytes();
+    let sealed = crate::ecies::encrypt_ballot(
+        &encryption_pubkey.0,
+        &ephemeral_secret,
+        &Vote::Nay.encode(),
+        32,
+    )
+    .unwrap();
+    let ephemeral_pubkey: H256 = sealed.ephemeral_public_key.into();
+    let ciphertext: BoundedVec<u8, <Test as crate::Config>::MaxVoteSize> =
+        sealed.ciphertext.try_into().unwrap();
+    let auth_tag: primitive_types::H128 = sealed.auth_tag.into();
+    let (challenge, responses, ring, key_images) = gen_signature_for_encrypted::<Test, ()>(
+        poll_id,
+        Vote::Nay,
+        ephemeral_pubkey.0,
+        &ciphertext,
+        auth_tag.0,
+    );
+
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(RingSigVoting::register_ring_group(
+            RuntimeOrigin::signed(ALICE),
+            ring,
+        ));
+
+        // 长度为零或超出 `MaxVoteSize` 的配置被拒绝
+        for ballot_length in [0, 65] {
+            assert_noop!(
+                RingSigVoting::create_poll(
+                    RuntimeOrigin::signed(ALICE),
+                    description.clone().try_into().unwrap(),
+                    ring_id,
+                    None,
+                    None,
+                    None,
+                    encryption_pubkey,
+                    PollConfig {
+                        ballot_length: Some(ballot_length),
+                        ..Default::default()
+                    },
+                    None,
+                ),
+                Error::<Test>::InvalidBallotLength
+            );
+        }
+        assert_ok!(RingSigVoting::create_poll(
+            RuntimeOrigin::signed(ALICE),
+            description.clone().try_into().unwrap(),
+            ring_id,
+            None,
+            None,
+            None,
+            encryption_pubkey,
+            PollConfig {
+                ballot_length: Some(32),
+                ..Default::default()
+            },
+            None,
+        ));
+
+        // 长度不符的密文在验证签名之前被拒绝
+        assert_noop!(
+            RingSigVoting::anonymous_vote(
+                RuntimeOrigin::signed(ALICE),
+                poll_id,
+                ephemeral_pubkey,
+                vec![1; 64].try_into().unwrap(),
+                auth_tag,
+                challenge,
+                responses.clone(),
+                key_images.clone(),
+            ),
+            Error::<Test>::InvalidBallotLength
+        );
+        assert_ok!(RingSigVoting::anonymous_vote(
+            RuntimeOrigin::signed(ALICE),
+            poll_id,
+            ephemeral_pubkey,
+            ciphertext,
+            auth_tag,
+            challenge,
+            responses,
+            key_images,
+        ));
+
+        // 计票时去掉填充后解码
+        assert_ok!(RingSigVoting::close_poll(
+            RuntimeOrigin::root(),
+            poll_id,
+            Some(encryption_privkey)
+        ));
+        assert_ok!(RingSigVoting::tally_batch(RuntimeOrigin::signed(BOB), poll_id, 1));
+        assert_eq!(RingSigVoting::closed_tally(poll_id), Some((0, 1)));
     });
 }
 
@@ -185,11 +312,11 @@ fn prevent_double_voting() {
     
     let ephemeral_pubkey: H256 = [2u8; 32].into();
     let ciphertext: BoundedVec<u8, <Test as crate::Config>::MaxVoteSize> = 
-        vec![1, 2, 3, 4].try_into().unwrap();
+        vec![1; 64].try_into().unwrap();
     let auth_tag: primitive_types::H128 = [0u8; 16].into();
     
     let vote = Vote::Yea;
-    let (challenge, responses, ring, key_images) = gen_signature_for_encrypted::<Test>(
+    let (challenge, responses, ring, key_images) = gen_signature_for_encrypted::<Test, ()>(
         poll_id,
         vote,
         ephemeral_pubkey.0,
@@ -211,7 +338,10 @@ fn prevent_double_voting() {
             ring_id,
             None,
             None,
+            None,
             encryption_pubkey,
+            PollConfig::default(),
+            None,
         ));
 
         // 第一次投票应该成功
@@ -248,7 +378,7 @@ fn cannot_vote_on_closed_poll() {
     let poll_id = 0;
     let description = b"Poll 0".to_vec();
     let ring_id = 0;
-    let ring = gen_ring::<Test>();
+    let ring = gen_ring::<Test, ()>();
     
     let mut csprng = OsRng;
     let private_key_scalar = Scalar::random(&mut csprng);
@@ -256,15 +386,14 @@ fn cannot_vote_on_closed_poll() {
     
     let encryption_privkey: H256 = private_key_scalar.to_bytes(). into();
     let encryption_pubkey: H256 = public_key_point.compress().to_bytes().into();
-    let tally = (0u32, 0u32);
     
     let ephemeral_pubkey: H256 = [2u8; 32].into();
     let ciphertext: BoundedVec<u8, <Test as crate::Config>::MaxVoteSize> = 
-        vec![1, 2, 3, 4].try_into().unwrap();
+        vec![1; 64].try_into().unwrap();
     let auth_tag: primitive_types::H128 = [0u8; 16].into();
     
     let vote = Vote::Yea;
-    let (challenge, responses, _ring, key_images) = gen_signature_for_encrypted::<Test>(
+    let (challenge, responses, _ring, key_images) = gen_signature_for_encrypted::<Test, ()>(
         poll_id,
         vote,
         ephemeral_pubkey.0,
@@ -286,15 +415,17 @@ fn cannot_vote_on_closed_poll() {
             ring_id,
             None,
             None,
+            None,
             encryption_pubkey,
+            PollConfig::default(),
+            None,
         ));
 
         // 关闭投票
         assert_ok!(RingSigVoting::close_poll(
             RuntimeOrigin::root(),
             poll_id,
-            encryption_privkey,
-            tally
+            Some(encryption_privkey)
         ));
 
         // 尝试在关闭后投票应该失败
@@ -314,9 +445,2574 @@ fn cannot_vote_on_closed_poll() {
     });
 }
 
+#[test]
+fn timestamp_deadline_expires() {
+    let poll_id = 0;
+    let description = b"Poll 0".to_vec();
+    let ring_id = 0;
+
+    let mut csprng = OsRng;
+    let private_key_scalar = Scalar::random(&mut csprng);
+    let public_key_point = private_key_scalar * RISTRETTO_BASEPOINT_POINT;
+    let encryption_pubkey: H256 = public_key_point.compress().to_bytes().into();
+
+    let ephemeral_pubkey: H256 = [2u8; 32].into();
+    let ciphertext: BoundedVec<u8, <Test as crate::Config>::MaxVoteSize> =
+        vec![1; 64].try_into().unwrap();
+    let auth_tag: primitive_types::H128 = [0u8; 16].into();
+
+    let vote = Vote::Yea;
+    let (challenge, responses, ring, key_images) = gen_signature_for_encrypted::<Test, ()>(
+        poll_id,
+        vote,
+        ephemeral_pubkey.0,
+        &ciphertext,
+        auth_tag.0,
+    );
+
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        Timestamp::set_timestamp(1_000);
+
+        assert_ok!(RingSigVoting::register_ring_group(
+            RuntimeOrigin::signed(ALICE),
+            ring,
+        ));
+
+        // 截止时间必须在未来
+        assert_err!(
+            RingSigVoting::create_poll(
+                RuntimeOrigin::signed(ALICE),
+                description.clone().try_into().unwrap(),
+                ring_id,
+                None,
+                None,
+                Some(PollMoment::Timestamp(1_000)),
+                encryption_pubkey,
+                PollConfig::default(),
+                None,
+            ),
+            Error::<Test>::InvalidDeadline
+        );
+
+        assert_ok!(RingSigVoting::create_poll(
+            RuntimeOrigin::signed(ALICE),
+            description.clone().try_into().unwrap(),
+            ring_id,
+            None,
+            None,
+            Some(PollMoment::Timestamp(2_000)),
+            encryption_pubkey,
+            PollConfig::default(),
+            None,
+        ));
+        let poll = Polls::<Test>::get(poll_id).unwrap();
+        assert_eq!(poll.deadline, Some(PollMoment::Timestamp(2_000)));
+
+        // 区块号不变，但时间已超过截止时间
+        Timestamp::set_timestamp(2_001);
+        assert_err!(
+            RingSigVoting::anonymous_vote(
+                RuntimeOrigin::signed(ALICE),
+                poll_id,
+                ephemeral_pubkey,
+                ciphertext,
+                auth_tag,
+                challenge,
+                responses,
+                key_images,
+            ),
+            Error::<Test>::PollExpired
+        );
+    });
+}
+
+#[test]
+fn cannot_vote_before_poll_opens() {
+    let poll_id = 0;
+    let description = b"Poll 0".to_vec();
+    let ring_id = 0;
+
+    let mut csprng = OsRng;
+    let private_key_scalar = Scalar::random(&mut csprng);
+    let public_key_point = private_key_scalar * RISTRETTO_BASEPOINT_POINT;
+    let encryption_pubkey: H256 = public_key_point.compress().to_bytes().into();
+
+    let ephemeral_pubkey: H256 = [2u8; 32].into();
+    let ciphertext: BoundedVec<u8, <Test as crate::Config>::MaxVoteSize> =
+        vec![1; 64].try_into().unwrap();
+    let auth_tag: primitive_types::H128 = [0u8; 16].into();
+
+    let vote = Vote::Yea;
+    let (challenge, responses, ring, key_images) = gen_signature_for_encrypted::<Test, ()>(
+        poll_id,
+        vote,
+        ephemeral_pubkey.0,
+        &ciphertext,
+        auth_tag.0,
+    );
+
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(RingSigVoting::register_ring_group(
+            RuntimeOrigin::signed(ALICE),
+            ring,
+        ));
+
+        // 开放时间与截止时间的计时方式必须一致
+        assert_err!(
+            RingSigVoting::create_poll(
+                RuntimeOrigin::signed(ALICE),
+                description.clone().try_into().unwrap(),
+                ring_id,
+                None,
+                Some(PollMoment::Block(5)),
+                Some(PollMoment::Timestamp(2_000)),
+                encryption_pubkey,
+                PollConfig::default(),
+                None,
+            ),
+            Error::<Test>::InvalidOpeningTime
+        );
+
+        assert_ok!(RingSigVoting::create_poll(
+            RuntimeOrigin::signed(ALICE),
+            description.clone().try_into().unwrap(),
+            ring_id,
+            None,
+            Some(PollMoment::Block(5)),
+            Some(PollMoment::Block(10)),
+            encryption_pubkey,
+            PollConfig::default(),
+            None,
+        ));
+
+        assert_err!(
+            RingSigVoting::anonymous_vote(
+                RuntimeOrigin::signed(ALICE),
+                poll_id,
+                ephemeral_pubkey,
+                ciphertext.clone(),
+                auth_tag,
+                challenge,
+                responses.clone(),
+                key_images.clone(),
+            ),
+            Error::<Test>::PollNotStarted
+        );
+
+        System::set_block_number(5);
+        assert_ok!(RingSigVoting::anonymous_vote(
+            RuntimeOrigin::signed(ALICE),
+            poll_id,
+            ephemeral_pubkey,
+            ciphertext,
+            auth_tag,
+            challenge,
+            responses,
+            key_images,
+        ));
+    });
+}
+
+#[test]
+fn ballot_replacement_overrides_previous_vote() {
+    let poll_id = 0;
+    let description = b"Poll 0".to_vec();
+    let ring_id = 0;
+
+    let mut csprng = OsRng;
+    let private_key_scalar = Scalar::random(&mut csprng);
+    let public_key_point = private_key_scalar * RISTRETTO_BASEPOINT_POINT;
+    let encryption_pubkey: H256 = public_key_point.compress().to_bytes().into();
+
+    let ephemeral_pubkey: H256 = [2u8; 32].into();
+    let auth_tag: primitive_types::H128 = [0u8; 16].into();
+    let coerced: BoundedVec<u8, <Test as crate::Config>::MaxVoteSize> =
+        vec![1; 64].try_into().unwrap();
+    let sincere: BoundedVec<u8, <Test as crate::Config>::MaxVoteSize> =
+        vec![5; 64].try_into().unwrap();
+
+    // 同一个投票人（相同私钥）签名两张不同的选票
+    let (ks, decoys) = gen_signer_and_decoys::<Test, ()>();
+    let (challenge_1, responses_1, ring, key_images_1) = gen_signature_for_encrypted_with::<Test, ()>(
+        poll_id,
+        ks.clone(),
+        decoys.clone(),
+        ephemeral_pubkey.0,
+        &coerced,
+        auth_tag.0,
+    );
+    let (challenge_2, responses_2, _ring, key_images_2) = gen_signature_for_encrypted_with::<Test, ()>(
+        poll_id,
+        ks,
+        decoys,
+        ephemeral_pubkey.0,
+        &sincere,
+        auth_tag.0,
+    );
+    assert_eq!(key_images_1, key_images_2);
+
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(RingSigVoting::register_ring_group(
+            RuntimeOrigin::signed(ALICE),
+            ring,
+        ));
+
+        assert_ok!(RingSigVoting::create_poll(
+            RuntimeOrigin::signed(ALICE),
+            description.clone().try_into().unwrap(),
+            ring_id,
+            None,
+            None,
+            None,
+            encryption_pubkey,
+            PollConfig {
+                ballot_replacement: true,
+                announce_revisions: true,
+                ..Default::default()
+            },
+            None,
+        ));
+
+        assert_ok!(RingSigVoting::anonymous_vote(
+            RuntimeOrigin::signed(ALICE),
+            poll_id,
+            ephemeral_pubkey,
+            coerced,
+            auth_tag,
+            challenge_1,
+            responses_1,
+            key_images_1,
+        ));
+
+        assert_ok!(RingSigVoting::anonymous_vote(
+            RuntimeOrigin::signed(BOB),
+            poll_id,
+            ephemeral_pubkey,
+            sincere.clone(),
+            auth_tag,
+            challenge_2,
+            responses_2,
+            key_images_2.clone(),
+        ));
+
+        // 只保留最后一张选票
+        assert_eq!(BallotCounts::<Test>::get(poll_id), 1);
+        assert_eq!(EncryptedVotes::<Test>::get(poll_id, 0).unwrap().ciphertext, sincere);
+
+        let key_image = CompressedRistrettoWrapper(key_images_2[0].0);
+        assert_eq!(
+            BallotSlots::<Test>::get(poll_id, &key_image),
+            Some(BallotSlot { index: 0, revision: 1 })
+        );
+        System::assert_last_event(
+            Event::<Test>::EncryptedVoteReplaced {
+                poll_id,
+                key_image,
+                revision: 1,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn ballot_replacement_is_quiet_by_default() {
+    let poll_id = 0;
+    let description = b"Poll 0".to_vec();
+    let ring_id = 0;
+
+    let mut csprng = OsRng;
+    let private_key_scalar = Scalar::random(&mut csprng);
+    let public_key_point = private_key_scalar * RISTRETTO_BASEPOINT_POINT;
+    let encryption_pubkey: H256 = public_key_point.compress().to_bytes().into();
+
+    let ephemeral_pubkey: H256 = [2u8; 32].into();
+    let auth_tag: primitive_types::H128 = [0u8; 16].into();
+    let coerced: BoundedVec<u8, <Test as crate::Config>::MaxVoteSize> =
+        vec![1; 64].try_into().unwrap();
+    let sincere: BoundedVec<u8, <Test as crate::Config>::MaxVoteSize> =
+        vec![5; 64].try_into().unwrap();
+
+    let (ks, decoys) = gen_signer_and_decoys::<Test, ()>();
+    let (challenge_1, responses_1, ring, key_images_1) = gen_signature_for_encrypted_with::<Test, ()>(
+        poll_id,
+        ks.clone(),
+        decoys.clone(),
+        ephemeral_pubkey.0,
+        &coerced,
+        auth_tag.0,
+    );
+    let (challenge_2, responses_2, _ring, key_images_2) = gen_signature_for_encrypted_with::<Test, ()>(
+        poll_id,
+        ks,
+        decoys,
+        ephemeral_pubkey.0,
+        &sincere,
+        auth_tag.0,
+    );
+
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(RingSigVoting::register_ring_group(
+            RuntimeOrigin::signed(ALICE),
+            ring,
+        ));
+        assert_ok!(RingSigVoting::create_poll(
+            RuntimeOrigin::signed(ALICE),
+            description.clone().try_into().unwrap(),
+            ring_id,
+            None,
+            None,
+            None,
+            encryption_pubkey,
+            PollConfig {
+                ballot_replacement: true,
+                ..Default::default()
+            },
+            None,
+        ));
+
+        assert_ok!(RingSigVoting::anonymous_vote(
+            RuntimeOrigin::signed(ALICE),
+            poll_id,
+            ephemeral_pubkey,
+            coerced,
+            auth_tag,
+            challenge_1,
+            responses_1,
+            key_images_1,
+        ));
+        let events = System::events().len();
+
+        assert_ok!(RingSigVoting::anonymous_vote(
+            RuntimeOrigin::signed(BOB),
+            poll_id,
+            ephemeral_pubkey,
+            sincere.clone(),
+            auth_tag,
+            challenge_2,
+            responses_2,
+            key_images_2.clone(),
+        ));
+
+        // 选票被替换，但不发出事件也不记录修订次数
+        assert_eq!(BallotCounts::<Test>::get(poll_id), 1);
+        assert_eq!(EncryptedVotes::<Test>::get(poll_id, 0).unwrap().ciphertext, sincere);
+
+        let key_image = CompressedRistrettoWrapper(key_images_2[0].0);
+        assert_eq!(
+            BallotSlots::<Test>::get(poll_id, &key_image),
+            Some(BallotSlot { index: 0, revision: 0 })
+        );
+        assert_eq!(System::events().len(), events);
+    });
+}
+
+#[test]
+fn approval_thresholds() {
+    assert!(ApprovalThreshold::SimpleMajority.is_met(3, 2));
+    assert!(!ApprovalThreshold::SimpleMajority.is_met(2, 2));
+    assert!(ApprovalThreshold::SuperMajority.is_met(2, 1));
+    assert!(!ApprovalThreshold::SuperMajority.is_met(3, 2));
+    assert!(ApprovalThreshold::AtLeast(Perbill::from_percent(60)).is_met(3, 2));
+    assert!(!ApprovalThreshold::AtLeast(Perbill::from_percent(61)).is_met(3, 2));
+    assert!(ApprovalThreshold::Unanimous.is_met(5, 0));
+    assert!(!ApprovalThreshold::Unanimous.is_met(5, 1));
+    // 没有任何有效票时不通过
+    assert!(!ApprovalThreshold::AtLeast(Perbill::from_percent(0)).is_met(0, 0));
+}
+
+#[test]
+fn close_poll_records_outcome() {
+    let poll_id = 0;
+    let description = b"Poll 0".to_vec();
+    let ring_id = 0;
+
+    let mut csprng = OsRng;
+    let private_key_scalar = Scalar::random(&mut csprng);
+    let public_key_point = private_key_scalar * RISTRETTO_BASEPOINT_POINT;
+    let encryption_privkey: H256 = private_key_scalar.to_bytes().into();
+    let encryption_pubkey: H256 = public_key_point.compress().to_bytes().into();
+
+    let vote = Vote::Yea;
+    let sealed = seal(&encryption_pubkey, &vote.encode());
+    let ephemeral_pubkey: H256 = sealed.ephemeral_public_key.into();
+    let ciphertext: BoundedVec<u8, <Test as crate::Config>::MaxVoteSize> =
+        sealed.ciphertext.try_into().unwrap();
+    let auth_tag: primitive_types::H128 = sealed.auth_tag.into();
+
+    let (challenge, responses, ring, key_images) = gen_signature_for_encrypted::<Test, ()>(
+        poll_id,
+        vote,
+        ephemeral_pubkey.0,
+        &ciphertext,
+        auth_tag.0,
+    );
+    let electorate = ring.len() as u32;
+
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(RingSigVoting::register_ring_group(
+            RuntimeOrigin::signed(ALICE),
+            ring,
+        ));
+
+        // 至少需要 1% 的环成员投票，且简单多数通过
+        assert_ok!(RingSigVoting::create_poll(
+            RuntimeOrigin::signed(ALICE),
+            description.clone().try_into().unwrap(),
+            ring_id,
+            None,
+            None,
+            None,
+            encryption_pubkey,
+            PollConfig {
+                quorum: Some(Perbill::from_percent(1)),
+                threshold: Some(ApprovalThreshold::SimpleMajority),
+                ..Default::default()
+            },
+            None,
+        ));
+
+        assert_ok!(RingSigVoting::anonymous_vote(
+            RuntimeOrigin::signed(ALICE),
+            poll_id,
+            ephemeral_pubkey,
+            ciphertext,
+            auth_tag,
+            challenge,
+            responses,
+            key_images,
+        ));
+
+        assert_eq!(RingSigVoting::closed_tally(poll_id), None);
+        assert_ok!(RingSigVoting::close_poll(
+            RuntimeOrigin::root(),
+            poll_id,
+            Some(encryption_privkey)
+        ));
+        assert_eq!(RingSigVoting::closed_tally(poll_id), None);
+        assert_ok!(RingSigVoting::tally_batch(RuntimeOrigin::signed(BOB), poll_id, 1));
+        assert_eq!(RingSigVoting::closed_tally(poll_id), Some((1, 0)));
+
+        // 128 名成员中只有 1 人投票，未达到法定人数
+        let outcome = Outcome {
+            turnout: 1,
+            electorate,
+            quorum_met: false,
+            passed: Some(false),
+            breakdown_withheld: false,
+            spoiled: 0,
+        };
+        assert_eq!(PollOutcomes::<Test>::get(poll_id), Some(outcome.clone()));
+        System::assert_last_event(
+            Event::<Test>::PollClosed {
+                poll_id,
+                tally: (1, 0),
+                outcome,
+                private_key_revealed: Some(encryption_privkey.0),
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn tally_batch_processes_in_steps() {
+    let poll_id = 0;
+    let description = b"Poll 0".to_vec();
+    let ring_id = 0;
+
+    let mut csprng = OsRng;
+    let private_key_scalar = Scalar::random(&mut csprng);
+    let public_key_point = private_key_scalar * RISTRETTO_BASEPOINT_POINT;
+    let encryption_privkey: H256 = private_key_scalar.to_bytes().into();
+    let encryption_pubkey: H256 = public_key_point.compress().to_bytes().into();
+
+    let vote = Vote::Yea;
+    let sealed = seal(&encryption_pubkey, &vote.encode());
+    let ephemeral_pubkey: H256 = sealed.ephemeral_public_key.into();
+    let ciphertext: BoundedVec<u8, <Test as crate::Config>::MaxVoteSize> =
+        sealed.ciphertext.try_into().unwrap();
+    let auth_tag: primitive_types::H128 = sealed.auth_tag.into();
+
+    let (challenge, responses, ring, key_images) = gen_signature_for_encrypted::<Test, ()>(
+        poll_id,
+        vote,
+        ephemeral_pubkey.0,
+        &ciphertext,
+        auth_tag.0,
+    );
+
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(RingSigVoting::register_ring_group(
+            RuntimeOrigin::signed(ALICE),
+            ring,
+        ));
+        assert_ok!(RingSigVoting::create_poll(
+            RuntimeOrigin::signed(ALICE),
+            description.clone().try_into().unwrap(),
+            ring_id,
+            None,
+            None,
+            None,
+            encryption_pubkey,
+            Default::default(),
+            None,
+        ));
+        assert_ok!(RingSigVoting::anonymous_vote(
+            RuntimeOrigin::signed(ALICE),
+            poll_id,
+            ephemeral_pubkey,
+            ciphertext,
+            auth_tag,
+            challenge,
+            responses,
+            key_images,
+        ));
+
+        // 计票只检查密文，直接追加一张无法解码的选票、一张反对票和一张无法解密的选票
+        let cast = EncryptedVotes::<Test>::get(poll_id, 0).unwrap();
+        let with_plaintext = |plaintext: &[u8]| {
+            let sealed = seal(&encryption_pubkey, plaintext);
+            let mut ballot = cast.clone();
+            ballot.ephemeral_public_key = sealed.ephemeral_public_key;
+            ballot.ciphertext = sealed.ciphertext.try_into().unwrap();
+            ballot.auth_tag = sealed.auth_tag;
+            ballot
+        };
+        let mut tampered = cast.clone();
+        tampered.auth_tag[0] ^= 1;
+        EncryptedVotes::<Test>::insert(poll_id, 1, with_plaintext(&[0xff]));
+        EncryptedVotes::<Test>::insert(poll_id, 2, with_plaintext(&Vote::Nay.encode()));
+        EncryptedVotes::<Test>::insert(poll_id, 3, tampered);
+        BallotCounts::<Test>::insert(poll_id, 4);
+
+        // 投票关闭前不能计票
+        assert_noop!(
+            RingSigVoting::tally_batch(RuntimeOrigin::signed(BOB), poll_id, 1),
+            Error::<Test>::InvalidPollStatus
+        );
+
+        assert_ok!(RingSigVoting::close_poll(
+            RuntimeOrigin::root(),
+            poll_id,
+            Some(encryption_privkey)
+        ));
+
+        // 每批两张，进度逐步推进
+        assert_ok!(RingSigVoting::tally_batch(RuntimeOrigin::signed(BOB), poll_id, 2));
+        System::assert_last_event(
+            Event::<Test>::TallyBatchProcessed { poll_id, processed: 2, spoiled: 1, remaining: 2 }.into(),
+        );
+        assert_eq!(TallyCursors::<Test>::get(poll_id), Some(2));
+        assert_eq!(PollVotes::<Test>::get(poll_id), (1, 0));
+        assert_eq!(RingSigVoting::closed_tally(poll_id), None);
+
+        // 最后一批处理完后关闭投票，无效选票按位置记录原因，不计入计票结果
+        assert_ok!(RingSigVoting::tally_batch(RuntimeOrigin::signed(BOB), poll_id, 10));
+        System::assert_has_event(
+            Event::<Test>::TallyBatchProcessed { poll_id, processed: 2, spoiled: 1, remaining: 0 }.into(),
+        );
+        assert_eq!(RingSigVoting::closed_tally(poll_id), Some((1, 1)));
+        assert_eq!(TallyCursors::<Test>::get(poll_id), None);
+        assert_eq!(
+            SpoiledBallots::<Test>::get(poll_id).into_inner(),
+            vec![
+                SpoiledBallot { index: 1, reason: SpoilReason::Undecodable },
+                SpoiledBallot { index: 3, reason: SpoilReason::Undecryptable },
+            ]
+        );
+        let outcome = PollOutcomes::<Test>::get(poll_id).unwrap();
+        assert_eq!((outcome.turnout, outcome.spoiled), (4, 2));
+
+        // 投票关闭后不能再计票
+        assert_noop!(
+            RingSigVoting::tally_batch(RuntimeOrigin::signed(BOB), poll_id, 1),
+            Error::<Test>::InvalidPollStatus
+        );
+    });
+}
+
+#[test]
+fn small_turnout_withholds_breakdown() {
+    let poll_id = 0;
+    let description = b"Poll 0".to_vec();
+    let ring_id = 0;
+
+    let mut csprng = OsRng;
+    let private_key_scalar = Scalar::random(&mut csprng);
+    let public_key_point = private_key_scalar * RISTRETTO_BASEPOINT_POINT;
+    let encryption_privkey: H256 = private_key_scalar.to_bytes().into();
+    let encryption_pubkey: H256 = public_key_point.compress().to_bytes().into();
+
+    let ephemeral_pubkey: H256 = [2u8; 32].into();
+    let ciphertext: BoundedVec<u8, <Test as crate::Config>::MaxVoteSize> =
+        vec![1; 64].try_into().unwrap();
+    let auth_tag: primitive_types::H128 = [0u8; 16].into();
+
+    let (challenge, responses, ring, key_images) = gen_signature_for_encrypted::<Test, ()>(
+        poll_id,
+        Vote::Yea,
+        ephemeral_pubkey.0,
+        &ciphertext,
+        auth_tag.0,
+    );
+    let electorate = ring.len() as u32;
+
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        // 至少 2 张选票才公开计票结果
+        MinBallotsForBreakdown::set(2);
+
+        assert_ok!(RingSigVoting::register_ring_group(
+            RuntimeOrigin::signed(ALICE),
+            ring,
+        ));
+        assert_ok!(RingSigVoting::create_poll(
+            RuntimeOrigin::signed(ALICE),
+            description.clone().try_into().unwrap(),
+            ring_id,
+            None,
+            None,
+            None,
+            encryption_pubkey,
+            PollConfig::default(),
+            None,
+        ));
+        assert_ok!(RingSigVoting::anonymous_vote(
+            RuntimeOrigin::signed(ALICE),
+            poll_id,
+            ephemeral_pubkey,
+            ciphertext,
+            auth_tag,
+            challenge,
+            responses,
+            key_images,
+        ));
+
+        // 只有 1 张选票，不能公开私钥
+        assert_err!(
+            RingSigVoting::close_poll(RuntimeOrigin::root(), poll_id, Some(encryption_privkey)),
+            Error::<Test>::BreakdownWithheld
+        );
+        assert_ok!(RingSigVoting::close_poll(RuntimeOrigin::root(), poll_id, None));
+
+        // 不进入计票阶段，直接关闭并只公布投票率
+        let poll = Polls::<Test>::get(poll_id).unwrap();
+        assert_eq!(poll.status, PollStatus::Closed);
+        assert_eq!(poll.encryption_private_key, None);
+        assert_eq!(RingSigVoting::closed_tally(poll_id), Some((0, 0)));
+        let outcome = Outcome {
+            turnout: 1,
+            electorate,
+            quorum_met: true,
+            passed: None,
+            breakdown_withheld: true,
+            spoiled: 0,
+        };
+        assert_eq!(PollOutcomes::<Test>::get(poll_id), Some(outcome.clone()));
+        System::assert_last_event(
+            Event::<Test>::PollClosed {
+                poll_id,
+                tally: (0, 0),
+                outcome,
+                private_key_revealed: None,
+            }
+            .into(),
+        );
+
+        // 未公开计票结果时也不能公布评论
+        let comments_hash = Preimage::note(vec![b"Too slow".to_vec()].encode().into()).unwrap();
+        assert_err!(
+            RingSigVoting::publish_comments(RuntimeOrigin::root(), poll_id, comments_hash),
+            Error::<Test>::BreakdownWithheld
+        );
+    });
+}
+
+#[test]
+fn passed_poll_schedules_proposal() {
+    let poll_id = 0;
+    let description = b"Poll 0".to_vec();
+    let ring_id = 0;
+
+    let mut csprng = OsRng;
+    let private_key_scalar = Scalar::random(&mut csprng);
+    let public_key_point = private_key_scalar * RISTRETTO_BASEPOINT_POINT;
+    let encryption_privkey: H256 = private_key_scalar.to_bytes().into();
+    let encryption_pubkey: H256 = public_key_point.compress().to_bytes().into();
+
+    let vote = Vote::Yea;
+    let sealed = seal(&encryption_pubkey, &vote.encode());
+    let ephemeral_pubkey: H256 = sealed.ephemeral_public_key.into();
+    let ciphertext: BoundedVec<u8, <Test as crate::Config>::MaxVoteSize> =
+        sealed.ciphertext.try_into().unwrap();
+    let auth_tag: primitive_types::H128 = sealed.auth_tag.into();
+
+    let (challenge, responses, ring, key_images) = gen_signature_for_encrypted::<Test, ()>(
+        poll_id,
+        vote,
+        ephemeral_pubkey.0,
+        &ciphertext,
+        auth_tag.0,
+    );
+
+    let call = RuntimeCall::System(frame_system::Call::set_storage {
+        items: vec![(b"decision".to_vec(), b"approved".to_vec())],
+    });
+
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let proposal = Preimage::bound(call).unwrap();
+
+        assert_ok!(RingSigVoting::register_ring_group(
+            RuntimeOrigin::signed(ALICE),
+            ring,
+        ));
+
+        // 没有通过门槛的投票不能附带调用
+        assert_err!(
+            RingSigVoting::create_poll(
+                RuntimeOrigin::signed(ALICE),
+                description.clone().try_into().unwrap(),
+                ring_id,
+                None,
+                None,
+                None,
+                encryption_pubkey,
+                PollConfig::default(),
+                Some(proposal.clone()),
+            ),
+            Error::<Test>::ProposalRequiresThreshold
+        );
+
+        assert_ok!(RingSigVoting::create_poll(
+            RuntimeOrigin::signed(ALICE),
+            description.clone().try_into().unwrap(),
+            ring_id,
+            None,
+            None,
+            None,
+            encryption_pubkey,
+            PollConfig {
+                threshold: Some(ApprovalThreshold::SimpleMajority),
+                ..Default::default()
+            },
+            Some(proposal),
+        ));
+
+        assert_ok!(RingSigVoting::anonymous_vote(
+            RuntimeOrigin::signed(ALICE),
+            poll_id,
+            ephemeral_pubkey,
+            ciphertext,
+            auth_tag,
+            challenge,
+            responses,
+            key_images,
+        ));
+
+        assert_ok!(RingSigVoting::close_poll(
+            RuntimeOrigin::root(),
+            poll_id,
+            Some(encryption_privkey)
+        ));
+        assert_ok!(RingSigVoting::tally_batch(RuntimeOrigin::signed(BOB), poll_id, 1));
+        System::assert_last_event(Event::<Test>::ProposalScheduled { poll_id }.into());
+        assert!(PollProposals::<Test>::get(poll_id).is_none());
+
+        // 经过 EnactmentDelay 后由调度器执行
+        for n in 2..=4 {
+            System::set_block_number(n);
+            Scheduler::on_initialize(n);
+        }
+        assert_eq!(
+            frame::deps::sp_io::storage::get(b"decision").map(|v| v.to_vec()),
+            Some(b"approved".to_vec())
+        );
+    });
+}
+
+#[test]
+fn proposals_require_proposal_origin() {
+    use frame::deps::frame_support::traits::{Bounded, QueryPreimage};
+    use frame::deps::sp_runtime::traits::Hash;
+
+    let description = b"Poll".to_vec();
+    let ring_id = 0;
+    let ring = gen_ring::<Test, ()>();
+    let encryption_pubkey: H256 = (Scalar::random(&mut OsRng) * RISTRETTO_BASEPOINT_POINT)
+        .compress()
+        .to_bytes()
+        .into();
+    let config = PollConfig {
+        threshold: Some(ApprovalThreshold::SimpleMajority),
+        ..Default::default()
+    };
+    let call = RuntimeCall::System(frame_system::Call::set_storage {
+        items: vec![(b"decision".to_vec(), b"approved".to_vec())],
+    });
+
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        MinBallotsForBreakdown::set(1);
+        let create = |who, proposal| {
+            RingSigVoting::create_poll(
+                RuntimeOrigin::signed(who),
+                description.clone().try_into().unwrap(),
+                ring_id,
+                None,
+                None,
+                None,
+                encryption_pubkey,
+                config.clone(),
+                proposal,
+            )
+        };
+
+        // BOB 注册了自己的环，并提交了调用的 Preimage
+        assert_ok!(RingSigVoting::register_ring_group(
+            RuntimeOrigin::signed(BOB),
+            ring,
+        ));
+        let encoded = call.encode();
+        let hash = <Test as frame_system::Config>::Hashing::hash(&encoded);
+        assert_ok!(Preimage::note_preimage(RuntimeOrigin::signed(BOB), encoded.clone()));
+        let proposal = Bounded::Lookup {
+            hash,
+            len: encoded.len() as u32,
+        };
+
+        // 但只有 `ProposalOrigin` 能附带调用，不附带调用时仍可创建投票
+        assert_noop!(create(BOB, Some(proposal.clone())), DispatchError::BadOrigin);
+        assert_ok!(create(BOB, None));
+        assert!(PollProposals::<Test>::get(0).is_none());
+
+        // 附带的调用在投票结束前不能被撤回
+        assert_ok!(create(ALICE, Some(proposal.clone())));
+        assert!(Preimage::is_requested(&hash));
+        assert_ok!(Preimage::unnote_preimage(RuntimeOrigin::signed(BOB), hash));
+        assert!(Preimage::have(&proposal));
+
+        // 投票结束后释放锁定
+        assert_ok!(RingSigVoting::close_poll(RuntimeOrigin::root(), 1, None));
+        assert!(!Preimage::is_requested(&hash));
+        assert!(!Preimage::have(&proposal));
+    });
+}
+
+#[test]
+fn petition_threshold_schedules_call() {
+    let petition_id = 0;
+    let description = b"Petition 0".to_vec();
+    let ring_id = 0;
+
+    // 两个签名者分别位于环的第 1、2 个位置
+    let (ks_1, mut decoys_1) = gen_signer_and_decoys::<Test, ()>();
+    let (ks_2, _) = gen_signer_and_decoys::<Test, ()>();
+    let public = |ks: &[Scalar]| -> Vec<_> {
+        ks.iter().map(|k| k * RISTRETTO_BASEPOINT_POINT).collect()
+    };
+    let mut decoys_2 = decoys_1.clone();
+    decoys_1[1] = public(&ks_2);
+    decoys_2[1] = public(&ks_1);
+
+    let call = RuntimeCall::System(frame_system::Call::set_storage {
+        items: vec![(b"petition".to_vec(), b"reached".to_vec())],
+    });
+
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let proposal = Preimage::bound(call).unwrap();
+
+        let message = RingSigVoting::petition_message(petition_id);
+        let (challenge_1, responses_1, ring, key_images_1) =
+            sign_message_at::<Test, ()>(ks_1.clone(), decoys_1.clone(), 1, &message);
+        let (challenge_2, responses_2, ring_2, key_images_2) =
+            sign_message_at::<Test, ()>(ks_2, decoys_2, 2, &message);
+        assert_eq!(ring, ring_2);
+
+        assert_ok!(RingSigVoting::register_ring_group(
+            RuntimeOrigin::signed(ALICE),
+            ring,
+        ));
+
+        // 门槛必须大于零且不超过环成员数
+        for threshold in [0, 129] {
+            assert_noop!(
+                RingSigVoting::create_petition(
+                    RuntimeOrigin::signed(ALICE),
+                    description.clone().try_into().unwrap(),
+                    ring_id,
+                    threshold,
+                    None,
+                ),
+                Error::<Test>::InvalidPetitionThreshold
+            );
+        }
+        assert_ok!(RingSigVoting::create_petition(
+            RuntimeOrigin::signed(ALICE),
+            description.try_into().unwrap(),
+            ring_id,
+            2,
+            Some(proposal),
+        ));
+
+        // 对其他消息的签名（如选票）不能用于请愿
+        let (challenge, responses, _, key_images) =
+            sign_message_at::<Test, ()>(ks_1, decoys_1, 1, b"ballot");
+        assert_noop!(
+            RingSigVoting::sign_petition(
+                RuntimeOrigin::signed(BOB),
+                petition_id,
+                challenge,
+                responses,
+                key_images,
+            ),
+            Error::<Test>::InvalidSignature
+        );
+
+        assert_ok!(RingSigVoting::sign_petition(
+            RuntimeOrigin::signed(BOB),
+            petition_id,
+            challenge_1,
+            responses_1.clone(),
+            key_images_1.clone(),
+        ));
+        System::assert_last_event(
+            Event::<Test>::PetitionSigned {
+                petition_id,
+                key_image: CompressedRistrettoWrapper(key_images_1[0].0),
+                signatures: 1,
+            }
+            .into(),
+        );
+        assert_noop!(
+            RingSigVoting::sign_petition(
+                RuntimeOrigin::signed(BOB),
+                petition_id,
+                challenge_1,
+                responses_1,
+                key_images_1,
+            ),
+            Error::<Test>::AlreadySigned
+        );
+        assert_eq!(Petitions::<Test>::get(petition_id).unwrap().reached_at, None);
+
+        // 第二个不同的签名者使请愿达到门槛
+        assert_ok!(RingSigVoting::sign_petition(
+            RuntimeOrigin::signed(BOB),
+            petition_id,
+            challenge_2,
+            responses_2,
+            key_images_2,
+        ));
+        System::assert_last_event(
+            Event::<Test>::PetitionThresholdReached {
+                petition_id,
+                signatures: 2,
+                proposal_scheduled: Some(true),
+            }
+            .into(),
+        );
+        assert_eq!(Petitions::<Test>::get(petition_id).unwrap().reached_at, Some(1));
+        assert!(PetitionProposals::<Test>::get(petition_id).is_none());
+
+        // 经过 EnactmentDelay 后由调度器执行
+        for n in 2..=4 {
+            System::set_block_number(n);
+            Scheduler::on_initialize(n);
+        }
+        assert_eq!(
+            frame::deps::sp_io::storage::get(b"petition").map(|v| v.to_vec()),
+            Some(b"reached".to_vec())
+        );
+    });
+}
+
+#[test]
+fn signed_account_cannot_schedule_root_call_via_petition() {
+    let petition_id = 0;
+    let ring_id = 0;
+    let (ks, decoys) = gen_signer_and_decoys_of_size::<Test, ()>(2);
+
+    let call = RuntimeCall::System(frame_system::Call::set_storage {
+        items: vec![(b"petition".to_vec(), b"reached".to_vec())],
+    });
+
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let proposal = Preimage::bound(call).unwrap();
+
+        // BOB 注册了只有自己一个真实成员的环，签名一次即可达到门槛
+        let message = RingSigVoting::petition_message(petition_id);
+        let (challenge, responses, ring, key_images) =
+            sign_message_at::<Test, ()>(ks, decoys, 1, &message);
+        assert_ok!(RingSigVoting::register_ring_group(
+            RuntimeOrigin::signed(BOB),
+            ring,
+        ));
+
+        // 但不能附带以 Root 执行的调用
+        assert_noop!(
+            RingSigVoting::create_petition(
+                RuntimeOrigin::signed(BOB),
+                b"Petition".to_vec().try_into().unwrap(),
+                ring_id,
+                1,
+                Some(proposal),
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(RingSigVoting::create_petition(
+            RuntimeOrigin::signed(BOB),
+            b"Petition".to_vec().try_into().unwrap(),
+            ring_id,
+            1,
+            None,
+        ));
+        assert_ok!(RingSigVoting::sign_petition(
+            RuntimeOrigin::signed(BOB),
+            petition_id,
+            challenge,
+            responses,
+            key_images,
+        ));
+        System::assert_last_event(
+            Event::<Test>::PetitionThresholdReached {
+                petition_id,
+                signatures: 1,
+                proposal_scheduled: None,
+            }
+            .into(),
+        );
+
+        // 调度器中没有任何调用
+        for n in 2..=4 {
+            System::set_block_number(n);
+            assert!(polkadot_sdk::pallet_scheduler::Agenda::<Test>::get(n).is_empty());
+            Scheduler::on_initialize(n);
+        }
+        assert_eq!(frame::deps::sp_io::storage::get(b"petition"), None);
+    });
+}
+
+#[test]
+fn whistleblower_report_acknowledged() {
+    let ring_id = 0;
+    let mut csprng = OsRng;
+    let keypair = |secret: Scalar| {
+        let public: [u8; 32] = (secret * RISTRETTO_BASEPOINT_POINT).compress().to_bytes();
+        (secret.to_bytes(), public)
+    };
+    let (committee_secret, committee_key) = keypair(Scalar::random(&mut csprng));
+    let (ks, decoys) = gen_signer_and_decoys::<Test, ()>();
+
+    // 举报加密给审查委员会，每条举报使用新的回执密钥
+    let report = |text: &[u8]| {
+        let sealed = crate::ecies::encrypt(
+            &committee_key,
+            &Scalar::random(&mut OsRng).to_bytes(),
+            text,
+        )
+        .unwrap();
+        let content = EncryptedMessage::<Test> {
+            ephemeral_public_key: sealed.ephemeral_public_key,
+            ciphertext: sealed.ciphertext.try_into().unwrap(),
+            auth_tag: sealed.auth_tag,
+        };
+        let (reply_secret, reply_key) = keypair(Scalar::random(&mut OsRng));
+        let (ring, challenge, responses, key_image) = sign_topic_message::<Test, ()>(
+            &ks,
+            &decoys,
+            &RingSigVoting::report_scope(ring_id),
+            &RingSigVoting::report_message(ring_id, &committee_key, &content, &reply_key),
+        );
+        (
+            content,
+            reply_secret,
+            H256::from(reply_key),
+            (challenge, responses, ring, key_image),
+        )
+    };
+
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (content, reply_secret, reply_key, (challenge, responses, ring, signed_image)) =
+            report(b"Exam answers were leaked before the final");
+        assert_ok!(RingSigVoting::register_ring_group(
+            RuntimeOrigin::signed(ALICE),
+            ring,
+        ));
+
+        assert_noop!(
+            RingSigVoting::submit_report(
+                RuntimeOrigin::signed(BOB),
+                ring_id,
+                content.clone(),
+                reply_key,
+                challenge,
+                responses.clone(),
+                signed_image,
+            ),
+            Error::<Test>::CommitteeKeyNotSet
+        );
+        assert_noop!(
+            RingSigVoting::set_committee_key(RuntimeOrigin::signed(ALICE), committee_key.into()),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            RingSigVoting::set_committee_key(RuntimeOrigin::root(), H256::repeat_byte(0xff)),
+            Error::<Test>::InvalidCommitteeKey
+        );
+        assert_ok!(RingSigVoting::set_committee_key(
+            RuntimeOrigin::root(),
+            committee_key.into()
+        ));
+
+        // 签名绑定回执公钥
+        assert_noop!(
+            RingSigVoting::submit_report(
+                RuntimeOrigin::signed(BOB),
+                ring_id,
+                content.clone(),
+                H256::from(keypair(Scalar::random(&mut OsRng)).1),
+                challenge,
+                responses.clone(),
+                signed_image,
+            ),
+            Error::<Test>::InvalidSignature
+        );
+        assert_ok!(RingSigVoting::submit_report(
+            RuntimeOrigin::signed(BOB),
+            ring_id,
+            content.clone(),
+            reply_key,
+            challenge,
+            responses.clone(),
+            signed_image,
+        ));
+        // 举报使用限定在举报中的密钥镜像，与投票的密钥镜像不同
+        let (_, _, _, vote_key_images) = sign_message::<Test, ()>(ks.clone(), decoys.clone(), b"vote");
+        assert_ne!(signed_image, vote_key_images[0]);
+        let key_image = CompressedRistrettoWrapper(signed_image.0);
+        System::assert_last_event(
+            Event::<Test>::ReportSubmitted {
+                report_id: 0,
+                ring_id,
+                key_image: key_image.clone(),
+            }
+            .into(),
+        );
+
+        // 重放同一条举报被拒绝
+        assert_noop!(
+            RingSigVoting::submit_report(
+                RuntimeOrigin::signed(BOB),
+                ring_id,
+                content,
+                reply_key,
+                challenge,
+                responses,
+                signed_image,
+            ),
+            Error::<Test>::ReplyKeyUsed
+        );
+
+        // 只有审查委员会能解密举报
+        let stored = Reports::<Test>::get(0).unwrap();
+        assert_eq!(stored.committee_key, committee_key);
+        assert_eq!(
+            crate::ecies::decrypt(
+                &committee_secret,
+                &stored.content.ephemeral_public_key,
+                &stored.content.ciphertext,
+                &stored.content.auth_tag,
+            ),
+            Some(b"Exam answers were leaked before the final".to_vec())
+        );
+
+        // 未回执的举报数有上限
+        for (text, result) in [
+            (&b"Follow-up"[..], Ok(())),
+            (&b"Another follow-up"[..], Err(Error::<Test>::TooManyOpenReports.into())),
+        ] {
+            let (content, _, reply_key, (challenge, responses, _, key_image)) = report(text);
+            assert_eq!(
+                RingSigVoting::submit_report(
+                    RuntimeOrigin::signed(BOB),
+                    ring_id,
+                    content,
+                    reply_key,
+                    challenge,
+                    responses,
+                    key_image,
+                ),
+                result
+            );
+        }
+        assert_eq!(OpenReports::<Test>::get(&key_image), 2);
+
+        // 回执加密给举报人的回执公钥，举报人凭密钥镜像认出自己的举报
+        let sealed = crate::ecies::encrypt(
+            &reply_key.0,
+            &Scalar::random(&mut csprng).to_bytes(),
+            b"Received, under review",
+        )
+        .unwrap();
+        let acknowledgement = EncryptedMessage::<Test> {
+            ephemeral_public_key: sealed.ephemeral_public_key,
+            ciphertext: sealed.ciphertext.try_into().unwrap(),
+            auth_tag: sealed.auth_tag,
+        };
+        assert_noop!(
+            RingSigVoting::acknowledge_report(
+                RuntimeOrigin::signed(ALICE),
+                0,
+                acknowledgement.clone()
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(RingSigVoting::acknowledge_report(
+            RuntimeOrigin::root(),
+            0,
+            acknowledgement.clone()
+        ));
+        System::assert_last_event(
+            Event::<Test>::ReportAcknowledged {
+                report_id: 0,
+                key_image: key_image.clone(),
+                acknowledgement: acknowledgement.clone(),
+            }
+            .into(),
+        );
+        assert_eq!(
+            crate::ecies::decrypt(
+                &reply_secret,
+                &acknowledgement.ephemeral_public_key,
+                &acknowledgement.ciphertext,
+                &acknowledgement.auth_tag,
+            ),
+            Some(b"Received, under review".to_vec())
+        );
+
+        assert!(Reports::<Test>::get(0).is_none());
+        assert_eq!(OpenReports::<Test>::get(&key_image), 1);
+        assert_noop!(
+            RingSigVoting::acknowledge_report(RuntimeOrigin::root(), 0, acknowledgement),
+            Error::<Test>::ReportNotFound
+        );
+    });
+}
+
+#[test]
+fn ring_membership_verified_per_domain_and_nonce() {
+    let ring_id = 0;
+    let (ks, decoys) = gen_signer_and_decoys::<Test, ()>();
+    let sign = |domain: &[u8], nonce: &[u8]| {
+        sign_topic_message::<Test, ()>(
+            &ks,
+            &decoys,
+            &RingSigVoting::membership_scope(ring_id, domain),
+            &RingSigVoting::membership_message(ring_id, domain, nonce, b"login"),
+        )
+    };
+    let (ring, challenge, responses, key_image) = sign(b"library", b"nonce-1");
+    let verify = |ring_id, domain: &[u8], nonce: &[u8], message: &[u8]| {
+        RingSigVoting::verify_membership(
+            ring_id,
+            domain,
+            nonce,
+            message,
+            challenge,
+            responses.to_vec(),
+            key_image,
+        )
+    };
+    // 同一成员在同一服务中的密钥镜像相同，在其他服务和投票中的密钥镜像不同
+    let (_, _, _, same_service) = sign(b"library", b"nonce-2");
+    let (_, _, _, other_service) = sign(b"canteen", b"nonce-1");
+    let (_, _, _, vote_key_images) = sign_message::<Test, ()>(ks.clone(), decoys.clone(), b"vote");
+    assert_eq!(same_service, key_image);
+    assert_ne!(other_service, key_image);
+    assert_ne!(vote_key_images[0], key_image);
+
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_eq!(verify(ring_id, b"library", b"nonce-1", b"login"), None);
+        assert_ok!(RingSigVoting::register_ring_group(
+            RuntimeOrigin::signed(ALICE),
+            ring.clone(),
+        ));
+
+        assert_eq!(
+            verify(ring_id, b"library", b"nonce-1", b"login"),
+            Some(key_image)
+        );
+        // 签名不能用于其他服务、其他挑战或其他消息
+        assert_eq!(verify(ring_id, b"canteen", b"nonce-1", b"login"), None);
+        assert_eq!(verify(ring_id, b"library", b"nonce-2", b"login"), None);
+        assert_eq!(verify(ring_id, b"library", b"nonce-1", b"logout"), None);
+
+        // 也不能用于其他公钥环
+        assert_ok!(RingSigVoting::register_ring_group(
+            RuntimeOrigin::signed(ALICE),
+            ring,
+        ));
+        assert_eq!(verify(ring_id + 1, b"library", b"nonce-1", b"login"), None);
+    });
+}
+
+#[test]
+fn membership_signature_rejected_as_vote() {
+    let poll_id = 0;
+    let ring_id = 0;
+
+    let mut csprng = OsRng;
+    let private_key_scalar = Scalar::random(&mut csprng);
+    let public_key_point = private_key_scalar * RISTRETTO_BASEPOINT_POINT;
+    let encryption_pubkey: H256 = public_key_point.compress().to_bytes().into();
+
+    // 链下服务选择的 domain、nonce 和消息，使编码后的成员身份消息恰好能拆成一张选票
+    let message = RingSigVoting::membership_message(ring_id, b"library", b"nonce-1", &[7; 72]);
+    assert_eq!(message.len(), 32 + 64 + 16);
+    let (ks, decoys) = gen_signer_and_decoys::<Test, ()>();
+    let (ring, challenge, responses, key_image) = sign_topic_message::<Test, ()>(
+        &ks,
+        &decoys,
+        &RingSigVoting::membership_scope(ring_id, b"library"),
+        &message,
+    );
+
+    let ephemeral_pubkey = H256::from_slice(&message[..32]);
+    let ciphertext: BoundedVec<u8, <Test as crate::Config>::MaxVoteSize> =
+        message[32..96].to_vec().try_into().unwrap();
+    let auth_tag = primitive_types::H128::from_slice(&message[96..]);
+
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(RingSigVoting::register_ring_group(
+            RuntimeOrigin::signed(ALICE),
+            ring,
+        ));
+        assert_ok!(RingSigVoting::create_poll(
+            RuntimeOrigin::signed(ALICE),
+            b"Poll 0".to_vec().try_into().unwrap(),
+            ring_id,
+            None,
+            None,
+            None,
+            encryption_pubkey,
+            PollConfig::default(),
+            None,
+        ));
+
+        // 选票签名的消息带有投票的域标识，成员身份签名不能被重放为选票
+        assert_noop!(
+            RingSigVoting::anonymous_vote(
+                RuntimeOrigin::signed(BOB),
+                poll_id,
+                ephemeral_pubkey,
+                ciphertext,
+                auth_tag,
+                challenge,
+                responses,
+                vec![key_image].try_into().unwrap(),
+            ),
+            Error::<Test>::InvalidSignature
+        );
+    });
+}
+
+#[test]
+fn instances_keep_separate_state() {
+    let description = b"Course evaluation".to_vec();
+
+    let mut csprng = OsRng;
+    let private_key_scalar = Scalar::random(&mut csprng);
+    let public_key_point = private_key_scalar * RISTRETTO_BASEPOINT_POINT;
+    let encryption_pubkey: H256 = public_key_point.compress().to_bytes().into();
+
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        // 课程评价实例有自己的公钥环和投票
+        assert_ok!(CourseEvaluation::register_ring_group(
+            RuntimeOrigin::signed(ALICE),
+            gen_ring::<Test, Instance1>(),
+        ));
+        assert_ok!(CourseEvaluation::create_poll(
+            RuntimeOrigin::signed(ALICE),
+            description.clone().try_into().unwrap(),
+            0,
+            None,
+            None,
+            None,
+            encryption_pubkey,
+            gen_poll_config(),
+            None,
+        ));
+
+        assert!(RingGroups::<Test, Instance1>::get(0).is_some());
+        assert!(Polls::<Test, Instance1>::get(0).is_some());
+        assert!(RingGroups::<Test>::get(0).is_none());
+        assert!(Polls::<Test>::get(0).is_none());
+
+        // 默认实例（赞成/反对）中不存在该公钥环
+        assert_err!(
+            RingSigVoting::create_poll(
+                RuntimeOrigin::signed(ALICE),
+                description.try_into().unwrap(),
+                0,
+                None,
+                None,
+                None,
+                encryption_pubkey,
+                PollConfig::default(),
+                None,
+            ),
+            Error::<Test>::RingGroupNotFound
+        );
+    });
+}
+
+#[test]
+fn schema_tally_validates_answers() {
+    use crate::schema_voting::{QuestionTally, Tally, TallyHandler as SchemaTally, Vote};
+
+    let config = gen_poll_config();
+    let vote = |answers: Vec<u32>| -> Vote { answers.try_into().unwrap() };
+
+    // 必须提供问卷结构
+    assert!(SchemaTally::new_tally(&PollConfig::default()).is_err());
+
+    let mut tally: Tally = SchemaTally::new_tally(&config).unwrap();
+    assert_ok!(SchemaTally::update_tally(&config, &vote(vec![2, 5]), &mut tally));
+    assert_ok!(SchemaTally::update_tally(&config, &vote(vec![0, 1]), &mut tally));
+
+    // 选项编号越界、取值超出范围或答案数量不符时拒绝，且不改变计票结果
+    let before = tally.clone();
+    assert!(SchemaTally::update_tally(&config, &vote(vec![3, 1]), &mut tally).is_err());
+    assert!(SchemaTally::update_tally(&config, &vote(vec![0, 6]), &mut tally).is_err());
+    assert!(SchemaTally::update_tally(&config, &vote(vec![0]), &mut tally).is_err());
+    assert_eq!(tally, before);
+
+    assert_eq!(
+        tally[0],
+        QuestionTally::Choice(vec![1, 0, 1].try_into().unwrap())
+    );
+    assert_eq!(
+        tally[1],
+        QuestionTally::Range {
+            min: 1,
+            max: 5,
+            responses: 2,
+            sum: 6
+        }
+    );
+    assert_ok!(SchemaTally::validate_tally(&config, &tally));
+
+    // 各问题作答人数不一致的计票结果无效
+    let mut forged = tally.clone();
+    forged[0] = QuestionTally::Choice(vec![5, 0, 1].try_into().unwrap());
+    assert!(SchemaTally::validate_tally(&config, &forged).is_err());
+}
+
+#[test]
+fn ranked_choice_runoff() {
+    use crate::ranked_voting::{Round, TallyHandler, Vote};
+    type Ranked = TallyHandler<ConstU32<1000>>;
+    type Tally = crate::ranked_voting::Tally<ConstU32<1000>>;
+
+    let config = PollConfig {
+        schema: Some(BallotSchema {
+            questions: vec![Question::Choice { options: 3 }].try_into().unwrap(),
+        }),
+        ..Default::default()
+    };
+    let vote = |ranking: Vec<u32>| -> Vote { ranking.try_into().unwrap() };
+    let cast = |tally: &mut Tally, ranking: Vec<u32>, times: u32| {
+        for _ in 0..times {
+            assert_ok!(Ranked::update_tally(&config, &vote(ranking.clone()), tally));
+        }
+    };
+    let round = |counts: Vec<u32>, eliminated: Option<u32>| Round {
+        counts: counts.try_into().unwrap(),
+        eliminated,
+    };
+
+    // 重复、越界或空的排序无效
+    let mut tally: Tally = Ranked::new_tally(&config).unwrap();
+    assert!(Ranked::update_tally(&config, &vote(vec![0, 0]), &mut tally).is_err());
+    assert!(Ranked::update_tally(&config, &vote(vec![3]), &mut tally).is_err());
+    assert!(Ranked::update_tally(&config, &vote(vec![]), &mut tally).is_err());
+
+    // 第一轮无人过半，淘汰 2 号后其选票转给 1 号
+    cast(&mut tally, vec![0, 1], 4);
+    cast(&mut tally, vec![1, 0], 3);
+    cast(&mut tally, vec![2, 1], 2);
+    assert_eq!(tally.ballots.len(), 3);
+    assert_ok!(Ranked::validate_tally(&config, &tally));
+    assert_ok!(Ranked::finalize(&config, &mut tally));
+    assert_eq!(
+        tally.rounds.to_vec(),
+        vec![round(vec![4, 3, 2], Some(2)), round(vec![4, 5, 0], None)]
+    );
+    assert_eq!(tally.winner, Some(1));
+
+    // 票数相同且之前没有轮次可比较时，淘汰编号最大的候选人
+    let mut tally: Tally = Ranked::new_tally(&config).unwrap();
+    cast(&mut tally, vec![0], 1);
+    cast(&mut tally, vec![1], 1);
+    cast(&mut tally, vec![2, 1], 1);
+    assert_ok!(Ranked::finalize(&config, &mut tally));
+    assert_eq!(
+        tally.rounds.to_vec(),
+        vec![
+            round(vec![1, 1, 1], Some(2)),
+            round(vec![1, 2, 0], None)
+        ]
+    );
+    assert_eq!(tally.winner, Some(1));
+
+    // 每张选票的排序都不同时，所有有效选票仍能计入
+    let config = PollConfig {
+        schema: Some(BallotSchema {
+            questions: vec![Question::Choice { options: 6 }].try_into().unwrap(),
+        }),
+        ..Default::default()
+    };
+    let mut tally: Tally = Ranked::new_tally(&config).unwrap();
+    for a in 0..6 {
+        for b in (0..6).filter(|b| *b != a) {
+            for c in (0..6).filter(|c| *c != a && *c != b) {
+                for d in (0..6).filter(|d| ![a, b, c].contains(d)) {
+                    assert_ok!(Ranked::update_tally(&config, &vote(vec![a, b, c, d]), &mut tally));
+                }
+            }
+        }
+    }
+    assert_eq!(tally.ballots.len(), 360);
+    assert_ok!(Ranked::validate_tally(&config, &tally));
+}
+
+#[test]
+fn schulze_ordering() {
+    use crate::schulze_voting::{Tally, TallyHandler as Schulze, Vote};
+
+    let config = PollConfig {
+        schema: Some(BallotSchema {
+            questions: vec![Question::Choice { options: 3 }].try_into().unwrap(),
+        }),
+        ..Default::default()
+    };
+    let mut tally: Tally = Schulze::new_tally(&config).unwrap();
+    let mut cast = |ranking: Vec<u32>, times: u32| {
+        let vote: Vote = ranking.try_into().unwrap();
+        for _ in 0..times {
+            assert_ok!(Schulze::update_tally(&config, &vote, &mut tally));
+        }
+    };
+
+    // 孔多塞循环：0 > 1 (5:4)，1 > 2 (7:2)，2 > 0 (6:3)
+    cast(vec![0, 1, 2], 3);
+    cast(vec![1, 2, 0], 4);
+    cast(vec![2, 0, 1], 2);
+    assert_eq!(tally.preferences[0].to_vec(), vec![0, 5, 3]);
+    assert_eq!(tally.preferences[1].to_vec(), vec![4, 0, 7]);
+    assert_eq!(tally.preferences[2].to_vec(), vec![6, 2, 0]);
+
+    assert_ok!(Schulze::validate_tally(&config, &tally));
+    // 最强路径：1 对 2 为 7:5，1 对 0 为 6:5，2 对 0 为 6:5
+    assert_ok!(Schulze::finalize(&config, &mut tally));
+    assert_eq!(tally.order.to_vec(), vec![1, 2, 0]);
+
+    // 部分排序：认为 2 优于其余两人，0 与 1 之间没有偏好
+    let mut partial: Tally = Schulze::new_tally(&config).unwrap();
+    assert_ok!(Schulze::update_tally(&config, &vec![2].try_into().unwrap(), &mut partial));
+    assert_eq!(partial.preferences[2].to_vec(), vec![1, 1, 0]);
+    assert_eq!(partial.preferences[0].to_vec(), vec![0, 0, 0]);
+    assert!(Schulze::update_tally(&config, &vec![1, 1].try_into().unwrap(), &mut partial).is_err());
+}
+
+#[test]
+fn approval_and_score_tallies() {
+    use crate::{approval_voting, score_voting};
+
+    // 认可投票：四个选项，勾选任意多个
+    let config = PollConfig {
+        schema: Some(BallotSchema {
+            questions: vec![Question::Choice { options: 4 }].try_into().unwrap(),
+        }),
+        ..Default::default()
+    };
+    let mut tally = approval_voting::TallyHandler::new_tally(&config).unwrap();
+    assert_ok!(approval_voting::TallyHandler::update_tally(&config, &0b0101, &mut tally));
+    assert_ok!(approval_voting::TallyHandler::update_tally(&config, &0b0110, &mut tally));
+    assert_ok!(approval_voting::TallyHandler::update_tally(&config, &0, &mut tally));
+    assert!(approval_voting::TallyHandler::update_tally(&config, &0b1_0000, &mut tally).is_err());
+    assert_eq!(tally.ballots, 3);
+    assert_eq!(tally.approvals.to_vec(), vec![1, 1, 2, 0]);
+    assert_ok!(approval_voting::TallyHandler::validate_tally(&config, &tally));
+
+    // 认可数不能超过选票数
+    let mut forged = tally.clone();
+    forged.approvals[3] = 4;
+    assert!(approval_voting::TallyHandler::validate_tally(&config, &forged).is_err());
+
+    // 计数溢出时报错且不改变计票结果
+    let mut full = tally.clone();
+    full.approvals[0] = u32::MAX;
+    assert!(approval_voting::TallyHandler::update_tally(&config, &0b0011, &mut full).is_err());
+    assert_eq!(full.approvals[1], 1);
+
+    // 评分投票：两个选项，分别按 1..=5 和 0..=10 打分
+    let config = PollConfig {
+        schema: Some(BallotSchema {
+            questions: vec![
+                Question::Range { min: 1, max: 5 },
+                Question::Range { min: 0, max: 10 },
+            ]
+            .try_into()
+            .unwrap(),
+        }),
+        ..Default::default()
+    };
+    let score = |scores: Vec<Option<u32>>| -> score_voting::Vote { scores.try_into().unwrap() };
+    let mut tally = score_voting::TallyHandler::new_tally(&config).unwrap();
+    assert_ok!(score_voting::TallyHandler::update_tally(&config, &score(vec![Some(5), Some(7)]), &mut tally));
+    assert_ok!(score_voting::TallyHandler::update_tally(&config, &score(vec![Some(2), None]), &mut tally));
+    assert!(score_voting::TallyHandler::update_tally(&config, &score(vec![Some(0), None]), &mut tally).is_err());
+    assert!(score_voting::TallyHandler::update_tally(&config, &score(vec![Some(1)]), &mut tally).is_err());
+    assert_eq!((tally[0].count, tally[0].sum), (2, 7));
+    assert_eq!((tally[1].count, tally[1].sum), (1, 7));
+    assert_ok!(score_voting::TallyHandler::validate_tally(&config, &tally));
+
+    // 单选题不能用于评分投票
+    assert!(score_voting::TallyHandler::new_tally(&gen_poll_config()).is_err());
+}
+
+#[test]
+fn quadratic_voting_enforces_budget() {
+    use crate::quadratic_voting::{cost, Vote};
+    type Quadratic = crate::quadratic_voting::TallyHandler<ConstU64<100>>;
+
+    let config = PollConfig {
+        schema: Some(BallotSchema {
+            questions: vec![Question::Choice { options: 3 }].try_into().unwrap(),
+        }),
+        ..Default::default()
+    };
+    let vote = |votes: Vec<i32>| -> Vote { votes.try_into().unwrap() };
+    assert_eq!(cost(&vote(vec![6, -8, 0])), Some(100));
+
+    let mut tally = Quadratic::new_tally(&config).unwrap();
+    assert_ok!(Quadratic::update_tally(&config, &vote(vec![6, -8, 0]), &mut tally));
+    assert_ok!(Quadratic::update_tally(&config, &vote(vec![10, 0, 0]), &mut tally));
+
+    // 超出 100 点预算的选票在计票时被拒绝
+    let before = tally.clone();
+    assert!(Quadratic::update_tally(&config, &vote(vec![10, 1, 0]), &mut tally).is_err());
+    assert!(Quadratic::update_tally(&config, &vote(vec![i32::MIN, 0, 0]), &mut tally).is_err());
+    assert_eq!(tally, before);
+
+    assert_eq!(tally.ballots, 2);
+    assert_eq!(tally.votes.to_vec(), vec![16, -8, 0]);
+    assert_ok!(Quadratic::validate_tally(&config, &tally));
+
+    // 两张选票在一个选项上最多共 20 票
+    let mut forged = tally.clone();
+    forged.votes[2] = 21;
+    assert!(Quadratic::validate_tally(&config, &forged).is_err());
+}
+
+#[test]
+fn participatory_budget_selects_projects() {
+    use crate::budget_voting::{TallyHandler as Budgeting, Vote};
+
+    // 四个项目，每张选票分配 10 点，资金上限 100
+    let config = PollConfig {
+        budget: Some(BudgetConfig {
+            allocation: 10,
+            costs: vec![60, 50, 30, 20].try_into().unwrap(),
+            cap: 100,
+        }),
+        ..Default::default()
+    };
+    assert!(Budgeting::new_tally(&PollConfig::default()).is_err());
+
+    let vote = |points: Vec<u32>| -> Vote { points.try_into().unwrap() };
+    let mut tally = Budgeting::new_tally(&config).unwrap();
+    assert_ok!(Budgeting::update_tally(&config, &vote(vec![5, 5, 0, 0]), &mut tally));
+    assert_ok!(Budgeting::update_tally(&config, &vote(vec![4, 2, 4, 0]), &mut tally));
+    assert_ok!(Budgeting::update_tally(&config, &vote(vec![0, 0, 3, 7]), &mut tally));
+
+    // 总点数必须恰好为 10
+    assert!(Budgeting::update_tally(&config, &vote(vec![5, 5, 1, 0]), &mut tally).is_err());
+    assert!(Budgeting::update_tally(&config, &vote(vec![5, 4, 0, 0]), &mut tally).is_err());
+    assert_eq!(tally.points.to_vec(), vec![9, 7, 7, 7]);
+
+    // 按得票依次选择：项目 0 (60)，项目 1 (50) 超出剩余资金被跳过，项目 2 (30)，
+    // 项目 3 (20) 超出剩余的 10 被跳过
+    assert_ok!(Budgeting::validate_tally(&config, &tally));
+    assert_ok!(Budgeting::finalize(&config, &mut tally));
+    assert_eq!(tally.funded.to_vec(), vec![0, 2]);
+
+    // 点数总和与选票数不符的计票结果无效
+    let mut forged = tally.clone();
+    forged.points[3] = 8;
+    assert!(Budgeting::validate_tally(&config, &forged).is_err());
+}
+
+#[test]
+fn evaluative_statistics() {
+    use crate::evaluative_voting::{Score, Tally, TallyHandler as Evaluative, Vote};
+    use frame::deps::sp_runtime::FixedU128;
+
+    let config = PollConfig::default();
+    let mut tally = Tally::default();
+    // 第一题：1, 2, 2, 5；第二题：4, 4, 4, 4
+    for first in [Score::One, Score::Two, Score::Two, Score::Five] {
+        let vote: Vote = vec![first, Score::Four].try_into().unwrap();
+        assert_ok!(Evaluative::update_tally(&config, &vote, &mut tally));
+    }
+    assert_eq!(tally[0].distribution(), [1, 2, 0, 0, 1]);
+    assert_eq!(tally[0].count(&Score::Two), 2);
+    assert_eq!(tally[1].responses(), 4);
+    assert_eq!(tally[0].summary(), None);
+
+    assert_ok!(Evaluative::finalize(&config, &mut tally));
+    let summary = tally[0].summary().unwrap();
+    assert_eq!(summary.responses, 4);
+    assert_eq!(summary.mean, FixedU128::from_rational(5, 2));
+    assert_eq!(summary.median, Score::Two);
+    assert_eq!(summary.mode, Score::Two);
+    // 方差 = (4·34 - 10²) / 16 = 2.25
+    assert_eq!(summary.std_dev, FixedU128::from_rational(3, 2));
+
+    let summary = tally[1].summary().unwrap();
+    assert_eq!(summary.mean, FixedU128::from_u32(4));
+    assert_eq!(summary.median, Score::Four);
+    assert_eq!(summary.std_dev, FixedU128::from_u32(0));
+}
+
+#[test]
+fn commented_ballots_tally_scores_only() {
+    use crate::evaluative_voting::{Ballot, BallotTallyHandler, Score, Tally};
+
+    let ballot = |comment: &[u8]| Ballot {
+        scores: vec![Score::Four, Score::Five].try_into().unwrap(),
+        comments: vec![comment.to_vec().try_into().unwrap()].try_into().unwrap(),
+    };
+
+    let config = PollConfig::default();
+    let mut tally = Tally::default();
+    assert_ok!(BallotTallyHandler::update_tally(
+        &config,
+        &ballot("讲解清楚，作业偏多".as_bytes()),
+        &mut tally
+    ));
+    assert!(BallotTallyHandler::update_tally(&config, &ballot(&[0xff, 0xfe]), &mut tally).is_err());
+    assert_eq!(tally.len(), 2);
+    assert_eq!(tally[1].distribution(), [0, 0, 0, 0, 1]);
+
+    assert_ok!(BallotTallyHandler::finalize(&config, &mut tally));
+    assert_eq!(tally[0].summary().unwrap().median, Score::Four);
+}
+
+#[test]
+fn sharded_tallies_merge() {
+    use crate::evaluative_voting::{Score, TallyHandler as Evaluative};
+    use crate::schema_voting::TallyHandler as SchemaTally;
+    use crate::simple_voting::{TallyHandler as Simple, Vote};
+
+    // 赞成/反对：两个分片合并后按通过门槛判定
+    let config = PollConfig {
+        threshold: Some(ApprovalThreshold::SuperMajority),
+        ..Default::default()
+    };
+    let (mut shard_a, mut shard_b) = ((0, 0), (0, 0));
+    assert_ok!(Simple::validate(&config, &Vote::Yea));
+    assert_ok!(Simple::update_tally(&config, &Vote::Yea, &mut shard_a));
+    assert_ok!(Simple::update_tally(&config, &Vote::Nay, &mut shard_a));
+    assert_ok!(Simple::update_tally(&config, &Vote::Yea, &mut shard_b));
+    assert_ok!(Simple::merge(&config, &mut shard_a, &shard_b));
+    assert_eq!(shard_a, (2, 1));
+    assert_eq!(Simple::finalize(&config, &mut shard_a).unwrap().passed, Some(true));
+    assert!(Simple::merge(&config, &mut (u32::MAX, 0), &(1, 0)).is_err());
+
+    // 评分：空分片不影响合并，问题数不同的分片不能合并
+    let config = PollConfig::default();
+    let vote = |scores: Vec<Score>| -> crate::evaluative_voting::Vote { scores.try_into().unwrap() };
+    assert!(Evaluative::validate(&config, &vote(vec![])).is_err());
+    let mut merged = crate::evaluative_voting::Tally::default();
+    let mut shard = merged.clone();
+    assert_ok!(Evaluative::update_tally(&config, &vote(vec![Score::Five, Score::One]), &mut shard));
+    assert_ok!(Evaluative::merge(&config, &mut merged, &shard));
+    assert_ok!(Evaluative::merge(&config, &mut merged, &shard));
+    assert_ok!(Evaluative::merge(&config, &mut merged, &Default::default()));
+    assert_eq!(merged[0].distribution(), [0, 0, 0, 0, 2]);
+    let mut other = Default::default();
+    assert_ok!(Evaluative::update_tally(&config, &vote(vec![Score::Two]), &mut other));
+    assert!(Evaluative::merge(&config, &mut merged, &other).is_err());
+    assert_eq!(merged[1].distribution(), [2, 0, 0, 0, 0]);
+
+    // 问卷：合并结果与逐张计票相同
+    let config = gen_poll_config();
+    let vote = |answers: Vec<u32>| -> crate::schema_voting::Vote { answers.try_into().unwrap() };
+    assert!(SchemaTally::validate(&config, &vote(vec![3, 1])).is_err());
+    let mut sequential = SchemaTally::new_tally(&config).unwrap();
+    let mut shard_a = sequential.clone();
+    let mut shard_b = sequential.clone();
+    for (i, answers) in [vec![0, 1], vec![2, 5], vec![2, 3]].into_iter().enumerate() {
+        assert_ok!(SchemaTally::update_tally(&config, &vote(answers.clone()), &mut sequential));
+        let shard = if i % 2 == 0 { &mut shard_a } else { &mut shard_b };
+        assert_ok!(SchemaTally::update_tally(&config, &vote(answers), shard));
+    }
+    assert_ok!(SchemaTally::merge(&config, &mut shard_a, &shard_b));
+    assert_eq!(shard_a, sequential);
+    assert_ok!(SchemaTally::validate_tally(&config, &shard_a));
+}
+
+#[test]
+fn comments_published_after_close() {
+    let poll_id = 0;
+    let description = b"Poll 0".to_vec();
+
+    let mut csprng = OsRng;
+    let private_key_scalar = Scalar::random(&mut csprng);
+    let public_key_point = private_key_scalar * RISTRETTO_BASEPOINT_POINT;
+    let encryption_privkey: H256 = private_key_scalar.to_bytes().into();
+    let encryption_pubkey: H256 = public_key_point.compress().to_bytes().into();
+
+    let comments: Vec<Vec<u8>> = vec!["讲解清楚".as_bytes().to_vec(), b"More examples".to_vec()];
+
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(RingSigVoting::register_ring_group(
+            RuntimeOrigin::signed(ALICE),
+            gen_ring::<Test, ()>(),
+        ));
+        assert_ok!(RingSigVoting::create_poll(
+            RuntimeOrigin::signed(ALICE),
+            description.try_into().unwrap(),
+            0,
+            None,
+            None,
+            None,
+            encryption_pubkey,
+            PollConfig::default(),
+            None,
+        ));
+
+        let comments_hash = Preimage::note(comments.encode().into()).unwrap();
+
+        // 投票关闭前不能公布评论
+        assert_err!(
+            RingSigVoting::publish_comments(RuntimeOrigin::root(), poll_id, comments_hash),
+            Error::<Test>::InvalidPollStatus
+        );
+
+        assert_ok!(RingSigVoting::close_poll(
+            RuntimeOrigin::root(),
+            poll_id,
+            Some(encryption_privkey)
+        ));
+        // 计票完成前不能公布
+        assert_err!(
+            RingSigVoting::publish_comments(RuntimeOrigin::root(), poll_id, comments_hash),
+            Error::<Test>::InvalidPollStatus
+        );
+        assert_ok!(RingSigVoting::tally_batch(RuntimeOrigin::signed(BOB), poll_id, 1));
+
+        // 只有关闭投票的权限才能公布，且评论必须已作为 Preimage 上链
+        assert_err!(
+            RingSigVoting::publish_comments(
+                RuntimeOrigin::signed(ALICE),
+                poll_id,
+                comments_hash
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_err!(
+            RingSigVoting::publish_comments(RuntimeOrigin::root(), poll_id, H256::zero()),
+            Error::<Test>::PreimageNotExist
+        );
+
+        assert_ok!(RingSigVoting::publish_comments(
+            RuntimeOrigin::root(),
+            poll_id,
+            comments_hash
+        ));
+        assert_eq!(PollComments::<Test>::get(poll_id), Some(comments_hash));
+        System::assert_last_event(
+            Event::<Test>::CommentsPublished {
+                poll_id,
+                comments_hash,
+            }
+            .into(),
+        );
+
+        assert_err!(
+            RingSigVoting::publish_comments(RuntimeOrigin::root(), poll_id, comments_hash),
+            Error::<Test>::CommentsAlreadyPublished
+        );
+    });
+}
+
+#[test]
+fn multi_subject_ballots() {
+    use crate::evaluative_voting::Score;
+    use crate::multi_subject_voting::{SubjectScores, TallyHandler as MultiSubject, Vote};
+
+    // 一次评价覆盖两位教师（ID 7 和 9）
+    let config = PollConfig {
+        subjects: vec![7, 9].try_into().unwrap(),
+        ..Default::default()
+    };
+    assert!(MultiSubject::new_tally(&PollConfig::default()).is_err());
+
+    let block = |subject, scores: Vec<Score>| SubjectScores {
+        subject,
+        scores: scores.try_into().unwrap(),
+    };
+    let vote = |blocks: Vec<SubjectScores>| -> Vote { blocks.try_into().unwrap() };
+
+    let mut tally = MultiSubject::new_tally(&config).unwrap();
+    assert_ok!(MultiSubject::update_tally(
+        &config,
+        &vote(vec![
+            block(7, vec![Score::Five, Score::Four]),
+            block(9, vec![Score::Two])
+        ]),
+        &mut tally
+    ));
+    // 只评价其中一位教师
+    assert_ok!(MultiSubject::update_tally(
+        &config,
+        &vote(vec![block(9, vec![Score::Three])]),
+        &mut tally
+    ));
+
+    // 未知对象或重复对象的选票无效，且不改变计票结果
+    let before = tally.clone();
+    assert!(MultiSubject::update_tally(&config, &vote(vec![block(8, vec![Score::One])]), &mut tally).is_err());
+    assert!(MultiSubject::update_tally(
+        &config,
+        &vote(vec![block(9, vec![Score::One]), block(9, vec![Score::One])]),
+        &mut tally
+    )
+    .is_err());
+    assert_eq!(tally, before);
+
+    assert_eq!(tally[0].stats.len(), 2);
+    assert_eq!(tally[1].stats[0].distribution(), [0, 1, 1, 0, 0]);
+
+    assert_ok!(MultiSubject::validate_tally(&config, &tally));
+    assert_ok!(MultiSubject::finalize(&config, &mut tally));
+    assert_eq!(tally[1].stats[0].summary().unwrap().responses, 2);
+
+    // 对象与投票配置不符的计票结果无效
+    let mut forged = tally.clone();
+    forged[1].subject = 8;
+    assert!(MultiSubject::validate_tally(&config, &forged).is_err());
+}
+
+#[test]
+fn ballot_schema_checked_on_create_and_close() {
+    let poll_id = 0;
+    let description = b"Course evaluation".to_vec();
+
+    let mut csprng = OsRng;
+    let private_key_scalar = Scalar::random(&mut csprng);
+    let public_key_point = private_key_scalar * RISTRETTO_BASEPOINT_POINT;
+    let encryption_privkey: H256 = private_key_scalar.to_bytes().into();
+    let encryption_pubkey: H256 = public_key_point.compress().to_bytes().into();
+
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(CourseEvaluation::register_ring_group(
+            RuntimeOrigin::signed(ALICE),
+            gen_ring::<Test, Instance1>(),
+        ));
+
+        let create = |config: PollConfig| {
+            CourseEvaluation::create_poll(
+                RuntimeOrigin::signed(ALICE),
+                description.clone().try_into().unwrap(),
+                0,
+                None,
+                None,
+                None,
+                encryption_pubkey,
+                config,
+                None,
+            )
+        };
+
+        // 缺少问卷或问卷无效
+        assert_err!(
+            create(PollConfig::default()),
+            Error::<Test, Instance1>::InvalidBallotSchema
+        );
+        let invalid = PollConfig {
+            schema: Some(BallotSchema {
+                questions: vec![Question::Choice { options: 1 }]
+                    .try_into()
+                    .unwrap(),
+            }),
+            ..Default::default()
+        };
+        assert_err!(create(invalid), Error::<Test, Instance1>::InvalidBallotSchema);
+
+        // 问卷计票不判定是否通过，不接受通过门槛
+        assert_err!(
+            create(PollConfig {
+                threshold: Some(ApprovalThreshold::SimpleMajority),
+                ..gen_poll_config()
+            }),
+            Error::<Test, Instance1>::ThresholdNotSupported
+        );
+
+        // 计票结果按问卷结构初始化
+        let config = gen_poll_config();
+        assert_ok!(create(config.clone()));
+        let initial = PollVotes::<Test, Instance1>::get(poll_id);
+        assert_eq!(
+            initial,
+            crate::schema_voting::TallyHandler::new_tally(&config).unwrap()
+        );
+
+        // 链上计票从初始计票结果开始累加
+        assert_ok!(CourseEvaluation::close_poll(
+            RuntimeOrigin::root(),
+            poll_id,
+            Some(encryption_privkey),
+        ));
+        assert_ok!(CourseEvaluation::tally_batch(RuntimeOrigin::signed(BOB), poll_id, 1));
+        assert_eq!(CourseEvaluation::closed_tally(poll_id), Some(initial));
+
+        // 计算最终结果前检查计票结果，不符合问卷结构时按未通过关闭
+        let poll_id = poll_id + 1;
+        assert_ok!(create(config));
+        assert_ok!(CourseEvaluation::close_poll(
+            RuntimeOrigin::root(),
+            poll_id,
+            Some(encryption_privkey),
+        ));
+        PollVotes::<Test, Instance1>::insert(poll_id, crate::schema_voting::Tally::default());
+        assert_ok!(CourseEvaluation::tally_batch(RuntimeOrigin::signed(BOB), poll_id, 1));
+        System::assert_has_event(Event::<Test, Instance1>::TallyFailed { poll_id }.into());
+        assert_eq!(
+            Polls::<Test, Instance1>::get(poll_id).unwrap().status,
+            PollStatus::Closed
+        );
+    });
+}
+
+#[test]
+fn anonymous_messages_linkable_within_topic() {
+    let (ks, decoys) = gen_signer_and_decoys::<Test, ()>();
+    let text = |msg: &[u8]| MessageContent::<Test>::Text(msg.to_vec().try_into().unwrap());
+    let sign = |topic_id: TopicId, content: &MessageContent<Test>| {
+        sign_topic_message::<Test, ()>(
+            &ks,
+            &decoys,
+            &RingSigVoting::topic_scope(topic_id),
+            &content.encode(),
+        )
+    };
+
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let question = text(b"Will the exam cover chapter 5?");
+        let (ring, challenge, responses, key_image) = sign(0, &question);
+        assert_ok!(RingSigVoting::register_ring_group(
+            RuntimeOrigin::signed(ALICE),
+            ring,
+        ));
+
+        assert_noop!(
+            RingSigVoting::create_topic(RuntimeOrigin::signed(ALICE), 1, None),
+            Error::<Test>::RingGroupNotFound
+        );
+        assert_noop!(
+            RingSigVoting::create_topic(
+                RuntimeOrigin::signed(ALICE),
+                0,
+                Some(RateLimit { posts: 0, period: 10 }),
+            ),
+            Error::<Test>::InvalidRateLimit
+        );
+        // 话题 0 每 10 个区块限发 1 条，话题 1 不限
+        assert_ok!(RingSigVoting::create_topic(
+            RuntimeOrigin::signed(ALICE),
+            0,
+            Some(RateLimit { posts: 1, period: 10 }),
+        ));
+        assert_ok!(RingSigVoting::create_topic(RuntimeOrigin::signed(ALICE), 0, None));
+
+        // 签名与话题或内容不符时被拒绝
+        assert_noop!(
+            RingSigVoting::post_message(
+                RuntimeOrigin::signed(BOB),
+                1,
+                question.clone(),
+                challenge,
+                responses.clone(),
+                key_image,
+            ),
+            Error::<Test>::InvalidSignature
+        );
+        assert_noop!(
+            RingSigVoting::post_message(
+                RuntimeOrigin::signed(BOB),
+                0,
+                text(b"Will the exam cover chapter 6?"),
+                challenge,
+                responses.clone(),
+                key_image,
+            ),
+            Error::<Test>::InvalidSignature
+        );
+        assert_noop!(
+            RingSigVoting::post_message(
+                RuntimeOrigin::signed(BOB),
+                2,
+                question.clone(),
+                challenge,
+                responses.clone(),
+                key_image,
+            ),
+            Error::<Test>::TopicNotFound
+        );
+
+        assert_ok!(RingSigVoting::post_message(
+            RuntimeOrigin::signed(BOB),
+            0,
+            question.clone(),
+            challenge,
+            responses,
+            key_image,
+        ));
+        System::assert_last_event(
+            Event::<Test>::AnonymousMessagePosted {
+                topic_id: 0,
+                ring_id: 0,
+                key_image: CompressedRistrettoWrapper(key_image.0),
+                content: question,
+            }
+            .into(),
+        );
+
+        // 同一成员在同一话题中的密钥镜像相同，超出频率限制
+        let follow_up = text(b"And chapter 6?");
+        let (_, challenge, responses, linked) = sign(0, &follow_up);
+        assert_eq!(linked, key_image);
+        assert_noop!(
+            RingSigVoting::post_message(
+                RuntimeOrigin::signed(BOB),
+                0,
+                follow_up.clone(),
+                challenge,
+                responses.clone(),
+                linked,
+            ),
+            Error::<Test>::RateLimited
+        );
+
+        // 在另一个话题中的密钥镜像不同，且不受限制
+        let (_, challenge_1, responses_1, unlinked) = sign(1, &follow_up);
+        assert_ne!(unlinked, key_image);
+        for _ in 0..2 {
+            assert_ok!(RingSigVoting::post_message(
+                RuntimeOrigin::signed(BOB),
+                1,
+                follow_up.clone(),
+                challenge_1,
+                responses_1.clone(),
+                unlinked,
+            ));
+        }
+
+        // 下一个周期可以再次留言
+        System::set_block_number(11);
+        assert_ok!(RingSigVoting::post_message(
+            RuntimeOrigin::signed(BOB),
+            0,
+            follow_up,
+            challenge,
+            responses,
+            linked,
+        ));
+        assert_eq!(Topics::<Test>::get(0).unwrap().posts, 2);
+        assert_eq!(Topics::<Test>::get(1).unwrap().posts, 2);
+
+        // 长留言以 Preimage 哈希引用
+        use frame::deps::sp_runtime::traits::Hash;
+        let long = vec![b'?'; 1024];
+        let hash = <Test as frame_system::Config>::Hashing::hash(&long);
+        let content = MessageContent::<Test>::Preimage(hash);
+        let (_, challenge, responses, key_image) = sign(1, &content);
+        assert_noop!(
+            RingSigVoting::post_message(
+                RuntimeOrigin::signed(BOB),
+                1,
+                content.clone(),
+                challenge,
+                responses.clone(),
+                key_image,
+            ),
+            Error::<Test>::PreimageNotExist
+        );
+        assert_eq!(Preimage::note(long.into()).unwrap(), hash);
+        assert_ok!(RingSigVoting::post_message(
+            RuntimeOrigin::signed(BOB),
+            1,
+            content,
+            challenge,
+            responses,
+            key_image,
+        ));
+    });
+}
+
+#[test]
+fn ecies_test_vectors() {
+    use crate::ecies;
+
+    let unhex = |hex: &str| -> Vec<u8> {
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            .collect()
+    };
+    let array = |hex: &str| -> [u8; 32] { unhex(hex).try_into().unwrap() };
+
+    // (私钥, 公钥, 临时私钥, 明文, 临时公钥, 密文, 认证标签)
+    let vectors = [
+        (
+            "0101010101010101010101010101010101010101010101010101010101010101",
+            "3e440469a098036d89ffb2d77a4542928f2f74c2b5769da7480736ace829dc10",
+            "0202020202020202020202020202020202020202020202020202020202020202",
+            "00",
+            "f6d73cfa04628744bf6939147f8535be14a0ef13e3ab294413177b541f08da09",
+            "6b",
+            "588704766f38289de6f04cf00bb712bf",
+        ),
+        (
+            "0707070707070707070707070707070707070707070707070707070707070707",
+            "aaf82404e5f7bfa7352ce093e4aabe82435385d64aa870090a56d1aa36169800",
+            "0909090909090909090909090909090909090909090909090909090909090909",
+            "01",
+            "b8f7a9200f12593e41717e64156d8a81b78972345a3258c928c6ae4029d1307f",
+            "94",
+            "038ab61c561e795b42a9e189463114a9",
+        ),
+        (
+            "1111111111111111111111111111111111111111111111111111111111111111",
+            "108e8d1590f8a01b7c61940faa56371db6742b5de8c9a3e29b1e9f3eafac6e79",
+            "2222222222222222222222222222222222222222222222222222222222222222",
+            "72696e672d7369672d766f74696e67",
+            "363787233dd9edefbf2c3ed4e3c33caece7f91fe85ed7565d0b2ee2c8610770f",
+            "e976c271280417822e30cb088f5c70",
+            "e5092a6a07ee8cbf3aa698af9f49fd40",
+        ),
+    ];
+
+    for (private_key, public_key, ephemeral_secret, plaintext, ephemeral, ciphertext, tag) in
+        vectors
+    {
+        let private_key = array(private_key);
+        let plaintext = unhex(plaintext);
+        let auth_tag: [u8; 16] = unhex(tag).try_into().unwrap();
+
+        let sealed =
+            ecies::encrypt(&array(public_key), &array(ephemeral_secret), &plaintext).unwrap();
+        assert_eq!(sealed.ephemeral_public_key, array(ephemeral));
+        assert_eq!(sealed.ciphertext, unhex(ciphertext));
+        assert_eq!(sealed.auth_tag, auth_tag);
+
+        assert_eq!(
+            ecies::decrypt(&private_key, &array(ephemeral), &unhex(ciphertext), &auth_tag),
+            Some(plaintext)
+        );
+
+        // 篡改认证标签或使用错误的私钥都无法解密
+        let mut tampered = auth_tag;
+        tampered[0] ^= 1;
+        assert_eq!(
+            ecies::decrypt(&private_key, &array(ephemeral), &unhex(ciphertext), &tampered),
+            None
+        );
+        assert_eq!(
+            ecies::decrypt(&[3u8; 32], &array(ephemeral), &unhex(ciphertext), &auth_tag),
+            None
+        );
+    }
+
+    // 临时公钥为单位元时拒绝解密
+    assert_eq!(ecies::decrypt(&[1u8; 32], &[0u8; 32], &[0], &[0u8; 16]), None);
+}
+
+#[test]
+fn ballot_padding() {
+    use crate::ecies::{pad, unpad};
+
+    // 填充到固定长度，去掉填充后还原
+    assert_eq!(pad(&[1, 2], 5), Some(vec![1, 2, 0x80, 0, 0]));
+    assert_eq!(unpad(&[1, 2, 0x80, 0, 0]), Some(&[1, 2][..]));
+    assert_eq!(unpad(&pad(&[], 4).unwrap()), Some(&[][..]));
+    // 选票末尾的零字节和 0x80 不影响还原
+    assert_eq!(unpad(&pad(&[0x80, 0], 4).unwrap()), Some(&[0x80, 0][..]));
+
+    // 至少需要 1 字节填充
+    assert_eq!(pad(&[1, 2], 2), None);
+    // 缺少填充标记的数据被拒绝
+    assert_eq!(unpad(&[1, 2, 0, 0]), None);
+    assert_eq!(unpad(&[0, 0]), None);
+
+    // 不同长度的选票加密后密文长度相同
+    let public_key = (Scalar::from(7u8) * RISTRETTO_BASEPOINT_POINT).compress().to_bytes();
+    for ballot in [&[0u8][..], &[1u8; 20][..]] {
+        let sealed = crate::ecies::encrypt_ballot(&public_key, &[9u8; 32], ballot, 32).unwrap();
+        assert_eq!(sealed.ciphertext.len(), 32);
+        assert_eq!(
+            crate::ecies::decrypt_ballot(
+                &Scalar::from(7u8).to_bytes(),
+                &sealed.ephemeral_public_key,
+                &sealed.ciphertext,
+                &sealed.auth_tag,
+            ),
+            Some(ballot.to_vec())
+        );
+    }
+}
+
+#[test]
+fn migrates_v0_polls_and_ballots() {
+    use crate::migrations::{v0, v1};
+    use frame::deps::frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade};
+
+    let private_key_scalar = Scalar::random(&mut OsRng);
+    let encryption_privkey: H256 = private_key_scalar.to_bytes().into();
+    let encryption_pubkey = (private_key_scalar * RISTRETTO_BASEPOINT_POINT)
+        .compress()
+        .to_bytes();
+
+    let old_poll = |status, encryption_private_key| v0::Poll::<Test> {
+        creator: ALICE,
+        description: b"Poll 0".to_vec().try_into().unwrap(),
+        status,
+        submission_deposit: Deposit {
+            who: ALICE,
+            amount: 10,
+        },
+        deadline: Some(20),
+        encryption_public_key: Some(encryption_pubkey),
+        encryption_private_key,
+    };
+    let ballot = |tag: u8| EncryptedVote::<Test> {
+        ephemeral_public_key: [tag; 32],
+        ciphertext: vec![tag; 8].try_into().unwrap(),
+        auth_tag: [tag; 16],
+        ring_signature: CLSAGWrapper {
+            challenge: ScalarWrapper([0u8; 32]),
+            responses: Default::default(),
+            ring: Default::default(),
+            key_images: Default::default(),
+        },
+    };
+
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        StorageVersion::new(0).put::<RingSigVoting>();
+        v0::Polls::<Test, ()>::insert(0, old_poll(PollStatus::Voting, None));
+        v0::Polls::<Test, ()>::insert(1, old_poll(PollStatus::Closed, Some([2u8; 32])));
+        v0::EncryptedVotes::<Test, ()>::insert(
+            0,
+            BoundedVec::truncate_from(vec![ballot(3), ballot(4)]),
+        );
+        v0::EncryptedVotes::<Test, ()>::insert(1, BoundedVec::truncate_from(vec![ballot(5)]));
+        v0::Polls::<Test, ()>::insert(2, old_poll(PollStatus::Voting, None));
+        v0::EncryptedVotes::<Test, ()>::insert(2, BoundedVec::truncate_from(vec![ballot(6)]));
+        PollVotes::<Test>::insert(1, (1, 0));
+        PollCount::<Test>::put(3);
+
+        // 启用 try-runtime 时同时运行迁移前后的检查
+        #[cfg(feature = "try-runtime")]
+        assert_ok!(v1::MigrateV0ToV1::<Test>::try_on_runtime_upgrade(true));
+        #[cfg(not(feature = "try-runtime"))]
+        v1::MigrateV0ToV1::<Test>::on_runtime_upgrade();
+        assert_eq!(RingSigVoting::on_chain_storage_version(), 1);
+
+        // 截止区块保留为按区块号计时，配置为默认值
+        let poll = Polls::<Test>::get(0).unwrap();
+        assert_eq!(poll.status, PollStatus::Voting);
+        assert_eq!(poll.opens_at, None);
+        assert_eq!(poll.deadline, Some(PollMoment::Block(20)));
+        assert_eq!(poll.config, PollConfig::default());
+        assert_eq!(poll.submission_deposit.amount, 10);
+        let closed = Polls::<Test>::get(1).unwrap();
+        assert_eq!(closed.status, PollStatus::Closed);
+        assert_eq!(closed.encryption_private_key, Some([2u8; 32]));
+        assert_eq!(PollVotes::<Test>::get(1), (1, 0));
+
+        // 只有仍在进行的投票标记为旧投票；选票数已记录，选票本身留在旧布局中
+        assert!(LegacyPolls::<Test>::contains_key(0));
+        assert!(!LegacyPolls::<Test>::contains_key(1));
+        assert!(LegacyPolls::<Test>::contains_key(2));
+        assert_eq!(BallotCounts::<Test>::get(0), 2);
+        assert_eq!(BallotCounts::<Test>::get(1), 1);
+        assert_eq!(EncryptedVotes::<Test>::get(0, 0), None);
+        assert_eq!(v0::EncryptedVotes::<Test, ()>::get(0).len(), 2);
+
+        // 已迁移的存储不会再次迁移
+        v1::MigrateV0ToV1::<Test>::on_runtime_upgrade();
+        assert_eq!(Polls::<Test>::get(0).unwrap().deadline, poll.deadline);
+        assert_eq!(BallotCounts::<Test>::get(0), 2);
+
+        // 旧投票的选票无法在链上计票：不接受新的选票，也不能进入链上计票
+        assert_noop!(
+            RingSigVoting::anonymous_vote(
+                RuntimeOrigin::signed(BOB),
+                0,
+                H256::zero(),
+                Default::default(),
+                Default::default(),
+                H256::zero(),
+                Default::default(),
+                Default::default(),
+            ),
+            Error::<Test>::LegacyPoll
+        );
+        assert_noop!(
+            RingSigVoting::close_poll(RuntimeOrigin::root(), 0, Some(encryption_privkey)),
+            Error::<Test>::LegacyPoll
+        );
+
+        // 按旧的方式关闭：公开私钥并提交链下统计的计票结果，旧选票按原顺序编号
+        assert_noop!(
+            RingSigVoting::close_legacy_poll(
+                RuntimeOrigin::signed(ALICE),
+                0,
+                Some(encryption_privkey),
+                (1, 1)
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            RingSigVoting::close_legacy_poll(
+                RuntimeOrigin::root(),
+                0,
+                Some(H256::repeat_byte(9)),
+                (1, 1)
+            ),
+            Error::<Test>::InvalidPrivateKey
+        );
+        assert_ok!(RingSigVoting::close_legacy_poll(
+            RuntimeOrigin::root(),
+            0,
+            Some(encryption_privkey),
+            (1, 1)
+        ));
+        let poll = Polls::<Test>::get(0).unwrap();
+        assert_eq!(poll.status, PollStatus::Closed);
+        assert_eq!(poll.encryption_private_key, Some(encryption_privkey.0));
+        assert_eq!(PollVotes::<Test>::get(0), (1, 1));
+        assert_eq!(PollOutcomes::<Test>::get(0).unwrap().turnout, 2);
+        assert!(!LegacyPolls::<Test>::contains_key(0));
+        assert_eq!(EncryptedVotes::<Test>::get(0, 0), Some(ballot(3)));
+        assert_eq!(EncryptedVotes::<Test>::get(0, 1), Some(ballot(4)));
+        assert!(!v0::EncryptedVotes::<Test, ()>::contains_key(0));
+        assert_noop!(
+            RingSigVoting::close_legacy_poll(
+                RuntimeOrigin::root(),
+                0,
+                Some(encryption_privkey),
+                (1, 1)
+            ),
+            Error::<Test>::NotLegacyPoll
+        );
+
+        // 投票人数不足时与 `close_poll` 相同，不公开私钥和计票结果
+        MinBallotsForBreakdown::set(2);
+        assert_noop!(
+            RingSigVoting::close_legacy_poll(
+                RuntimeOrigin::root(),
+                2,
+                Some(encryption_privkey),
+                (1, 0)
+            ),
+            Error::<Test>::BreakdownWithheld
+        );
+        assert_ok!(RingSigVoting::close_legacy_poll(
+            RuntimeOrigin::root(),
+            2,
+            None,
+            (1, 0)
+        ));
+        let poll = Polls::<Test>::get(2).unwrap();
+        assert_eq!(poll.status, PollStatus::Closed);
+        assert_eq!(poll.encryption_private_key, None);
+        assert_eq!(PollVotes::<Test>::get(2), (0, 0));
+        assert!(PollOutcomes::<Test>::get(2).unwrap().breakdown_withheld);
+        assert_eq!(EncryptedVotes::<Test>::get(2, 0), Some(ballot(6)));
+        assert!(!LegacyPolls::<Test>::contains_key(2));
+        MinBallotsForBreakdown::set(0);
+
+        // 已关闭投票的旧选票由任何人逐个投票迁移
+        assert_noop!(
+            RingSigVoting::migrate_legacy_ballots(RuntimeOrigin::signed(BOB), 0),
+            Error::<Test>::NoLegacyBallots
+        );
+        assert_ok!(RingSigVoting::migrate_legacy_ballots(RuntimeOrigin::signed(BOB), 1));
+        assert_eq!(EncryptedVotes::<Test>::get(1, 0), Some(ballot(5)));
+        assert!(!v0::EncryptedVotes::<Test, ()>::contains_key(1));
+    });
+}
+
+#[test]
+fn upgrades_v0_evaluative_tallies() {
+    use crate::evaluative_voting::Tally;
+    use crate::migrations::v1::{EvaluativeTally, TallyUpgrade};
+
+    // 版本 0 的计票结果：每个问题为五个评分等级的计数
+    let encoded = vec![[0u32, 1, 2, 0, 0], [3, 0, 0, 0, 0]].encode();
+    let old = <EvaluativeTally as TallyUpgrade<Tally>>::Old::decode(&mut &encoded[..]).unwrap();
+
+    // 仍在进行的投票只转换计数
+    let open = EvaluativeTally::upgrade(old.clone(), false).unwrap();
+    assert_eq!(open[0].distribution(), [0, 1, 2, 0, 0]);
+    assert_eq!(open[1].responses(), 3);
+    assert!(open[0].summary().is_none());
+
+    // 已关闭投票同时得出统计量
+    let closed = EvaluativeTally::upgrade(old, true).unwrap();
+    assert_eq!(closed[0].distribution(), [0, 1, 2, 0, 0]);
+    assert_eq!(closed[0].summary().unwrap().responses, 3);
+    assert_eq!(closed[1].summary().unwrap().responses, 3);
+}
+
 // #[test]
 // fn register_ring_group() {
-//     let ring = gen_ring::<Test>();
+//     let ring = gen_ring::<Test, ()>();
 //
 //     new_test_ext().execute_with(|| {
 //         System::set_block_number(1);
@@ -334,7 +3030,7 @@ fn cannot_vote_on_closed_poll() {
 //     let poll_id = 0;
 //     let description = b"Poll 0".to_vec();
 //     let ring_id = 0;
-//     let ring = gen_ring::<Test>();
+//     let ring = gen_ring::<Test, ()>();
 //
 //     new_test_ext().execute_with(|| {
 //         System::set_block_number(1);
@@ -363,7 +3059,7 @@ fn cannot_vote_on_closed_poll() {
 //     let poll_id = 0;
 //     let description = b"Poll 0".to_vec();
 //     let ring_id = 0;
-//     let ring = gen_ring::<Test>();
+//     let ring = gen_ring::<Test, ()>();
 //
 //     new_test_ext().execute_with(|| {
 //         System::set_block_number(1);
@@ -398,7 +3094,7 @@ fn cannot_vote_on_closed_poll() {
 //     let poll_id = 0;
 //     let description = b"Poll 0".to_vec();
 //     let ring_id = 0;
-//     let (challenge, responses, ring, key_images) = gen_signature::<Test>(poll_id, vote.clone());
+//     let (challenge, responses, ring, key_images) = gen_signature::<Test, ()>(poll_id, vote.clone());
 //
 //     new_test_ext().execute_with(|| {
 //         System::set_block_number(1);
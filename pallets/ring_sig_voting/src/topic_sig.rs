@@ -0,0 +1,188 @@
+//! 话题范围的可链接环签名，用于匿名留言板
+//!
+//! 投票使用的 CLSAG 密钥镜像为 `x·Hp(P)`，与消息无关，同一成员在所有话题中的密钥镜像相同。
+//! 留言需要同一话题内可链接、不同话题间不可链接，因此密钥镜像的基点由话题派生：
+//!
+//! - 基点：`H_i = Hp(DOMAIN ‖ len(topic) ‖ topic ‖ P_i)`，`Hp` 为 SHA-512 哈希到 Ristretto 点
+//! - 密钥镜像：`I = x·H_π`，同一私钥在同一话题中总是得到相同的 `I`
+//! - 签名：单层 LSAG，`c_{i+1} = Hs(DOMAIN ‖ len(topic) ‖ topic ‖ ring ‖ I ‖ len(m) ‖ m ‖ L_i ‖ R_i)`，
+//!   其中 `L_i = s_i·G + c_i·P_i`，`R_i = s_i·H_i + c_i·I`，`Hs` 为 SHA-512 归约到标量
+//! - 签名由 `c_0`、每个环成员的响应 `s_i` 和密钥镜像组成，均为 32 字节
+//!
+//! 环使用 `RingGroups` 中每个成员的第一个公钥。
+
+use curve25519_dalek::{
+    constants::RISTRETTO_BASEPOINT_POINT,
+    ristretto::{CompressedRistretto, RistrettoPoint},
+    scalar::Scalar,
+    traits::IsIdentity,
+};
+use rand_core::{CryptoRng, RngCore};
+use scale_info::prelude::{vec, vec::Vec};
+use sha2::{Digest, Sha512};
+
+/// 哈希的域分隔标签
+pub const DOMAIN: &[u8] = b"ring-sig-voting/topic-sig/v1";
+
+/// 话题签名
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TopicSignature {
+    pub challenge: [u8; 32],
+    pub responses: Vec<[u8; 32]>,
+    pub key_image: [u8; 32],
+}
+
+/// 私钥在给定话题中的密钥镜像，私钥为零时返回 `None`
+pub fn key_image(secret: &[u8; 32], topic: &[u8]) -> Option<[u8; 32]> {
+    let secret = Scalar::from_bytes_mod_order(*secret);
+    if secret == Scalar::ZERO {
+        return None;
+    }
+    let public_key = (secret * RISTRETTO_BASEPOINT_POINT).compress();
+    Some(
+        (secret * key_image_base(topic, &public_key))
+            .compress()
+            .to_bytes(),
+    )
+}
+
+/// 以环中第 `secret_index` 个成员的身份签名，私钥与该位置的公钥不符时返回 `None`
+pub fn sign<R: RngCore + CryptoRng>(
+    rng: &mut R,
+    ring: &[[u8; 32]],
+    secret_index: usize,
+    secret: &[u8; 32],
+    topic: &[u8],
+    message: &[u8],
+) -> Option<TopicSignature> {
+    let n = ring.len();
+    let secret = Scalar::from_bytes_mod_order(*secret);
+    let public_keys = decompress_ring(ring)?;
+    if secret == Scalar::ZERO
+        || public_keys.get(secret_index)? != &(secret * RISTRETTO_BASEPOINT_POINT)
+    {
+        return None;
+    }
+
+    let bases: Vec<RistrettoPoint> = ring
+        .iter()
+        .map(|pk| key_image_base(topic, &CompressedRistretto(*pk)))
+        .collect();
+    let key_image = secret * bases[secret_index];
+    let key_image_bytes = key_image.compress().to_bytes();
+    let transcript = transcript(ring, topic, message, &key_image_bytes);
+
+    let mut challenges = vec![Scalar::ZERO; n];
+    let mut responses = vec![Scalar::ZERO; n];
+
+    let alpha = random_scalar(rng);
+    let mut c = round(
+        &transcript,
+        alpha * RISTRETTO_BASEPOINT_POINT,
+        alpha * bases[secret_index],
+    );
+    for offset in 1..n {
+        let i = (secret_index + offset) % n;
+        challenges[i] = c;
+        responses[i] = random_scalar(rng);
+        c = round(
+            &transcript,
+            responses[i] * RISTRETTO_BASEPOINT_POINT + c * public_keys[i],
+            responses[i] * bases[i] + c * key_image,
+        );
+    }
+    challenges[secret_index] = c;
+    responses[secret_index] = alpha - c * secret;
+
+    Some(TopicSignature {
+        challenge: challenges[0].to_bytes(),
+        responses: responses.iter().map(Scalar::to_bytes).collect(),
+        key_image: key_image_bytes,
+    })
+}
+
+/// 验证话题签名，环为空、长度不符或任一编码无效时返回 `false`
+pub fn verify(ring: &[[u8; 32]], topic: &[u8], message: &[u8], signature: &TopicSignature) -> bool {
+    if ring.is_empty() || signature.responses.len() != ring.len() {
+        return false;
+    }
+    let Some(public_keys) = decompress_ring(ring) else {
+        return false;
+    };
+    let Some(key_image) = decompress(&signature.key_image) else {
+        return false;
+    };
+    let Some(challenge) = canonical_scalar(&signature.challenge) else {
+        return false;
+    };
+
+    let transcript = transcript(ring, topic, message, &signature.key_image);
+    let mut c = challenge;
+    for ((pk, public_key), response) in ring.iter().zip(&public_keys).zip(&signature.responses) {
+        let Some(s) = canonical_scalar(response) else {
+            return false;
+        };
+        let base = key_image_base(topic, &CompressedRistretto(*pk));
+        c = round(
+            &transcript,
+            RistrettoPoint::vartime_double_scalar_mul_basepoint(&c, public_key, &s),
+            s * base + c * key_image,
+        );
+    }
+
+    c == challenge
+}
+
+/// 密钥镜像的基点，由话题和公钥派生
+fn key_image_base(topic: &[u8], public_key: &CompressedRistretto) -> RistrettoPoint {
+    let hash = Sha512::new()
+        .chain_update(DOMAIN)
+        .chain_update((topic.len() as u64).to_le_bytes())
+        .chain_update(topic)
+        .chain_update(public_key.as_bytes());
+    RistrettoPoint::from_hash(hash)
+}
+
+/// 每一轮挑战共用的哈希前缀
+fn transcript(ring: &[[u8; 32]], topic: &[u8], message: &[u8], key_image: &[u8; 32]) -> Sha512 {
+    let mut hash = Sha512::new()
+        .chain_update(DOMAIN)
+        .chain_update((topic.len() as u64).to_le_bytes())
+        .chain_update(topic);
+    for pk in ring {
+        hash.update(pk);
+    }
+    hash.chain_update(key_image)
+        .chain_update((message.len() as u64).to_le_bytes())
+        .chain_update(message)
+}
+
+/// 由本轮的两个承诺计算下一轮的挑战
+fn round(transcript: &Sha512, l: RistrettoPoint, r: RistrettoPoint) -> Scalar {
+    let hash = transcript
+        .clone()
+        .chain_update(l.compress().as_bytes())
+        .chain_update(r.compress().as_bytes());
+    Scalar::from_hash(hash)
+}
+
+fn random_scalar<R: RngCore + CryptoRng>(rng: &mut R) -> Scalar {
+    let mut bytes = [0u8; 64];
+    rng.fill_bytes(&mut bytes);
+    Scalar::from_bytes_mod_order_wide(&bytes)
+}
+
+fn canonical_scalar(bytes: &[u8; 32]) -> Option<Scalar> {
+    Scalar::from_canonical_bytes(*bytes).into()
+}
+
+fn decompress_ring(ring: &[[u8; 32]]) -> Option<Vec<RistrettoPoint>> {
+    ring.iter().map(decompress).collect()
+}
+
+/// 解码压缩点，拒绝无效编码和单位元
+fn decompress(bytes: &[u8; 32]) -> Option<RistrettoPoint> {
+    CompressedRistretto(*bytes)
+        .decompress()
+        .filter(|point| !point.is_identity())
+}
@@ -3,15 +3,29 @@ use curve25519_dalek::{
     ristretto::{CompressedRistretto, RistrettoPoint},
     scalar::Scalar,
 };
-use frame::deps::frame_support::traits::Currency;
+use frame::deps::frame_support::traits::{Bounded, Currency, OriginTrait, Time};
+use frame::deps::sp_runtime::{ArithmeticError, FixedPointNumber, FixedU128, Perbill};
 use frame::prelude::*;
 use nazgul::clsag::CLSAG;
 
 /// 货币余额
-pub type BalanceOf<T> =
-    <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+pub type BalanceOf<T, I = ()> =
+    <<T as Config<I>>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 /// 押金对象
-pub type DepositOf<T> = Deposit<<T as frame_system::Config>::AccountId, BalanceOf<T>>;
+pub type DepositOf<T, I = ()> = Deposit<<T as frame_system::Config>::AccountId, BalanceOf<T, I>>;
+/// 投票通过后可执行的调用
+pub type CallOf<T> = <T as frame_system::Config>::RuntimeCall;
+/// 内联或以 Preimage 哈希引用的调用
+pub type BoundedCallOf<T> = Bounded<CallOf<T>, <T as frame_system::Config>::Hashing>;
+/// 调度器使用的 origin
+pub type PalletsOriginOf<T> =
+    <<T as frame_system::Config>::RuntimeOrigin as OriginTrait>::PalletsOrigin;
+/// 链上时间戳
+pub type MomentOf<T, I = ()> = <<T as Config<I>>::Time as Time>::Moment;
+/// 投票的开放或截止时间点
+pub type PollMomentOf<T, I = ()> = PollMoment<BlockNumberFor<T>, MomentOf<T, I>>;
+/// 匿名留言板的话题
+pub type TopicOf<T> = Topic<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>;
 
 // RistrettoPoint (公钥) 包装器
 // RistrettoPoint 压缩后是 32 字节
@@ -74,8 +88,8 @@ impl From<H256> for ScalarWrapper {
     DecodeWithMemTracking,
     MaxEncodedLen,
 )]
-#[scale_info(skip_type_params(T))]
-pub struct CLSAGWrapper<T: Config> {
+#[scale_info(skip_type_params(T, I))]
+pub struct CLSAGWrapper<T: Config<I>, I: 'static = ()> {
     /// This is the challenge generated non-interactievely
     pub challenge: ScalarWrapper,
     /// These responses are mostly fake, except one which is real.
@@ -89,8 +103,8 @@ pub struct CLSAGWrapper<T: Config> {
     pub key_images: BoundedVec<CompressedRistrettoWrapper, T::NumRingLayers>,
 }
 
-impl<T: Config> From<CLSAGWrapper<T>> for CLSAG {
-    fn from(wrapper: CLSAGWrapper<T>) -> Self {
+impl<T: Config<I>, I: 'static> From<CLSAGWrapper<T, I>> for CLSAG {
+    fn from(wrapper: CLSAGWrapper<T, I>) -> Self {
         CLSAG {
             challenge: wrapper.challenge.into(),
             responses: wrapper.responses.into_iter().map(|r| r.into()).collect(),
@@ -137,14 +151,272 @@ pub enum PollStatus {
     Voting,
     /// 已关闭
     Closed,
+    /// 已公开私钥，正在链上分批计票
+    Tallying,
+}
+
+/// 投票的开放或截止时间点。
+/// 平行链出块时间不固定时，按时间戳计时比按区块号更准确。
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Encode,
+    Decode,
+    TypeInfo,
+    PartialEq,
+    Eq,
+    MaxEncodedLen,
+    DecodeWithMemTracking,
+)]
+pub enum PollMoment<BlockNumber, Moment> {
+    /// 按区块号计时
+    Block(BlockNumber),
+    /// 按 `pallet_timestamp` 的时间戳计时（毫秒）
+    Timestamp(Moment),
+}
+
+impl<BlockNumber: PartialOrd, Moment: PartialOrd> PollMoment<BlockNumber, Moment> {
+    /// 是否早于另一个时间点。两者计时方式不同时无法比较，返回 `None`
+    pub fn is_before(&self, other: &Self) -> Option<bool> {
+        match (self, other) {
+            (Self::Block(a), Self::Block(b)) => Some(a < b),
+            (Self::Timestamp(a), Self::Timestamp(b)) => Some(a < b),
+            _ => None,
+        }
+    }
+}
+
+/// 创建投票时指定的可选配置
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    Encode,
+    Decode,
+    TypeInfo,
+    PartialEq,
+    Eq,
+    MaxEncodedLen,
+    DecodeWithMemTracking,
+)]
+pub struct PollConfig {
+    /// 是否允许重新投票：相同 `key_images[0]` 的新选票替换旧选票，计票时只计最后一张。
+    /// 被胁迫投票的人可以事后悄悄覆盖自己的选票。
+    pub ballot_replacement: bool,
+    /// 重新投票时是否记录修订次数并发出 `EncryptedVoteReplaced` 事件。
+    /// 默认关闭：胁迫者知道被胁迫选票的密钥镜像，公开的替换事件会暴露覆盖行为
+    pub announce_revisions: bool,
+    /// 法定人数：投票人数占环成员数的最低比例，`None` 表示不要求
+    pub quorum: Option<Perbill>,
+    /// 通过门槛，`None` 表示该投票只统计结果、不判定是否通过
+    pub threshold: Option<ApprovalThreshold>,
+    /// 问卷结构。问题文本等说明放在元数据 Preimage 中，链上只保存用于校验计票的结构
+    pub schema: Option<BallotSchema>,
+    /// 参与式预算的项目资金和上限
+    pub budget: Option<BudgetConfig>,
+    /// 多对象评价中被评价的对象（教师或课程），名称等说明放在元数据 Preimage 中
+    pub subjects: BoundedVec<SubjectId, ConstU32<MAX_SUBJECTS>>,
+    /// 选票密文的固定长度（含填充），`None` 表示使用 `MaxVoteSize`。
+    /// 同一投票的所有密文长度相同，避免密文长度泄露选项
+    pub ballot_length: Option<u32>,
+}
+
+impl PollConfig {
+    /// 问卷只有一道单选题时返回其选项数，排序类投票以此作为候选人数量
+    pub fn candidates(&self) -> Option<u32> {
+        match self.schema.as_ref()?.questions.as_slice() {
+            [Question::Choice { options }] => Some(*options),
+            _ => None,
+        }
+    }
+}
+
+/// 被评价对象的 ID
+pub type SubjectId = u32;
+/// 一次评价最多包含的对象数
+pub const MAX_SUBJECTS: u32 = 8;
+
+/// 参与式预算配置
+#[derive(
+    Clone, Debug, Encode, Decode, TypeInfo, PartialEq, Eq, MaxEncodedLen, DecodeWithMemTracking,
+)]
+pub struct BudgetConfig {
+    /// 每张选票必须分配的总点数
+    pub allocation: u32,
+    /// 每个项目所需的资金
+    pub costs: BoundedVec<u64, ConstU32<MAX_CHOICE_OPTIONS>>,
+    /// 资金上限
+    pub cap: u64,
+}
+
+/// 问卷最多包含的问题数
+pub const MAX_SCHEMA_QUESTIONS: u32 = 16;
+/// 单选题最多包含的选项数
+pub const MAX_CHOICE_OPTIONS: u32 = 16;
+
+/// 问卷中的一个问题
+#[derive(
+    Clone, Debug, Encode, Decode, TypeInfo, PartialEq, Eq, MaxEncodedLen, DecodeWithMemTracking,
+)]
+pub enum Question {
+    /// 单选题，答案为选项编号 `0..options`
+    Choice { options: u32 },
+    /// 数值题，答案为 `min..=max` 之间的整数
+    Range { min: u32, max: u32 },
+}
+
+impl Question {
+    /// 问题本身是否有效：单选题至少两个选项，数值题范围非空
+    pub fn is_valid(&self) -> bool {
+        match self {
+            Self::Choice { options } => (2..=MAX_CHOICE_OPTIONS).contains(options),
+            Self::Range { min, max } => min <= max,
+        }
+    }
+
+    /// 答案是否在该问题允许的范围内
+    pub fn accepts(&self, answer: u32) -> bool {
+        match self {
+            Self::Choice { options } => answer < *options,
+            Self::Range { min, max } => (*min..=*max).contains(&answer),
+        }
+    }
+}
+
+/// 一个投票的问卷结构，每张选票按顺序回答所有问题
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    Encode,
+    Decode,
+    TypeInfo,
+    PartialEq,
+    Eq,
+    MaxEncodedLen,
+    DecodeWithMemTracking,
+)]
+pub struct BallotSchema {
+    pub questions: BoundedVec<Question, ConstU32<MAX_SCHEMA_QUESTIONS>>,
+}
+
+impl BallotSchema {
+    /// 问卷至少包含一个问题，且每个问题都有效
+    pub fn is_valid(&self) -> bool {
+        !self.questions.is_empty() && self.questions.iter().all(Question::is_valid)
+    }
+}
+
+/// 判定投票通过所需的赞成票比例
+#[derive(
+    Clone, Debug, Encode, Decode, TypeInfo, PartialEq, Eq, MaxEncodedLen, DecodeWithMemTracking,
+)]
+pub enum ApprovalThreshold {
+    /// 简单多数：赞成票多于反对票
+    SimpleMajority,
+    /// 绝对多数：赞成票不少于三分之二
+    SuperMajority,
+    /// 赞成票占比不低于给定比例
+    AtLeast(Perbill),
+    /// 全票通过：没有反对票
+    Unanimous,
+}
+
+impl ApprovalThreshold {
+    /// 给定赞成票和反对票数，判断是否达到门槛。没有任何赞成或反对票时不通过
+    pub fn is_met(&self, ayes: u32, nays: u32) -> bool {
+        let total = ayes.saturating_add(nays);
+        if total == 0 {
+            return false;
+        }
+        match self {
+            Self::SimpleMajority => ayes > nays,
+            Self::SuperMajority => (ayes as u64) * 3 >= (total as u64) * 2,
+            Self::AtLeast(ratio) => ayes >= ratio.mul_ceil(total),
+            Self::Unanimous => nays == 0,
+        }
+    }
+}
+
+/// 投票关闭时判定的结果
+#[derive(
+    Clone,
+    Debug,
+    Encode,
+    Decode,
+    TypeInfo,
+    PartialEq,
+    Eq,
+    MaxEncodedLen,
+    DecodeWithMemTracking,
+    Default,
+)]
+pub struct Outcome {
+    /// 投票人数
+    pub turnout: u32,
+    /// 有投票资格的人数（环成员数）
+    pub electorate: u32,
+    /// 是否达到法定人数
+    pub quorum_met: bool,
+    /// 是否通过（需同时达到法定人数和通过门槛）。未设置通过门槛时为 `None`
+    pub passed: Option<bool>,
+    /// 投票人数不足，未公开计票结果和解密私钥
+    pub breakdown_withheld: bool,
+    /// 计票时判为无效、未计入计票结果的选票数
+    pub spoiled: u32,
+}
+
+/// 允许重新投票时，一个密钥镜像对应的选票
+#[derive(
+    Clone, Debug, Encode, Decode, TypeInfo, PartialEq, Eq, MaxEncodedLen, DecodeWithMemTracking,
+)]
+pub struct BallotSlot {
+    /// 选票在 `EncryptedVotes` 中的位置
+    pub index: u32,
+    /// 已被替换的次数，仅在投票开启 `announce_revisions` 时记录
+    pub revision: u32,
+}
+
+/// 选票在计票时被判为无效的原因
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Encode,
+    Decode,
+    TypeInfo,
+    PartialEq,
+    Eq,
+    MaxEncodedLen,
+    DecodeWithMemTracking,
+)]
+pub enum SpoilReason {
+    /// 解密失败（认证标签不匹配）
+    Undecryptable,
+    /// 明文无法解码为选票
+    Undecodable,
+    /// 选票不符合投票配置，被计票逻辑拒绝
+    Rejected,
+}
+
+/// 计票时被判为无效的选票
+#[derive(
+    Clone, Debug, Encode, Decode, TypeInfo, PartialEq, Eq, MaxEncodedLen, DecodeWithMemTracking,
+)]
+pub struct SpoiledBallot {
+    /// 选票在 `EncryptedVotes` 中的位置
+    pub index: u32,
+    /// 无效原因
+    pub reason: SpoilReason,
 }
 
 /// 投票（Poll）的详细信息
 #[derive(
     Clone, Debug, Encode, Decode, TypeInfo, PartialEq, Eq, MaxEncodedLen, DecodeWithMemTracking,
 )]
-#[scale_info(skip_type_params(T))]
-pub struct Poll<T: Config> {
+#[scale_info(skip_type_params(T, I))]
+pub struct Poll<T: Config<I>, I: 'static = ()> {
     /// 创建者
     pub creator: T::AccountId,
     /// 描述
@@ -152,13 +424,84 @@ pub struct Poll<T: Config> {
     /// 当前状态
     pub status: PollStatus,
     /// 创建者支付的押金
-    pub submission_deposit: DepositOf<T>,
-    /// 投票截止区块号
-    pub deadline: Option<BlockNumberFor<T>>,
+    pub submission_deposit: DepositOf<T, I>,
+    /// 投票开放时间，`None` 表示创建后立即开放
+    pub opens_at: Option<PollMomentOf<T, I>>,
+    /// 投票截止时间（区块号或时间戳）
+    pub deadline: Option<PollMomentOf<T, I>>,
     /// 用于投票加密的一次性公钥
     pub encryption_public_key: Option<[u8; 32]>,
     /// 用于揭示的私钥（仅在关闭后公开）
     pub encryption_private_key: Option<[u8; 32]>,
+    /// 投票配置
+    pub config: PollConfig,
+}
+
+/// 匿名请愿：不收集选票，只统计不同环成员的签名数
+#[derive(
+    Clone, Debug, Encode, Decode, TypeInfo, PartialEq, Eq, MaxEncodedLen, DecodeWithMemTracking,
+)]
+#[scale_info(skip_type_params(T, I))]
+pub struct Petition<T: Config<I>, I: 'static = ()> {
+    /// 创建者
+    pub creator: T::AccountId,
+    /// 描述
+    pub description: BoundedVec<u8, T::MaxDescriptionLength>,
+    /// 可以签名的公钥环
+    pub ring_id: RingId,
+    /// 触发所需的签名数
+    pub threshold: u32,
+    /// 已收到的签名数
+    pub signatures: u32,
+    /// 达到门槛的区块，尚未达到时为 `None`
+    pub reached_at: Option<BlockNumberFor<T>>,
+}
+
+/// 举报或回执的密文，加密方式见 `ecies`
+#[derive(
+    CloneNoBound,
+    DebugNoBound,
+    PartialEqNoBound,
+    EqNoBound,
+    Encode,
+    Decode,
+    TypeInfo,
+    MaxEncodedLen,
+    DecodeWithMemTracking,
+)]
+#[scale_info(skip_type_params(T, I))]
+pub struct EncryptedMessage<T: Config<I>, I: 'static = ()> {
+    pub ephemeral_public_key: [u8; 32],
+    pub ciphertext: BoundedVec<u8, T::MaxReportSize>,
+    pub auth_tag: [u8; 16],
+}
+
+/// 尚未回执的匿名举报
+#[derive(
+    CloneNoBound,
+    DebugNoBound,
+    PartialEqNoBound,
+    EqNoBound,
+    Encode,
+    Decode,
+    TypeInfo,
+    MaxEncodedLen,
+    DecodeWithMemTracking,
+)]
+#[scale_info(skip_type_params(T, I))]
+pub struct Report<T: Config<I>, I: 'static = ()> {
+    /// 举报人所在的公钥环
+    pub ring_id: RingId,
+    /// 举报人在 `report_scope` 下的密钥镜像，回执以此标识举报人
+    pub key_image: CompressedRistrettoWrapper,
+    /// 举报加密时使用的审查委员会公钥
+    pub committee_key: [u8; 32],
+    /// 加密给审查委员会的举报内容
+    pub content: EncryptedMessage<T, I>,
+    /// 举报人的一次性公钥，审查委员会用它加密回执
+    pub reply_public_key: [u8; 32],
+    /// 提交举报的区块
+    pub submitted_at: BlockNumberFor<T>,
 }
 
 /// 单个加密投票的结构
@@ -173,8 +516,8 @@ pub struct Poll<T: Config> {
     MaxEncodedLen,
     DecodeWithMemTracking,
 )]
-#[scale_info(skip_type_params(T))]
-pub struct EncryptedVote<T: Config> {
+#[scale_info(skip_type_params(T, I))]
+pub struct EncryptedVote<T: Config<I>, I: 'static = ()> {
     /// R: 临时公钥点 (用于 ECDH)
     pub ephemeral_public_key: [u8; 32],
     /// Cipher: 加密的投票内容
@@ -182,15 +525,77 @@ pub struct EncryptedVote<T: Config> {
     /// Tag: 认证标签 (MAC)
     pub auth_tag: [u8; 16],
     /// 环签名数据（证明投票者身份）
-    pub ring_signature: CLSAGWrapper<T>,
+    pub ring_signature: CLSAGWrapper<T, I>,
 }
 
 /// 用于存储的合格投票者（成员）的公钥环矩阵
-pub type RingMatrix<T> = BoundedVec<
-    BoundedVec<CompressedRistrettoWrapper, <T as Config>::NumRingLayers>,
-    <T as Config>::MaxMembersInRing,
+pub type RingMatrix<T, I = ()> = BoundedVec<
+    BoundedVec<CompressedRistrettoWrapper, <T as Config<I>>::NumRingLayers>,
+    <T as Config<I>>::MaxMembersInRing,
 >;
 
+/// 留言话题的发帖频率限制：每个密钥镜像在每 `period` 个区块内最多发 `posts` 条留言
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Encode,
+    Decode,
+    TypeInfo,
+    PartialEq,
+    Eq,
+    MaxEncodedLen,
+    DecodeWithMemTracking,
+)]
+pub struct RateLimit<BlockNumber> {
+    pub posts: u32,
+    pub period: BlockNumber,
+}
+
+/// 匿名留言板的话题
+#[derive(
+    Clone, Debug, Encode, Decode, TypeInfo, PartialEq, Eq, MaxEncodedLen, DecodeWithMemTracking,
+)]
+pub struct Topic<AccountId, BlockNumber> {
+    /// 创建者
+    pub creator: AccountId,
+    /// 可以留言的公钥环
+    pub ring_id: RingId,
+    /// 发帖频率限制，`None` 表示不限制
+    pub rate_limit: Option<RateLimit<BlockNumber>>,
+    /// 已收到的留言数
+    pub posts: u32,
+}
+
+/// 一个密钥镜像在当前限流周期内的发帖情况
+#[derive(
+    Clone, Debug, Encode, Decode, TypeInfo, PartialEq, Eq, MaxEncodedLen, DecodeWithMemTracking,
+)]
+pub struct PostWindow<BlockNumber> {
+    /// 当前周期开始的区块
+    pub started: BlockNumber,
+    /// 当前周期内已发的留言数
+    pub posts: u32,
+}
+
+/// 留言内容：直接上链的短消息，或以 Preimage 哈希引用的长消息
+#[derive(
+    CloneNoBound,
+    DebugNoBound,
+    PartialEqNoBound,
+    EqNoBound,
+    Encode,
+    Decode,
+    TypeInfo,
+    MaxEncodedLen,
+    DecodeWithMemTracking,
+)]
+#[scale_info(skip_type_params(T, I))]
+pub enum MessageContent<T: Config<I>, I: 'static = ()> {
+    Text(BoundedVec<u8, T::MaxMessageLength>),
+    Preimage(T::Hash),
+}
+
 // 简单的赞成/反对投票实现
 pub mod simple_voting {
     use super::*;
@@ -218,13 +623,43 @@ pub mod simple_voting {
     // 定义计票逻辑
     pub struct TallyHandler;
     impl TallyLogic<Vote, Tally> for TallyHandler {
-        fn update_tally(vote: &Vote, tally: &mut Tally) -> DispatchResult {
-            match vote {
-                Vote::Yea => tally.0 += 1,
-                Vote::Nay => tally.1 += 1,
-            }
+        fn update_tally(_config: &PollConfig, vote: &Vote, tally: &mut Tally) -> DispatchResult {
+            let count = match vote {
+                Vote::Yea => &mut tally.0,
+                Vote::Nay => &mut tally.1,
+            };
+            *count = count.checked_add(1).ok_or(ArithmeticError::Overflow)?;
+            Ok(())
+        }
+
+        fn merge(_config: &PollConfig, tally: &mut Tally, other: &Tally) -> DispatchResult {
+            *tally = (
+                tally
+                    .0
+                    .checked_add(other.0)
+                    .ok_or(ArithmeticError::Overflow)?,
+                tally
+                    .1
+                    .checked_add(other.1)
+                    .ok_or(ArithmeticError::Overflow)?,
+            );
             Ok(())
         }
+
+        fn decides_outcome() -> bool {
+            true
+        }
+
+        fn finalize(config: &PollConfig, tally: &mut Tally) -> Result<Outcome, DispatchError> {
+            let (ayes, nays) = *tally;
+            Ok(Outcome {
+                passed: config
+                    .threshold
+                    .as_ref()
+                    .map(|threshold| threshold.is_met(ayes, nays)),
+                ..Default::default()
+            })
+        }
     }
 }
 
@@ -255,8 +690,55 @@ pub mod evaluative_voting {
         Five,
     }
 
+    impl Score {
+        /// 从低到高的全部评分等级
+        pub const ALL: [Score; 5] = [
+            Score::One,
+            Score::Two,
+            Score::Three,
+            Score::Four,
+            Score::Five,
+        ];
+
+        /// 评分对应的分值
+        pub fn value(&self) -> u32 {
+            match self {
+                Score::One => 1,
+                Score::Two => 2,
+                Score::Three => 3,
+                Score::Four => 4,
+                Score::Five => 5,
+            }
+        }
+    }
+
     pub type Vote = BoundedVec<Score, ConstU32<MAX_QUESTIONS>>;
 
+    /// 关闭投票时为每个问题计算的统计量
+    #[derive(
+        Encode,
+        Decode,
+        Clone,
+        PartialEq,
+        Eq,
+        RuntimeDebug,
+        TypeInfo,
+        MaxEncodedLen,
+        DecodeWithMemTracking,
+    )]
+    pub struct QuestionSummary {
+        /// 作答人数
+        pub responses: u32,
+        /// 平均分
+        pub mean: FixedU128,
+        /// 中位数（作答人数为偶数时取较低的一个）
+        pub median: Score,
+        /// 众数（人数相同时取较低的评分）
+        pub mode: Score,
+        /// 总体标准差
+        pub std_dev: FixedU128,
+    }
+
     /// 一个问题的五个评分等级的计数
     #[derive(
         Encode,
@@ -276,13 +758,114 @@ pub mod evaluative_voting {
         score_3: u32,
         score_4: u32,
         score_5: u32,
+        summary: Option<QuestionSummary>,
+    }
+
+    impl QuestionStats {
+        /// 由从 1 分到 5 分每个评分的人数创建统计，统计量在关闭投票时计算
+        pub fn from_distribution(distribution: [u32; 5]) -> Self {
+            let [score_1, score_2, score_3, score_4, score_5] = distribution;
+            Self {
+                score_1,
+                score_2,
+                score_3,
+                score_4,
+                score_5,
+                summary: None,
+            }
+        }
+
+        /// 给出某个评分的人数
+        pub fn count(&self, score: &Score) -> u32 {
+            match score {
+                Score::One => self.score_1,
+                Score::Two => self.score_2,
+                Score::Three => self.score_3,
+                Score::Four => self.score_4,
+                Score::Five => self.score_5,
+            }
+        }
+
+        /// 从 1 分到 5 分每个评分的人数
+        pub fn distribution(&self) -> [u32; 5] {
+            Score::ALL.map(|score| self.count(&score))
+        }
+
+        /// 作答人数
+        pub fn responses(&self) -> u32 {
+            self.distribution()
+                .iter()
+                .fold(0u32, |total, count| total.saturating_add(*count))
+        }
+
+        /// 关闭投票时计算的统计量，无人作答时为 `None`
+        pub fn summary(&self) -> Option<&QuestionSummary> {
+            self.summary.as_ref()
+        }
+
+        fn count_mut(&mut self, score: &Score) -> &mut u32 {
+            match score {
+                Score::One => &mut self.score_1,
+                Score::Two => &mut self.score_2,
+                Score::Three => &mut self.score_3,
+                Score::Four => &mut self.score_4,
+                Score::Five => &mut self.score_5,
+            }
+        }
+
+        fn summarize(&self) -> Option<QuestionSummary> {
+            let n = self
+                .distribution()
+                .iter()
+                .map(|count| *count as u128)
+                .sum::<u128>();
+            if n == 0 {
+                return None;
+            }
+
+            let (sum, sum_of_squares) = Score::ALL.iter().fold((0u128, 0u128), |(s, sq), score| {
+                let value = score.value() as u128;
+                let count = self.count(score) as u128;
+                (s + value * count, sq + value * value * count)
+            });
+            let mean = FixedU128::checked_from_rational(sum, n)?;
+            // 方差 = (n·Σx² - (Σx)²) / n²
+            let variance = FixedU128::checked_from_rational(n * sum_of_squares - sum * sum, n * n)?;
+
+            // 累计人数首次达到一半时的评分
+            let half = n.div_ceil(2);
+            let mut cumulative = 0u128;
+            let median = Score::ALL.into_iter().find(|score| {
+                cumulative += self.count(score) as u128;
+                cumulative >= half
+            })?;
+            let mode = Score::ALL
+                .into_iter()
+                .rev()
+                .max_by_key(|score| self.count(score))?;
+
+            Some(QuestionSummary {
+                responses: n as u32,
+                mean,
+                median,
+                mode,
+                std_dev: variance.try_sqrt()?,
+            })
+        }
     }
 
     pub type Tally = BoundedVec<QuestionStats, ConstU32<MAX_QUESTIONS>>;
 
     pub struct TallyHandler;
     impl TallyLogic<Vote, Tally> for TallyHandler {
-        fn update_tally(vote: &Vote, tally: &mut Tally) -> DispatchResult {
+        fn validate(_config: &PollConfig, vote: &Vote) -> DispatchResult {
+            ensure!(!vote.is_empty(), "Empty ballot");
+            Ok(())
+        }
+
+        fn update_tally(config: &PollConfig, vote: &Vote, tally: &mut Tally) -> DispatchResult {
+            Self::validate(config, vote)?;
+
             // 如果这是此 Poll 的第一张票，tally (BoundedVec) 是空的。
             // 我们需要根据 vote 的长度来初始化它。
             if tally.is_empty() {
@@ -298,19 +881,1444 @@ pub mod evaluative_voting {
                 "Vote and Tally dimensions mismatch"
             );
 
-            for (i, score) in vote.iter().enumerate() {
-                let stats = &mut tally
-                    .get_mut(i)
-                    .expect("Index within bounds due to previous checks");
-                match score {
-                    Score::One => stats.score_1 += 1,
-                    Score::Two => stats.score_2 += 1,
-                    Score::Three => stats.score_3 += 1,
-                    Score::Four => stats.score_4 += 1,
-                    Score::Five => stats.score_5 += 1,
-                }
+            // 先在副本上累加，溢出时不改变计票结果
+            let mut updated = tally.clone();
+            for (score, stats) in vote.iter().zip(updated.iter_mut()) {
+                let count = stats.count_mut(score);
+                *count = count.checked_add(1).ok_or(ArithmeticError::Overflow)?;
             }
+            *tally = updated;
             Ok(())
         }
+
+        fn merge(_config: &PollConfig, tally: &mut Tally, other: &Tally) -> DispatchResult {
+            // 没有选票的一方还没有确定问题数
+            if other.is_empty() {
+                return Ok(());
+            }
+            if tally.is_empty() {
+                *tally = other.clone();
+                return Ok(());
+            }
+            ensure!(
+                tally.len() == other.len(),
+                "Vote and Tally dimensions mismatch"
+            );
+
+            let mut merged = tally.clone();
+            for (stats, other) in merged.iter_mut().zip(other.iter()) {
+                for score in Score::ALL.iter() {
+                    let count = stats.count_mut(score);
+                    *count = count
+                        .checked_add(other.count(score))
+                        .ok_or(ArithmeticError::Overflow)?;
+                }
+            }
+            *tally = merged;
+            Ok(())
+        }
+
+        fn finalize(_config: &PollConfig, tally: &mut Tally) -> Result<Outcome, DispatchError> {
+            for stats in tally.iter_mut() {
+                stats.summary = stats.summarize();
+            }
+            Ok(Outcome::default())
+        }
+    }
+
+    /// 每条评论的最大字节数
+    pub const MAX_COMMENT_LENGTH: u32 = 500;
+    /// 每张选票最多包含的评论数
+    pub const MAX_COMMENTS: u32 = 3;
+
+    /// UTF-8 编码的评论
+    pub type Comment = BoundedVec<u8, ConstU32<MAX_COMMENT_LENGTH>>;
+
+    /// 带评论的选票。评分计入计票结果；评论不进入计票结果，在投票关闭后另行公布
+    #[derive(
+        Encode,
+        Decode,
+        Clone,
+        PartialEq,
+        Eq,
+        RuntimeDebug,
+        TypeInfo,
+        MaxEncodedLen,
+        DecodeWithMemTracking,
+    )]
+    pub struct Ballot {
+        pub scores: Vote,
+        pub comments: BoundedVec<Comment, ConstU32<MAX_COMMENTS>>,
+    }
+
+    /// 带评论选票的计票逻辑：只统计评分，评论仅检查是否为有效的 UTF-8
+    pub struct BallotTallyHandler;
+    impl TallyLogic<Ballot, Tally> for BallotTallyHandler {
+        fn validate(config: &PollConfig, ballot: &Ballot) -> DispatchResult {
+            ensure!(
+                ballot
+                    .comments
+                    .iter()
+                    .all(|comment| core::str::from_utf8(comment).is_ok()),
+                "Comment is not valid UTF-8"
+            );
+            <TallyHandler as TallyLogic<Vote, Tally>>::validate(config, &ballot.scores)
+        }
+
+        fn update_tally(config: &PollConfig, ballot: &Ballot, tally: &mut Tally) -> DispatchResult {
+            Self::validate(config, ballot)?;
+            <TallyHandler as TallyLogic<Vote, Tally>>::update_tally(config, &ballot.scores, tally)
+        }
+
+        fn merge(config: &PollConfig, tally: &mut Tally, other: &Tally) -> DispatchResult {
+            <TallyHandler as TallyLogic<Vote, Tally>>::merge(config, tally, other)
+        }
+
+        fn finalize(config: &PollConfig, tally: &mut Tally) -> Result<Outcome, DispatchError> {
+            <TallyHandler as TallyLogic<Vote, Tally>>::finalize(config, tally)
+        }
+    }
+}
+
+// 按投票配置中的问卷计票
+pub mod schema_voting {
+    use super::*;
+    use scale_info::prelude::{vec, vec::Vec};
+
+    /// 按问题顺序给出的答案：单选题为选项编号，数值题为取值
+    pub type Vote = BoundedVec<u32, ConstU32<MAX_SCHEMA_QUESTIONS>>;
+
+    /// 一个问题的统计
+    #[derive(
+        Encode,
+        Decode,
+        Clone,
+        PartialEq,
+        Eq,
+        RuntimeDebug,
+        TypeInfo,
+        MaxEncodedLen,
+        DecodeWithMemTracking,
+    )]
+    pub enum QuestionTally {
+        /// 每个选项的票数
+        Choice(BoundedVec<u32, ConstU32<MAX_CHOICE_OPTIONS>>),
+        /// 数值题的取值范围、作答人数和取值总和
+        Range {
+            min: u32,
+            max: u32,
+            responses: u32,
+            sum: u64,
+        },
+    }
+
+    impl QuestionTally {
+        /// 为一个问题创建空的统计
+        pub fn new(question: &Question) -> Self {
+            match question {
+                Question::Choice { options } => {
+                    Self::Choice(BoundedVec::truncate_from(vec![0; *options as usize]))
+                }
+                Question::Range { min, max } => Self::Range {
+                    min: *min,
+                    max: *max,
+                    responses: 0,
+                    sum: 0,
+                },
+            }
+        }
+
+        /// 统计的结构是否与问题一致
+        pub fn matches(&self, question: &Question) -> bool {
+            match (self, question) {
+                (Self::Choice(counts), Question::Choice { options }) => {
+                    counts.len() as u32 == *options
+                }
+                (Self::Range { min, max, .. }, Question::Range { min: lo, max: hi }) => {
+                    min == lo && max == hi
+                }
+                _ => false,
+            }
+        }
+
+        /// 答案是否在统计记录的范围内
+        pub fn accepts(&self, answer: u32) -> bool {
+            match self {
+                Self::Choice(counts) => (answer as usize) < counts.len(),
+                Self::Range { min, max, .. } => (*min..=*max).contains(&answer),
+            }
+        }
+
+        /// 作答人数
+        pub fn responses(&self) -> u32 {
+            match self {
+                Self::Choice(counts) => counts.iter().fold(0u32, |acc, c| acc.saturating_add(*c)),
+                Self::Range { responses, .. } => *responses,
+            }
+        }
+
+        /// 合并另一部分选票中同一问题的统计
+        fn merge(&mut self, other: &Self) -> DispatchResult {
+            match (self, other) {
+                (Self::Choice(counts), Self::Choice(others)) => {
+                    ensure!(
+                        counts.len() == others.len(),
+                        "Vote and Tally dimensions mismatch"
+                    );
+                    for (count, other) in counts.iter_mut().zip(others.iter()) {
+                        *count = count.checked_add(*other).ok_or(ArithmeticError::Overflow)?;
+                    }
+                }
+                (
+                    Self::Range {
+                        min,
+                        max,
+                        responses,
+                        sum,
+                    },
+                    Self::Range {
+                        min: lo,
+                        max: hi,
+                        responses: other_responses,
+                        sum: other_sum,
+                    },
+                ) => {
+                    ensure!(min == lo && max == hi, "Vote and Tally dimensions mismatch");
+                    *responses = responses
+                        .checked_add(*other_responses)
+                        .ok_or(ArithmeticError::Overflow)?;
+                    *sum = sum
+                        .checked_add(*other_sum)
+                        .ok_or(ArithmeticError::Overflow)?;
+                }
+                _ => return Err("Vote and Tally dimensions mismatch".into()),
+            }
+            Ok(())
+        }
+
+        fn record(&mut self, answer: u32) -> DispatchResult {
+            match self {
+                Self::Choice(counts) => {
+                    let count = counts
+                        .get_mut(answer as usize)
+                        .ok_or("Answer out of range")?;
+                    *count = count.checked_add(1).ok_or(ArithmeticError::Overflow)?;
+                }
+                Self::Range { responses, sum, .. } => {
+                    *responses = responses.checked_add(1).ok_or(ArithmeticError::Overflow)?;
+                    *sum = sum
+                        .checked_add(answer as u64)
+                        .ok_or(ArithmeticError::Overflow)?;
+                }
+            }
+            Ok(())
+        }
+    }
+
+    pub type Tally = BoundedVec<QuestionTally, ConstU32<MAX_SCHEMA_QUESTIONS>>;
+
+    pub struct TallyHandler;
+    impl TallyLogic<Vote, Tally> for TallyHandler {
+        fn new_tally(config: &PollConfig) -> Result<Tally, DispatchError> {
+            let schema = config
+                .schema
+                .as_ref()
+                .ok_or(DispatchError::Other("Ballot schema required"))?;
+            let tally: Vec<QuestionTally> =
+                schema.questions.iter().map(QuestionTally::new).collect();
+            BoundedVec::try_from(tally).map_err(|_| {
+                DispatchError::Other("Failed to initialize tally: exceeds maximum questions")
+            })
+        }
+
+        fn validate(config: &PollConfig, vote: &Vote) -> DispatchResult {
+            let schema = config
+                .schema
+                .as_ref()
+                .ok_or(DispatchError::Other("Ballot schema required"))?;
+            ensure!(
+                vote.len() == schema.questions.len(),
+                "Vote and Tally dimensions mismatch"
+            );
+            ensure!(
+                vote.iter()
+                    .zip(schema.questions.iter())
+                    .all(|(answer, question)| question.accepts(*answer)),
+                "Answer out of range"
+            );
+            Ok(())
+        }
+
+        fn update_tally(config: &PollConfig, vote: &Vote, tally: &mut Tally) -> DispatchResult {
+            Self::validate(config, vote)?;
+            ensure!(
+                vote.len() == tally.len(),
+                "Vote and Tally dimensions mismatch"
+            );
+            // 先检查所有答案，避免只记录了一部分
+            ensure!(
+                vote.iter()
+                    .zip(tally.iter())
+                    .all(|(answer, stats)| stats.accepts(*answer)),
+                "Answer out of range"
+            );
+
+            // 在副本上累加，溢出时不改变计票结果
+            let mut updated = tally.clone();
+            for (answer, stats) in vote.iter().zip(updated.iter_mut()) {
+                stats.record(*answer)?;
+            }
+            *tally = updated;
+            Ok(())
+        }
+
+        fn merge(_config: &PollConfig, tally: &mut Tally, other: &Tally) -> DispatchResult {
+            ensure!(
+                tally.len() == other.len(),
+                "Vote and Tally dimensions mismatch"
+            );
+            let mut merged = tally.clone();
+            for (stats, other) in merged.iter_mut().zip(other.iter()) {
+                stats.merge(other)?;
+            }
+            *tally = merged;
+            Ok(())
+        }
+
+        fn validate_tally(config: &PollConfig, tally: &Tally) -> DispatchResult {
+            let schema = config
+                .schema
+                .as_ref()
+                .ok_or(DispatchError::Other("Ballot schema required"))?;
+            ensure!(
+                schema.questions.len() == tally.len(),
+                "Tally does not match ballot schema"
+            );
+            ensure!(
+                tally
+                    .iter()
+                    .zip(schema.questions.iter())
+                    .all(|(stats, question)| stats.matches(question)),
+                "Tally does not match ballot schema"
+            );
+
+            // 每张选票回答所有问题，因此各问题的作答人数相同
+            let responses = tally.first().map_or(0, QuestionTally::responses);
+            ensure!(
+                tally.iter().all(|stats| stats.responses() == responses),
+                "Inconsistent responses across questions"
+            );
+            for stats in tally.iter() {
+                if let QuestionTally::Range {
+                    min,
+                    max,
+                    responses,
+                    sum,
+                } = stats
+                {
+                    let responses = *responses as u64;
+                    ensure!(
+                        (*min as u64 * responses..=*max as u64 * responses).contains(sum),
+                        "Range sum out of bounds"
+                    );
+                }
+            }
+            Ok(())
+        }
+    }
+}
+
+// 排序投票，按即时决选（淘汰制）计票
+pub mod ranked_voting {
+    use super::*;
+    use scale_info::prelude::{vec, vec::Vec};
+
+    /// 按偏好从高到低排列的候选人编号，不必列出所有候选人
+    pub type Vote = BoundedVec<u32, ConstU32<MAX_CHOICE_OPTIONS>>;
+
+    /// 相同排序的选票数
+    #[derive(
+        Encode,
+        Decode,
+        Clone,
+        PartialEq,
+        Eq,
+        RuntimeDebug,
+        TypeInfo,
+        MaxEncodedLen,
+        DecodeWithMemTracking,
+    )]
+    pub struct RankedBallots {
+        pub ranking: Vote,
+        pub count: u32,
+    }
+
+    /// 一轮计票
+    #[derive(
+        Encode,
+        Decode,
+        Clone,
+        PartialEq,
+        Eq,
+        RuntimeDebug,
+        TypeInfo,
+        MaxEncodedLen,
+        DecodeWithMemTracking,
+    )]
+    pub struct Round {
+        /// 本轮每个候选人的票数，已淘汰的候选人为 0
+        pub counts: BoundedVec<u32, ConstU32<MAX_CHOICE_OPTIONS>>,
+        /// 本轮被淘汰的候选人，产生胜者的一轮为 `None`
+        pub eliminated: Option<u32>,
+    }
+
+    /// `MaxRankings` 为计票结果中最多保存的不同排序数
+    #[derive(
+        CloneNoBound,
+        DebugNoBound,
+        PartialEqNoBound,
+        EqNoBound,
+        DefaultNoBound,
+        Encode,
+        Decode,
+        TypeInfo,
+        MaxEncodedLen,
+        DecodeWithMemTracking,
+    )]
+    #[scale_info(skip_type_params(MaxRankings))]
+    pub struct Tally<MaxRankings: Get<u32>> {
+        /// 候选人数量
+        pub candidates: u32,
+        /// 按排序归并的选票
+        pub ballots: BoundedVec<RankedBallots, MaxRankings>,
+        /// 关闭投票时计算的各轮计票，便于审计
+        pub rounds: BoundedVec<Round, ConstU32<MAX_CHOICE_OPTIONS>>,
+        /// 胜者
+        pub winner: Option<u32>,
+    }
+
+    /// 排序非空、不重复，且只包含有效的候选人
+    pub fn is_valid_ranking(ranking: &Vote, candidates: u32) -> bool {
+        !ranking.is_empty()
+            && ranking
+                .iter()
+                .enumerate()
+                .all(|(i, c)| *c < candidates && !ranking[..i].contains(c))
+    }
+
+    /// 在票数最少的候选人中选出被淘汰者：依次比较之前各轮的票数，仍然相同时淘汰编号最大的
+    fn lowest(remaining: &[usize], counts: &[u32], rounds: &[Round]) -> usize {
+        let fewest = |tied: Vec<usize>, counts: &[u32]| -> Vec<usize> {
+            let min = tied.iter().map(|c| counts[*c]).min().unwrap_or(0);
+            tied.into_iter().filter(|c| counts[*c] == min).collect()
+        };
+
+        let mut tied = fewest(remaining.to_vec(), counts);
+        for round in rounds.iter().rev() {
+            if tied.len() == 1 {
+                break;
+            }
+            tied = fewest(tied, &round.counts);
+        }
+        tied.into_iter().max().unwrap_or(0)
+    }
+
+    /// 把 `count` 张相同排序的选票归并到计票结果中
+    fn add_ballots<MaxRankings: Get<u32>>(
+        ballots: &mut BoundedVec<RankedBallots, MaxRankings>,
+        ranking: &Vote,
+        count: u32,
+    ) -> DispatchResult {
+        match ballots.iter_mut().find(|b| b.ranking == *ranking) {
+            Some(ballots) => {
+                ballots.count = ballots
+                    .count
+                    .checked_add(count)
+                    .ok_or(ArithmeticError::Overflow)?
+            }
+            None => ballots
+                .try_push(RankedBallots {
+                    ranking: ranking.clone(),
+                    count,
+                })
+                .map_err(|_| DispatchError::Other("Too many distinct rankings"))?,
+        }
+        Ok(())
+    }
+
+    /// `MaxRankings` 应不小于实例的 `MaxVotesPerPoll`，
+    /// 这样即使每张选票的排序都不同，所有有效选票也都能计入
+    pub struct TallyHandler<MaxRankings>(PhantomData<MaxRankings>);
+    impl<MaxRankings: Get<u32>> TallyLogic<Vote, Tally<MaxRankings>> for TallyHandler<MaxRankings> {
+        fn new_tally(config: &PollConfig) -> Result<Tally<MaxRankings>, DispatchError> {
+            let candidates = config.candidates().ok_or(DispatchError::Other(
+                "Ranked voting requires a single choice question",
+            ))?;
+            Ok(Tally {
+                candidates,
+                ..Default::default()
+            })
+        }
+
+        fn validate(config: &PollConfig, vote: &Vote) -> DispatchResult {
+            let candidates = config.candidates().ok_or(DispatchError::Other(
+                "Ranked voting requires a single choice question",
+            ))?;
+            ensure!(is_valid_ranking(vote, candidates), "Invalid ranking");
+            Ok(())
+        }
+
+        fn update_tally(
+            config: &PollConfig,
+            vote: &Vote,
+            tally: &mut Tally<MaxRankings>,
+        ) -> DispatchResult {
+            Self::validate(config, vote)?;
+            ensure!(is_valid_ranking(vote, tally.candidates), "Invalid ranking");
+            add_ballots(&mut tally.ballots, vote, 1)
+        }
+
+        fn merge(
+            _config: &PollConfig,
+            tally: &mut Tally<MaxRankings>,
+            other: &Tally<MaxRankings>,
+        ) -> DispatchResult {
+            ensure!(
+                tally.candidates == other.candidates,
+                "Vote and Tally dimensions mismatch"
+            );
+            let mut ballots = tally.ballots.clone();
+            for other in other.ballots.iter() {
+                add_ballots(&mut ballots, &other.ranking, other.count)?;
+            }
+            tally.ballots = ballots;
+            Ok(())
+        }
+
+        fn validate_tally(config: &PollConfig, tally: &Tally<MaxRankings>) -> DispatchResult {
+            ensure!(
+                config.candidates() == Some(tally.candidates),
+                "Tally does not match ballot schema"
+            );
+            for (i, ballots) in tally.ballots.iter().enumerate() {
+                ensure!(
+                    ballots.count > 0 && is_valid_ranking(&ballots.ranking, tally.candidates),
+                    "Invalid ranking"
+                );
+                ensure!(
+                    tally.ballots[..i]
+                        .iter()
+                        .all(|b| b.ranking != ballots.ranking),
+                    "Duplicate ranking"
+                );
+            }
+            Ok(())
+        }
+
+        fn finalize(
+            _config: &PollConfig,
+            tally: &mut Tally<MaxRankings>,
+        ) -> Result<Outcome, DispatchError> {
+            let candidates = tally.candidates as usize;
+            let mut eliminated = vec![false; candidates];
+            let mut rounds: Vec<Round> = Vec::new();
+            let mut winner = None;
+
+            while !tally.ballots.is_empty() {
+                // 每张选票计入其排序中第一个未被淘汰的候选人
+                let mut counts = vec![0u32; candidates];
+                for ballots in tally.ballots.iter() {
+                    let next = ballots
+                        .ranking
+                        .iter()
+                        .find(|c| eliminated.get(**c as usize) == Some(&false));
+                    if let Some(c) = next {
+                        let count = &mut counts[*c as usize];
+                        *count = count
+                            .checked_add(ballots.count)
+                            .ok_or(ArithmeticError::Overflow)?;
+                    }
+                }
+                let active: u64 = counts.iter().map(|c| *c as u64).sum();
+                let remaining: Vec<usize> = (0..candidates).filter(|c| !eliminated[*c]).collect();
+
+                // 只剩一人或有人获得过半有效票时结束
+                let leader = remaining.iter().copied().max_by_key(|c| counts[*c]);
+                let decided =
+                    leader.filter(|c| remaining.len() == 1 || counts[*c] as u64 * 2 > active);
+                let loser = match decided {
+                    Some(_) => None,
+                    None => Some(lowest(&remaining, &counts, &rounds)),
+                };
+
+                rounds.push(Round {
+                    counts: BoundedVec::truncate_from(counts),
+                    eliminated: loser.map(|c| c as u32),
+                });
+                match loser {
+                    Some(c) => eliminated[c] = true,
+                    None => {
+                        winner = decided.map(|c| c as u32);
+                        break;
+                    }
+                }
+            }
+
+            tally.rounds = BoundedVec::truncate_from(rounds);
+            tally.winner = winner;
+            Ok(Outcome::default())
+        }
+    }
+}
+
+// 排序投票，按 Schulze 方法（孔多塞一致）计票
+pub mod schulze_voting {
+    use super::*;
+    use crate::ranked_voting::is_valid_ranking;
+    use scale_info::prelude::{vec, vec::Vec};
+
+    /// 与排序投票相同：按偏好从高到低排列的候选人编号，未列出的候选人排在最后
+    pub type Vote = crate::ranked_voting::Vote;
+
+    pub type Row = BoundedVec<u32, ConstU32<MAX_CHOICE_OPTIONS>>;
+
+    #[derive(
+        Encode,
+        Decode,
+        Clone,
+        PartialEq,
+        Eq,
+        RuntimeDebug,
+        TypeInfo,
+        MaxEncodedLen,
+        DecodeWithMemTracking,
+        Default,
+    )]
+    pub struct Tally {
+        /// 候选人数量
+        pub candidates: u32,
+        /// 两两偏好矩阵：`preferences[i][j]` 为认为 i 优于 j 的票数
+        pub preferences: BoundedVec<Row, ConstU32<MAX_CHOICE_OPTIONS>>,
+        /// 关闭投票时计算的候选人排名，第一位为胜者
+        pub order: Row,
+    }
+
+    /// 按最强路径计算排名：i 的最强路径强于 j 时排在 j 之前，无法区分时按编号排序
+    fn schulze_order(preferences: &[Row]) -> Vec<u32> {
+        let n = preferences.len();
+        let d = |i: usize, j: usize| preferences[i][j];
+
+        let mut p: Vec<Vec<u32>> = (0..n)
+            .map(|i| {
+                (0..n)
+                    .map(|j| if d(i, j) > d(j, i) { d(i, j) } else { 0 })
+                    .collect()
+            })
+            .collect();
+        for k in 0..n {
+            for i in 0..n {
+                if i == k {
+                    continue;
+                }
+                for j in 0..n {
+                    if j != k && j != i {
+                        p[i][j] = p[i][j].max(p[i][k].min(p[k][j]));
+                    }
+                }
+            }
+        }
+
+        // 强于的对手越多排名越靠前，该关系可传递，因此按此排序即得到 Schulze 排名
+        let wins = |i: usize| (0..n).filter(|j| p[i][*j] > p[*j][i]).count();
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by_key(|i| (core::cmp::Reverse(wins(*i)), *i));
+        order.into_iter().map(|i| i as u32).collect()
+    }
+
+    pub struct TallyHandler;
+    impl TallyLogic<Vote, Tally> for TallyHandler {
+        fn new_tally(config: &PollConfig) -> Result<Tally, DispatchError> {
+            let candidates = config.candidates().ok_or(DispatchError::Other(
+                "Schulze voting requires a single choice question",
+            ))?;
+            let row = Row::truncate_from(vec![0; candidates as usize]);
+            Ok(Tally {
+                candidates,
+                preferences: BoundedVec::truncate_from(vec![row; candidates as usize]),
+                ..Default::default()
+            })
+        }
+
+        fn validate(config: &PollConfig, vote: &Vote) -> DispatchResult {
+            let candidates = config.candidates().ok_or(DispatchError::Other(
+                "Schulze voting requires a single choice question",
+            ))?;
+            ensure!(is_valid_ranking(vote, candidates), "Invalid ranking");
+            Ok(())
+        }
+
+        fn update_tally(config: &PollConfig, vote: &Vote, tally: &mut Tally) -> DispatchResult {
+            Self::validate(config, vote)?;
+            ensure!(is_valid_ranking(vote, tally.candidates), "Invalid ranking");
+            ensure!(
+                tally.preferences.len() as u32 == tally.candidates,
+                "Vote and Tally dimensions mismatch"
+            );
+
+            // 先在副本上累加，溢出时不改变计票结果
+            let mut preferences = tally.preferences.clone();
+            let rank = |c: u32| vote.iter().position(|r| *r == c);
+            for i in 0..tally.candidates {
+                let Some(ri) = rank(i) else { continue };
+                for j in 0..tally.candidates {
+                    if i != j && rank(j).is_none_or(|rj| ri < rj) {
+                        let count = &mut preferences[i as usize][j as usize];
+                        *count = count.checked_add(1).ok_or(ArithmeticError::Overflow)?;
+                    }
+                }
+            }
+            tally.preferences = preferences;
+            Ok(())
+        }
+
+        fn merge(_config: &PollConfig, tally: &mut Tally, other: &Tally) -> DispatchResult {
+            ensure!(
+                tally.candidates == other.candidates
+                    && tally.preferences.len() == other.preferences.len(),
+                "Vote and Tally dimensions mismatch"
+            );
+            let mut preferences = tally.preferences.clone();
+            for (row, other) in preferences.iter_mut().zip(other.preferences.iter()) {
+                ensure!(
+                    row.len() == other.len(),
+                    "Vote and Tally dimensions mismatch"
+                );
+                for (count, other) in row.iter_mut().zip(other.iter()) {
+                    *count = count.checked_add(*other).ok_or(ArithmeticError::Overflow)?;
+                }
+            }
+            tally.preferences = preferences;
+            Ok(())
+        }
+
+        fn validate_tally(config: &PollConfig, tally: &Tally) -> DispatchResult {
+            ensure!(
+                config.candidates() == Some(tally.candidates),
+                "Tally does not match ballot schema"
+            );
+            ensure!(
+                tally.preferences.len() as u32 == tally.candidates
+                    && tally
+                        .preferences
+                        .iter()
+                        .all(|row| row.len() as u32 == tally.candidates),
+                "Tally does not match ballot schema"
+            );
+            ensure!(
+                tally
+                    .preferences
+                    .iter()
+                    .enumerate()
+                    .all(|(i, row)| row[i] == 0),
+                "Candidate cannot be preferred over itself"
+            );
+            Ok(())
+        }
+
+        fn finalize(_config: &PollConfig, tally: &mut Tally) -> Result<Outcome, DispatchError> {
+            ensure!(
+                tally
+                    .preferences
+                    .iter()
+                    .all(|row| row.len() == tally.preferences.len()),
+                "Vote and Tally dimensions mismatch"
+            );
+            tally.order = Row::truncate_from(schulze_order(&tally.preferences));
+            Ok(Outcome::default())
+        }
+    }
+}
+
+// 认可投票：每张选票勾选任意多个选项
+pub mod approval_voting {
+    use super::*;
+    use scale_info::prelude::vec;
+
+    /// 勾选的选项，第 i 位表示认可第 i 个选项（最多 `MAX_CHOICE_OPTIONS` 个）
+    pub type Vote = u16;
+
+    #[derive(
+        Encode,
+        Decode,
+        Clone,
+        PartialEq,
+        Eq,
+        RuntimeDebug,
+        TypeInfo,
+        MaxEncodedLen,
+        DecodeWithMemTracking,
+        Default,
+    )]
+    pub struct Tally {
+        /// 选票数
+        pub ballots: u32,
+        /// 每个选项获得的认可数
+        pub approvals: BoundedVec<u32, ConstU32<MAX_CHOICE_OPTIONS>>,
+    }
+
+    pub struct TallyHandler;
+    impl TallyLogic<Vote, Tally> for TallyHandler {
+        fn new_tally(config: &PollConfig) -> Result<Tally, DispatchError> {
+            let options = config.candidates().ok_or(DispatchError::Other(
+                "Approval voting requires a single choice question",
+            ))?;
+            Ok(Tally {
+                ballots: 0,
+                approvals: BoundedVec::truncate_from(vec![0; options as usize]),
+            })
+        }
+
+        fn validate(config: &PollConfig, vote: &Vote) -> DispatchResult {
+            let options = config.candidates().ok_or(DispatchError::Other(
+                "Approval voting requires a single choice question",
+            ))?;
+            ensure!(
+                options >= u16::BITS || vote >> options == 0,
+                "Approval for unknown option"
+            );
+            Ok(())
+        }
+
+        fn update_tally(config: &PollConfig, vote: &Vote, tally: &mut Tally) -> DispatchResult {
+            Self::validate(config, vote)?;
+            let options = tally.approvals.len() as u32;
+            ensure!(
+                options >= u16::BITS || vote >> options == 0,
+                "Approval for unknown option"
+            );
+
+            let mut updated = tally.clone();
+            updated.ballots = updated
+                .ballots
+                .checked_add(1)
+                .ok_or(ArithmeticError::Overflow)?;
+            for (i, count) in updated.approvals.iter_mut().enumerate() {
+                if vote & (1 << i) != 0 {
+                    *count = count.checked_add(1).ok_or(ArithmeticError::Overflow)?;
+                }
+            }
+            *tally = updated;
+            Ok(())
+        }
+
+        fn merge(_config: &PollConfig, tally: &mut Tally, other: &Tally) -> DispatchResult {
+            ensure!(
+                tally.approvals.len() == other.approvals.len(),
+                "Vote and Tally dimensions mismatch"
+            );
+            let mut merged = tally.clone();
+            merged.ballots = merged
+                .ballots
+                .checked_add(other.ballots)
+                .ok_or(ArithmeticError::Overflow)?;
+            for (count, other) in merged.approvals.iter_mut().zip(other.approvals.iter()) {
+                *count = count.checked_add(*other).ok_or(ArithmeticError::Overflow)?;
+            }
+            *tally = merged;
+            Ok(())
+        }
+
+        fn validate_tally(config: &PollConfig, tally: &Tally) -> DispatchResult {
+            ensure!(
+                config.candidates() == Some(tally.approvals.len() as u32),
+                "Tally does not match ballot schema"
+            );
+            ensure!(
+                tally.approvals.iter().all(|count| *count <= tally.ballots),
+                "More approvals than ballots"
+            );
+            Ok(())
+        }
+    }
+}
+
+// 评分投票：每张选票为每个选项打分，可以跳过部分选项
+pub mod score_voting {
+    use super::*;
+    use scale_info::prelude::vec::Vec;
+
+    /// 按选项顺序给出的分数，`None` 表示不评价该选项
+    pub type Vote = BoundedVec<Option<u32>, ConstU32<MAX_SCHEMA_QUESTIONS>>;
+
+    /// 一个选项的评分统计
+    #[derive(
+        Encode,
+        Decode,
+        Clone,
+        PartialEq,
+        Eq,
+        RuntimeDebug,
+        TypeInfo,
+        MaxEncodedLen,
+        DecodeWithMemTracking,
+    )]
+    pub struct OptionScore {
+        pub min: u32,
+        pub max: u32,
+        /// 评价该选项的人数
+        pub count: u32,
+        /// 分数总和
+        pub sum: u64,
+    }
+
+    /// 问卷中每个数值题对应一个选项，分数范围为该题的取值范围
+    pub type Tally = BoundedVec<OptionScore, ConstU32<MAX_SCHEMA_QUESTIONS>>;
+
+    pub struct TallyHandler;
+    impl TallyLogic<Vote, Tally> for TallyHandler {
+        fn new_tally(config: &PollConfig) -> Result<Tally, DispatchError> {
+            let schema = config
+                .schema
+                .as_ref()
+                .ok_or(DispatchError::Other("Ballot schema required"))?;
+            let tally = schema
+                .questions
+                .iter()
+                .map(|question| match question {
+                    Question::Range { min, max } => Ok(OptionScore {
+                        min: *min,
+                        max: *max,
+                        count: 0,
+                        sum: 0,
+                    }),
+                    Question::Choice { .. } => Err(DispatchError::Other(
+                        "Score voting requires range questions",
+                    )),
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            BoundedVec::try_from(tally).map_err(|_| {
+                DispatchError::Other("Failed to initialize tally: exceeds maximum questions")
+            })
+        }
+
+        fn validate(config: &PollConfig, vote: &Vote) -> DispatchResult {
+            let options = Self::new_tally(config)?;
+            ensure!(
+                vote.len() == options.len(),
+                "Vote and Tally dimensions mismatch"
+            );
+            ensure!(
+                vote.iter().zip(options.iter()).all(|(score, option)| score
+                    .is_none_or(|score| (option.min..=option.max).contains(&score))),
+                "Score out of range"
+            );
+            Ok(())
+        }
+
+        fn update_tally(config: &PollConfig, vote: &Vote, tally: &mut Tally) -> DispatchResult {
+            Self::validate(config, vote)?;
+            ensure!(
+                vote.len() == tally.len(),
+                "Vote and Tally dimensions mismatch"
+            );
+
+            let mut updated = tally.clone();
+            for (score, stats) in vote.iter().zip(updated.iter_mut()) {
+                let Some(score) = score else { continue };
+                ensure!(
+                    (stats.min..=stats.max).contains(score),
+                    "Score out of range"
+                );
+                stats.count = stats
+                    .count
+                    .checked_add(1)
+                    .ok_or(ArithmeticError::Overflow)?;
+                stats.sum = stats
+                    .sum
+                    .checked_add(*score as u64)
+                    .ok_or(ArithmeticError::Overflow)?;
+            }
+            *tally = updated;
+            Ok(())
+        }
+
+        fn merge(_config: &PollConfig, tally: &mut Tally, other: &Tally) -> DispatchResult {
+            ensure!(
+                tally.len() == other.len(),
+                "Vote and Tally dimensions mismatch"
+            );
+            let mut merged = tally.clone();
+            for (stats, other) in merged.iter_mut().zip(other.iter()) {
+                ensure!(
+                    stats.min == other.min && stats.max == other.max,
+                    "Vote and Tally dimensions mismatch"
+                );
+                stats.count = stats
+                    .count
+                    .checked_add(other.count)
+                    .ok_or(ArithmeticError::Overflow)?;
+                stats.sum = stats
+                    .sum
+                    .checked_add(other.sum)
+                    .ok_or(ArithmeticError::Overflow)?;
+            }
+            *tally = merged;
+            Ok(())
+        }
+
+        fn validate_tally(config: &PollConfig, tally: &Tally) -> DispatchResult {
+            let expected = Self::new_tally(config)?;
+            ensure!(
+                expected.len() == tally.len(),
+                "Tally does not match ballot schema"
+            );
+            for (stats, empty) in tally.iter().zip(expected.iter()) {
+                ensure!(
+                    stats.min == empty.min && stats.max == empty.max,
+                    "Tally does not match ballot schema"
+                );
+                let count = stats.count as u64;
+                ensure!(
+                    (stats.min as u64 * count..=stats.max as u64 * count).contains(&stats.sum),
+                    "Score sum out of bounds"
+                );
+            }
+            Ok(())
+        }
+    }
+}
+
+// 二次方投票：每位投票人有相同的点数预算，给一个选项投 n 票花费 n² 点
+pub mod quadratic_voting {
+    use super::*;
+    use core::marker::PhantomData;
+    use scale_info::prelude::vec;
+
+    /// 按选项顺序给出的票数，正数为支持，负数为反对
+    pub type Vote = BoundedVec<i32, ConstU32<MAX_CHOICE_OPTIONS>>;
+
+    #[derive(
+        Encode,
+        Decode,
+        Clone,
+        PartialEq,
+        Eq,
+        RuntimeDebug,
+        TypeInfo,
+        MaxEncodedLen,
+        DecodeWithMemTracking,
+        Default,
+    )]
+    pub struct Tally {
+        /// 有效选票数
+        pub ballots: u32,
+        /// 每个选项的净票数（支持减反对）
+        pub votes: BoundedVec<i64, ConstU32<MAX_CHOICE_OPTIONS>>,
+    }
+
+    /// 一张选票花费的点数
+    pub fn cost(vote: &Vote) -> Option<u64> {
+        vote.iter().try_fold(0u64, |total, votes| {
+            let votes = votes.unsigned_abs() as u64;
+            total.checked_add(votes.checked_mul(votes)?)
+        })
+    }
+
+    /// `Budget` 为每位投票人的点数预算。
+    /// 选票在关闭前是加密的，链上无法在提交时检查预算，因此在计票时拒绝超出预算的选票
+    pub struct TallyHandler<Budget>(PhantomData<Budget>);
+    impl<Budget: Get<u64>> TallyLogic<Vote, Tally> for TallyHandler<Budget> {
+        fn new_tally(config: &PollConfig) -> Result<Tally, DispatchError> {
+            let options = config.candidates().ok_or(DispatchError::Other(
+                "Quadratic voting requires a single choice question",
+            ))?;
+            Ok(Tally {
+                ballots: 0,
+                votes: BoundedVec::truncate_from(vec![0; options as usize]),
+            })
+        }
+
+        fn validate(config: &PollConfig, vote: &Vote) -> DispatchResult {
+            let options = config.candidates().ok_or(DispatchError::Other(
+                "Quadratic voting requires a single choice question",
+            ))?;
+            ensure!(
+                vote.len() as u32 == options,
+                "Vote and Tally dimensions mismatch"
+            );
+            ensure!(
+                cost(vote).is_some_and(|cost| cost <= Budget::get()),
+                "Ballot exceeds credit budget"
+            );
+            Ok(())
+        }
+
+        fn update_tally(config: &PollConfig, vote: &Vote, tally: &mut Tally) -> DispatchResult {
+            Self::validate(config, vote)?;
+            ensure!(
+                vote.len() == tally.votes.len(),
+                "Vote and Tally dimensions mismatch"
+            );
+
+            let mut updated = tally.clone();
+            updated.ballots = updated
+                .ballots
+                .checked_add(1)
+                .ok_or(ArithmeticError::Overflow)?;
+            for (votes, total) in vote.iter().zip(updated.votes.iter_mut()) {
+                *total = total
+                    .checked_add(*votes as i64)
+                    .ok_or(ArithmeticError::Overflow)?;
+            }
+            *tally = updated;
+            Ok(())
+        }
+
+        fn merge(_config: &PollConfig, tally: &mut Tally, other: &Tally) -> DispatchResult {
+            ensure!(
+                tally.votes.len() == other.votes.len(),
+                "Vote and Tally dimensions mismatch"
+            );
+            let mut merged = tally.clone();
+            merged.ballots = merged
+                .ballots
+                .checked_add(other.ballots)
+                .ok_or(ArithmeticError::Overflow)?;
+            for (total, other) in merged.votes.iter_mut().zip(other.votes.iter()) {
+                *total = total.checked_add(*other).ok_or(ArithmeticError::Overflow)?;
+            }
+            *tally = merged;
+            Ok(())
+        }
+
+        fn validate_tally(config: &PollConfig, tally: &Tally) -> DispatchResult {
+            ensure!(
+                config.candidates() == Some(tally.votes.len() as u32),
+                "Tally does not match ballot schema"
+            );
+            // 每张选票在一个选项上最多投 √Budget 票
+            let max_votes = Budget::get().isqrt().saturating_mul(tally.ballots as u64);
+            ensure!(
+                tally
+                    .votes
+                    .iter()
+                    .all(|votes| votes.unsigned_abs() <= max_votes),
+                "Votes exceed credit budget"
+            );
+            Ok(())
+        }
+    }
+}
+
+// 参与式预算：每张选票把固定点数分配给各项目，关闭时按得票在资金上限内选出获资助的项目
+pub mod budget_voting {
+    use super::*;
+    use scale_info::prelude::{vec, vec::Vec};
+
+    /// 按项目顺序给出的点数，总和必须等于 `BudgetConfig::allocation`
+    pub type Vote = BoundedVec<u32, ConstU32<MAX_CHOICE_OPTIONS>>;
+
+    #[derive(
+        Encode,
+        Decode,
+        Clone,
+        PartialEq,
+        Eq,
+        RuntimeDebug,
+        TypeInfo,
+        MaxEncodedLen,
+        DecodeWithMemTracking,
+        Default,
+    )]
+    pub struct Tally {
+        /// 每张选票分配的点数
+        pub allocation: u32,
+        /// 每个项目所需的资金
+        pub costs: BoundedVec<u64, ConstU32<MAX_CHOICE_OPTIONS>>,
+        /// 资金上限
+        pub cap: u64,
+        /// 有效选票数
+        pub ballots: u32,
+        /// 每个项目获得的点数
+        pub points: BoundedVec<u64, ConstU32<MAX_CHOICE_OPTIONS>>,
+        /// 关闭投票时选出的项目，按得票从高到低排列，随 `PollClosed` 事件公布
+        pub funded: BoundedVec<u32, ConstU32<MAX_CHOICE_OPTIONS>>,
+    }
+
+    /// 按得票从高到低依次选择项目（得票相同时编号小的优先），放不进剩余资金的项目跳过
+    fn select(points: &[u64], costs: &[u64], cap: u64) -> Vec<u32> {
+        let mut ranked: Vec<usize> = (0..points.len()).filter(|p| points[*p] > 0).collect();
+        ranked.sort_by_key(|p| (core::cmp::Reverse(points[*p]), *p));
+
+        let mut remaining = cap;
+        let mut funded = Vec::new();
+        for project in ranked {
+            if let Some(rest) = remaining.checked_sub(costs[project]) {
+                remaining = rest;
+                funded.push(project as u32);
+            }
+        }
+        funded
+    }
+
+    pub struct TallyHandler;
+    impl TallyLogic<Vote, Tally> for TallyHandler {
+        fn new_tally(config: &PollConfig) -> Result<Tally, DispatchError> {
+            let budget = config
+                .budget
+                .as_ref()
+                .ok_or(DispatchError::Other("Budget configuration required"))?;
+            ensure!(
+                budget.allocation > 0 && !budget.costs.is_empty(),
+                "Invalid budget configuration"
+            );
+            Ok(Tally {
+                allocation: budget.allocation,
+                costs: budget.costs.clone(),
+                cap: budget.cap,
+                points: BoundedVec::truncate_from(vec![0; budget.costs.len()]),
+                ..Default::default()
+            })
+        }
+
+        fn validate(config: &PollConfig, vote: &Vote) -> DispatchResult {
+            let budget = config
+                .budget
+                .as_ref()
+                .ok_or(DispatchError::Other("Budget configuration required"))?;
+            ensure!(
+                vote.len() == budget.costs.len(),
+                "Vote and Tally dimensions mismatch"
+            );
+            let total = vote.iter().map(|p| *p as u64).sum::<u64>();
+            ensure!(
+                total == budget.allocation as u64,
+                "Allocation does not match the fixed total"
+            );
+            Ok(())
+        }
+
+        fn update_tally(config: &PollConfig, vote: &Vote, tally: &mut Tally) -> DispatchResult {
+            Self::validate(config, vote)?;
+            ensure!(
+                vote.len() == tally.points.len(),
+                "Vote and Tally dimensions mismatch"
+            );
+            let total = vote.iter().map(|p| *p as u64).sum::<u64>();
+            ensure!(
+                total == tally.allocation as u64,
+                "Allocation does not match the fixed total"
+            );
+
+            let mut updated = tally.clone();
+            updated.ballots = updated
+                .ballots
+                .checked_add(1)
+                .ok_or(ArithmeticError::Overflow)?;
+            for (points, total) in vote.iter().zip(updated.points.iter_mut()) {
+                *total = total
+                    .checked_add(*points as u64)
+                    .ok_or(ArithmeticError::Overflow)?;
+            }
+            *tally = updated;
+            Ok(())
+        }
+
+        fn merge(_config: &PollConfig, tally: &mut Tally, other: &Tally) -> DispatchResult {
+            ensure!(
+                tally.allocation == other.allocation
+                    && tally.costs == other.costs
+                    && tally.cap == other.cap
+                    && tally.points.len() == other.points.len(),
+                "Vote and Tally dimensions mismatch"
+            );
+            let mut merged = tally.clone();
+            merged.ballots = merged
+                .ballots
+                .checked_add(other.ballots)
+                .ok_or(ArithmeticError::Overflow)?;
+            for (total, other) in merged.points.iter_mut().zip(other.points.iter()) {
+                *total = total.checked_add(*other).ok_or(ArithmeticError::Overflow)?;
+            }
+            *tally = merged;
+            Ok(())
+        }
+
+        fn validate_tally(config: &PollConfig, tally: &Tally) -> DispatchResult {
+            let expected = Self::new_tally(config)?;
+            ensure!(
+                expected.allocation == tally.allocation
+                    && expected.costs == tally.costs
+                    && expected.cap == tally.cap
+                    && expected.points.len() == tally.points.len(),
+                "Tally does not match budget configuration"
+            );
+            let total = tally
+                .points
+                .iter()
+                .try_fold(0u64, |sum, p| sum.checked_add(*p))
+                .ok_or(ArithmeticError::Overflow)?;
+            ensure!(
+                total == tally.allocation as u64 * tally.ballots as u64,
+                "Points do not match the number of ballots"
+            );
+            Ok(())
+        }
+
+        fn finalize(_config: &PollConfig, tally: &mut Tally) -> Result<Outcome, DispatchError> {
+            ensure!(
+                tally.points.len() == tally.costs.len(),
+                "Vote and Tally dimensions mismatch"
+            );
+            tally.funded =
+                BoundedVec::truncate_from(select(&tally.points, &tally.costs, tally.cap));
+            Ok(Outcome::default())
+        }
+    }
+}
+
+// 多对象评价：一张选票为多位教师或多门课程分别评分，计票结果按对象分开
+pub mod multi_subject_voting {
+    use super::*;
+    use crate::evaluative_voting;
+    use scale_info::prelude::vec::Vec;
+
+    /// 对一个对象的评分
+    #[derive(
+        Encode,
+        Decode,
+        Clone,
+        PartialEq,
+        Eq,
+        RuntimeDebug,
+        TypeInfo,
+        MaxEncodedLen,
+        DecodeWithMemTracking,
+    )]
+    pub struct SubjectScores {
+        pub subject: SubjectId,
+        pub scores: evaluative_voting::Vote,
+    }
+
+    /// 每个对象一组评分，可以只评价其中一部分对象，但同一对象只能出现一次
+    pub type Vote = BoundedVec<SubjectScores, ConstU32<MAX_SUBJECTS>>;
+
+    /// 一个对象的计票结果
+    #[derive(
+        Encode,
+        Decode,
+        Clone,
+        PartialEq,
+        Eq,
+        RuntimeDebug,
+        TypeInfo,
+        MaxEncodedLen,
+        DecodeWithMemTracking,
+    )]
+    pub struct SubjectTally {
+        pub subject: SubjectId,
+        pub stats: evaluative_voting::Tally,
+    }
+
+    /// 按投票配置中的对象顺序排列
+    pub type Tally = BoundedVec<SubjectTally, ConstU32<MAX_SUBJECTS>>;
+
+    pub struct TallyHandler;
+    impl TallyLogic<Vote, Tally> for TallyHandler {
+        fn new_tally(config: &PollConfig) -> Result<Tally, DispatchError> {
+            let subjects = &config.subjects;
+            ensure!(!subjects.is_empty(), "Subjects required");
+            ensure!(
+                subjects
+                    .iter()
+                    .enumerate()
+                    .all(|(i, subject)| !subjects[..i].contains(subject)),
+                "Duplicate subject"
+            );
+            let tally: Vec<SubjectTally> = subjects
+                .iter()
+                .map(|subject| SubjectTally {
+                    subject: *subject,
+                    stats: Default::default(),
+                })
+                .collect();
+            Ok(BoundedVec::truncate_from(tally))
+        }
+
+        fn validate(config: &PollConfig, vote: &Vote) -> DispatchResult {
+            for (i, block) in vote.iter().enumerate() {
+                ensure!(
+                    vote[..i].iter().all(|b| b.subject != block.subject),
+                    "Duplicate subject"
+                );
+                ensure!(config.subjects.contains(&block.subject), "Unknown subject");
+                <evaluative_voting::TallyHandler as TallyLogic<_, _>>::validate(
+                    config,
+                    &block.scores,
+                )?;
+            }
+            Ok(())
+        }
+
+        fn update_tally(config: &PollConfig, vote: &Vote, tally: &mut Tally) -> DispatchResult {
+            Self::validate(config, vote)?;
+            let mut updated = tally.clone();
+            for block in vote.iter() {
+                let entry = updated
+                    .iter_mut()
+                    .find(|entry| entry.subject == block.subject)
+                    .ok_or("Unknown subject")?;
+                <evaluative_voting::TallyHandler as TallyLogic<_, _>>::update_tally(
+                    config,
+                    &block.scores,
+                    &mut entry.stats,
+                )?;
+            }
+            *tally = updated;
+            Ok(())
+        }
+
+        fn merge(config: &PollConfig, tally: &mut Tally, other: &Tally) -> DispatchResult {
+            ensure!(
+                tally.len() == other.len()
+                    && tally
+                        .iter()
+                        .zip(other.iter())
+                        .all(|(entry, other)| entry.subject == other.subject),
+                "Tally does not match poll subjects"
+            );
+            let mut merged = tally.clone();
+            for (entry, other) in merged.iter_mut().zip(other.iter()) {
+                <evaluative_voting::TallyHandler as TallyLogic<_, _>>::merge(
+                    config,
+                    &mut entry.stats,
+                    &other.stats,
+                )?;
+            }
+            *tally = merged;
+            Ok(())
+        }
+
+        fn validate_tally(config: &PollConfig, tally: &Tally) -> DispatchResult {
+            ensure!(
+                tally.len() == config.subjects.len()
+                    && tally
+                        .iter()
+                        .zip(config.subjects.iter())
+                        .all(|(entry, subject)| entry.subject == *subject),
+                "Tally does not match poll subjects"
+            );
+            Ok(())
+        }
+
+        fn finalize(config: &PollConfig, tally: &mut Tally) -> Result<Outcome, DispatchError> {
+            for entry in tally.iter_mut() {
+                <evaluative_voting::TallyHandler as TallyLogic<_, _>>::finalize(
+                    config,
+                    &mut entry.stats,
+                )?;
+            }
+            Ok(Outcome::default())
+        }
     }
 }
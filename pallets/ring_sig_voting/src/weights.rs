@@ -1,14 +1,18 @@
-
-//! Autogenerated weights for `ring_sig_voting`
+//! Autogenerated weights for the `RingSigVoting` instance of `ring_sig_voting` in `parachain-template-runtime`
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 51.0.0
-//! DATE: 2025-11-26, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! GENERATED WITH THE PALLET BENCHMARKING PROCEDURE OF THE SUBSTRATE BENCHMARK CLI VERSION 47.0.0
+//! DATE: 2026-10-19, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `DESKTOP-EGPK2MH`, CPU: `12th Gen Intel(R) Core(TM) i7-12700H`
-//! WASM-EXECUTION: `Compiled`, CHAIN: `None`, DB CACHE: `1024`
+//! HOSTNAME: `vm`, CPU: `Intel(R) Xeon(R) Processor`
+//! WASM-EXECUTION: `Native`, CHAIN: `None`, DB CACHE: `1024`
+//!
+//! NOTE: no wasm build of the runtime was available, so the runtime API was dispatched natively.
+//! Steps, repeats, storage tracking, proof recording, analysis and the template are those of the
+//! CLI. Native code usually runs faster than compiled wasm; rerun the command below against the
+//! wasm runtime on reference hardware before relying on the execution times.
 
-// Executed Command:
-// /home/sqfzy/.cargo/bin/frame-omni-bencher
+// Command:
+// frame-omni-bencher
 // v1
 // benchmark
 // pallet
@@ -17,7 +21,7 @@
 // --pallet
 // ring_sig_voting
 // --extrinsic
-// 
+// *
 // --template
 // ./pallets/ring_sig_voting/frame-weight-template.hbs
 // --output
@@ -39,6 +43,17 @@ pub trait WeightInfo {
 	fn create_poll() -> Weight;
 	fn close_poll() -> Weight;
 	fn anonymous_vote() -> Weight;
+	fn publish_comments() -> Weight;
+	fn tally_batch(n: u32, ) -> Weight;
+	fn create_topic() -> Weight;
+	fn post_message(r: u32, ) -> Weight;
+	fn create_petition() -> Weight;
+	fn sign_petition(r: u32, ) -> Weight;
+	fn set_committee_key() -> Weight;
+	fn submit_report(r: u32, ) -> Weight;
+	fn acknowledge_report() -> Weight;
+	fn close_legacy_poll(n: u32, ) -> Weight;
+	fn migrate_legacy_ballots(n: u32, ) -> Weight;
 }
 
 /// Weights for `ring_sig_voting` using the Substrate node and recommended hardware.
@@ -52,8 +67,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `76`
 		//  Estimated: `1493`
-		// Minimum execution time: 22_394_000 picoseconds.
-		Weight::from_parts(26_341_000, 1493)
+		// Minimum execution time: 20_702_000 picoseconds.
+		Weight::from_parts(32_267_000, 1493)
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
@@ -62,58 +77,298 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `RingSigVoting::PollCount` (r:1 w:1)
 	/// Proof: `RingSigVoting::PollCount` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	/// Storage: `RingSigVoting::PollVotes` (r:0 w:1)
-	/// Proof: `RingSigVoting::PollVotes` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
-	/// Storage: `RingSigVoting::EncryptedVotes` (r:0 w:1)
-	/// Proof: `RingSigVoting::EncryptedVotes` (`max_values`: None, `max_size`: Some(8503018), added: 8505493, mode: `MaxEncodedLen`)
+	/// Proof: `RingSigVoting::PollVotes` (`max_values`: None, `max_size`: Some(607), added: 3082, mode: `MaxEncodedLen`)
 	/// Storage: `RingSigVoting::PollRingId` (r:0 w:1)
 	/// Proof: `RingSigVoting::PollRingId` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
 	/// Storage: `RingSigVoting::Polls` (r:0 w:1)
-	/// Proof: `RingSigVoting::Polls` (`max_values`: None, `max_size`: Some(426), added: 2901, mode: `MaxEncodedLen`)
+	/// Proof: `RingSigVoting::Polls` (`max_values`: None, `max_size`: Some(780), added: 3255, mode: `MaxEncodedLen`)
 	fn create_poll() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `177`
 		//  Estimated: `7707`
-		// Minimum execution time: 26_845_000 picoseconds.
-		Weight::from_parts(31_972_000, 7707)
+		// Minimum execution time: 28_605_000 picoseconds.
+		Weight::from_parts(33_818_000, 7707)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: `RingSigVoting::Polls` (r:1 w:1)
-	/// Proof: `RingSigVoting::Polls` (`max_values`: None, `max_size`: Some(426), added: 2901, mode: `MaxEncodedLen`)
-	/// Storage: `RingSigVoting::PollVotes` (r:0 w:1)
-	/// Proof: `RingSigVoting::PollVotes` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
-	/// Storage: `RingSigVoting::PollMetadata` (r:0 w:1)
-	/// Proof: `RingSigVoting::PollMetadata` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
-	/// Storage: `RingSigVoting::PollRingId` (r:0 w:1)
-	/// Proof: `RingSigVoting::PollRingId` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Proof: `RingSigVoting::Polls` (`max_values`: None, `max_size`: Some(780), added: 3255, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::LegacyPolls` (r:1 w:0)
+	/// Proof: `RingSigVoting::LegacyPolls` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::BallotCounts` (r:1 w:0)
+	/// Proof: `RingSigVoting::BallotCounts` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::TallyCursors` (r:0 w:1)
+	/// Proof: `RingSigVoting::TallyCursors` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
 	fn close_poll() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `403`
-		//  Estimated: `3891`
-		// Minimum execution time: 131_009_000 picoseconds.
-		Weight::from_parts(138_399_000, 3891)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+		//  Measured:  `430`
+		//  Estimated: `4245`
+		// Minimum execution time: 70_594_000 picoseconds.
+		Weight::from_parts(76_802_000, 4245)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `RingSigVoting::Polls` (r:1 w:0)
-	/// Proof: `RingSigVoting::Polls` (`max_values`: None, `max_size`: Some(426), added: 2901, mode: `MaxEncodedLen`)
+	/// Proof: `RingSigVoting::Polls` (`max_values`: None, `max_size`: Some(780), added: 3255, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::LegacyPolls` (r:1 w:0)
+	/// Proof: `RingSigVoting::LegacyPolls` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
 	/// Storage: `RingSigVoting::PollRingId` (r:1 w:0)
 	/// Proof: `RingSigVoting::PollRingId` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
 	/// Storage: `RingSigVoting::RingGroups` (r:1 w:0)
 	/// Proof: `RingSigVoting::RingGroups` (`max_values`: None, `max_size`: Some(4242), added: 6717, mode: `MaxEncodedLen`)
 	/// Storage: `RingSigVoting::UsedKeyImages` (r:1 w:1)
 	/// Proof: `RingSigVoting::UsedKeyImages` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
-	/// Storage: `RingSigVoting::EncryptedVotes` (r:1 w:1)
-	/// Proof: `RingSigVoting::EncryptedVotes` (`max_values`: None, `max_size`: Some(8503018), added: 8505493, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::BallotCounts` (r:1 w:1)
+	/// Proof: `RingSigVoting::BallotCounts` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::EncryptedVotes` (r:0 w:1)
+	/// Proof: `RingSigVoting::EncryptedVotes` (`max_values`: None, `max_size`: Some(8531), added: 11006, mode: `MaxEncodedLen`)
 	fn anonymous_vote() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `4653`
-		//  Estimated: `8506483`
-		// Minimum execution time: 172_131_194_000 picoseconds.
-		Weight::from_parts(175_714_502_000, 8506483)
+		//  Measured:  `4639`
+		//  Estimated: `7707`
+		// Minimum execution time: 138_375_517_000 picoseconds.
+		Weight::from_parts(160_886_160_000, 7707)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `RingSigVoting::Polls` (r:1 w:0)
+	/// Proof: `RingSigVoting::Polls` (`max_values`: None, `max_size`: Some(780), added: 3255, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::PollComments` (r:1 w:1)
+	/// Proof: `RingSigVoting::PollComments` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::PollOutcomes` (r:1 w:0)
+	/// Proof: `RingSigVoting::PollOutcomes` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::StatusFor` (r:1 w:0)
+	/// Proof: `Preimage::StatusFor` (`max_values`: None, `max_size`: Some(91), added: 2566, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::RequestStatusFor` (r:1 w:0)
+	/// Proof: `Preimage::RequestStatusFor` (`max_values`: None, `max_size`: Some(91), added: 2566, mode: `MaxEncodedLen`)
+	fn publish_comments() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `583`
+		//  Estimated: `4245`
+		// Minimum execution time: 57_716_000 picoseconds.
+		Weight::from_parts(89_348_000, 4245)
 			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `RingSigVoting::Polls` (r:1 w:1)
+	/// Proof: `RingSigVoting::Polls` (`max_values`: None, `max_size`: Some(780), added: 3255, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::BallotCounts` (r:1 w:0)
+	/// Proof: `RingSigVoting::BallotCounts` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::TallyCursors` (r:1 w:1)
+	/// Proof: `RingSigVoting::TallyCursors` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::PollVotes` (r:1 w:1)
+	/// Proof: `RingSigVoting::PollVotes` (`max_values`: None, `max_size`: Some(607), added: 3082, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::SpoiledBallots` (r:1 w:0)
+	/// Proof: `RingSigVoting::SpoiledBallots` (`max_values`: None, `max_size`: Some(5018), added: 7493, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::EncryptedVotes` (r:999 w:0)
+	/// Proof: `RingSigVoting::EncryptedVotes` (`max_values`: None, `max_size`: Some(8531), added: 11006, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::PollRingId` (r:1 w:1)
+	/// Proof: `RingSigVoting::PollRingId` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::RingGroups` (r:1 w:0)
+	/// Proof: `RingSigVoting::RingGroups` (`max_values`: None, `max_size`: Some(4242), added: 6717, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::PollProposals` (r:1 w:0)
+	/// Proof: `RingSigVoting::PollProposals` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::PollMetadata` (r:0 w:1)
+	/// Proof: `RingSigVoting::PollMetadata` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::PollOutcomes` (r:0 w:1)
+	/// Proof: `RingSigVoting::PollOutcomes` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 1000]`.
+	fn tally_batch(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4855 + n * (8522 ±0)`
+		//  Estimated: `8483 + n * (11006 ±0)`
+		// Minimum execution time: 292_330_000 picoseconds.
+		Weight::from_parts(653_496_332, 8483)
+			// Standard Error: 1_195_972
+			.saturating_add(Weight::from_parts(166_759_195, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(Weight::from_parts(0, 11006).saturating_mul(n.into()))
+	}
+	/// Storage: `RingSigVoting::RingGroups` (r:1 w:0)
+	/// Proof: `RingSigVoting::RingGroups` (`max_values`: None, `max_size`: Some(4242), added: 6717, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::TopicCount` (r:1 w:1)
+	/// Proof: `RingSigVoting::TopicCount` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::Topics` (r:0 w:1)
+	/// Proof: `RingSigVoting::Topics` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	fn create_topic() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `177`
+		//  Estimated: `7707`
+		// Minimum execution time: 33_815_000 picoseconds.
+		Weight::from_parts(35_767_000, 7707)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
+	/// Storage: `RingSigVoting::Topics` (r:1 w:1)
+	/// Proof: `RingSigVoting::Topics` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::RingGroups` (r:1 w:0)
+	/// Proof: `RingSigVoting::RingGroups` (`max_values`: None, `max_size`: Some(4242), added: 6717, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::PostWindows` (r:1 w:1)
+	/// Proof: `RingSigVoting::PostWindows` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[2, 128]`.
+	fn post_message(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `267 + r * (33 ±0)`
+		//  Estimated: `7707`
+		// Minimum execution time: 363_389_000 picoseconds.
+		Weight::from_parts(112_617_859, 7707)
+			// Standard Error: 1_682_507
+			.saturating_add(Weight::from_parts(194_152_137, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Sudo::Key` (r:1 w:0)
+	/// Proof: `Sudo::Key` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::RingGroups` (r:1 w:0)
+	/// Proof: `RingSigVoting::RingGroups` (`max_values`: None, `max_size`: Some(4242), added: 6717, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::StatusFor` (r:1 w:0)
+	/// Proof: `Preimage::StatusFor` (`max_values`: None, `max_size`: Some(91), added: 2566, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::RequestStatusFor` (r:1 w:1)
+	/// Proof: `Preimage::RequestStatusFor` (`max_values`: None, `max_size`: Some(91), added: 2566, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::PetitionCount` (r:1 w:1)
+	/// Proof: `RingSigVoting::PetitionCount` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::Petitions` (r:0 w:1)
+	/// Proof: `RingSigVoting::Petitions` (`max_values`: None, `max_size`: Some(327), added: 2802, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::PetitionProposals` (r:0 w:1)
+	/// Proof: `RingSigVoting::PetitionProposals` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	fn create_petition() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4538`
+		//  Estimated: `7707`
+		// Minimum execution time: 40_991_000 picoseconds.
+		Weight::from_parts(80_077_000, 7707)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `RingSigVoting::Petitions` (r:1 w:1)
+	/// Proof: `RingSigVoting::Petitions` (`max_values`: None, `max_size`: Some(327), added: 2802, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::RingGroups` (r:1 w:0)
+	/// Proof: `RingSigVoting::RingGroups` (`max_values`: None, `max_size`: Some(4242), added: 6717, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::PetitionSigners` (r:1 w:1)
+	/// Proof: `RingSigVoting::PetitionSigners` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::PetitionProposals` (r:1 w:1)
+	/// Proof: `RingSigVoting::PetitionProposals` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Agenda` (r:1 w:1)
+	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(398862), added: 401337, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::StatusFor` (r:1 w:0)
+	/// Proof: `Preimage::StatusFor` (`max_values`: None, `max_size`: Some(91), added: 2566, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::RequestStatusFor` (r:1 w:1)
+	/// Proof: `Preimage::RequestStatusFor` (`max_values`: None, `max_size`: Some(91), added: 2566, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[2, 128]`.
+	fn sign_petition(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `461 + r * (33 ±0)`
+		//  Estimated: `402327`
+		// Minimum execution time: 714_148_000 picoseconds.
+		Weight::from_parts(742_366_000, 402327)
+			// Standard Error: 9_739_439
+			.saturating_add(Weight::from_parts(1_005_669_524, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `RingSigVoting::CommitteeKey` (r:0 w:1)
+	/// Proof: `RingSigVoting::CommitteeKey` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	fn set_committee_key() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 15_300_000 picoseconds.
+		Weight::from_parts(18_749_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `RingSigVoting::CommitteeKey` (r:1 w:0)
+	/// Proof: `RingSigVoting::CommitteeKey` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::RingGroups` (r:1 w:0)
+	/// Proof: `RingSigVoting::RingGroups` (`max_values`: None, `max_size`: Some(4242), added: 6717, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::UsedReplyKeys` (r:1 w:1)
+	/// Proof: `RingSigVoting::UsedReplyKeys` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::OpenReports` (r:1 w:1)
+	/// Proof: `RingSigVoting::OpenReports` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::ReportCount` (r:1 w:1)
+	/// Proof: `RingSigVoting::ReportCount` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::Reports` (r:0 w:1)
+	/// Proof: `RingSigVoting::Reports` (`max_values`: None, `max_size`: Some(4270), added: 6745, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[2, 128]`.
+	fn submit_report(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `241 + r * (33 ±0)`
+		//  Estimated: `7707`
+		// Minimum execution time: 463_328_000 picoseconds.
+		Weight::from_parts(476_901_000, 7707)
+			// Standard Error: 1_165_425
+			.saturating_add(Weight::from_parts(199_716_430, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `RingSigVoting::Reports` (r:1 w:1)
+	/// Proof: `RingSigVoting::Reports` (`max_values`: None, `max_size`: Some(4270), added: 6745, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::OpenReports` (r:1 w:1)
+	/// Proof: `RingSigVoting::OpenReports` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	fn acknowledge_report() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4415`
+		//  Estimated: `7735`
+		// Minimum execution time: 30_526_000 picoseconds.
+		Weight::from_parts(36_487_000, 7735)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `RingSigVoting::Polls` (r:1 w:1)
+	/// Proof: `RingSigVoting::Polls` (`max_values`: None, `max_size`: Some(780), added: 3255, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::LegacyPolls` (r:1 w:1)
+	/// Proof: `RingSigVoting::LegacyPolls` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::BallotCounts` (r:1 w:0)
+	/// Proof: `RingSigVoting::BallotCounts` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::EncryptedVotes` (r:1 w:1001)
+	/// Proof: `RingSigVoting::EncryptedVotes` (`max_values`: None, `max_size`: Some(8531), added: 11006, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::PollRingId` (r:1 w:1)
+	/// Proof: `RingSigVoting::PollRingId` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::RingGroups` (r:1 w:0)
+	/// Proof: `RingSigVoting::RingGroups` (`max_values`: None, `max_size`: Some(4242), added: 6717, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::SpoiledBallots` (r:1 w:0)
+	/// Proof: `RingSigVoting::SpoiledBallots` (`max_values`: None, `max_size`: Some(5018), added: 7493, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::PollProposals` (r:1 w:0)
+	/// Proof: `RingSigVoting::PollProposals` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::PollVotes` (r:0 w:1)
+	/// Proof: `RingSigVoting::PollVotes` (`max_values`: None, `max_size`: Some(607), added: 3082, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::PollMetadata` (r:0 w:1)
+	/// Proof: `RingSigVoting::PollMetadata` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::TallyCursors` (r:0 w:1)
+	/// Proof: `RingSigVoting::TallyCursors` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::PollOutcomes` (r:0 w:1)
+	/// Proof: `RingSigVoting::PollOutcomes` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 1000]`.
+	fn close_legacy_poll(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4762 + n * (8503 ±0)`
+		//  Estimated: `11996`
+		// Minimum execution time: 204_561_000 picoseconds.
+		Weight::from_parts(2_621_130_617, 11996)
+			// Standard Error: 611_740
+			.saturating_add(Weight::from_parts(33_776_106, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `RingSigVoting::LegacyPolls` (r:1 w:0)
+	/// Proof: `RingSigVoting::LegacyPolls` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::EncryptedVotes` (r:1 w:1000)
+	/// Proof: `RingSigVoting::EncryptedVotes` (`max_values`: None, `max_size`: Some(8531), added: 11006, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 1000]`.
+	fn migrate_legacy_ballots(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `124 + n * (8503 ±0)`
+		//  Estimated: `11996`
+		// Minimum execution time: 40_311_000 picoseconds.
+		Weight::from_parts(41_403_000, 11996)
+			// Standard Error: 102_363
+			.saturating_add(Weight::from_parts(30_170_740, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -126,8 +381,8 @@ impl WeightInfo for () {
 		// Proof Size summary in bytes:
 		//  Measured:  `76`
 		//  Estimated: `1493`
-		// Minimum execution time: 22_394_000 picoseconds.
-		Weight::from_parts(26_341_000, 1493)
+		// Minimum execution time: 20_702_000 picoseconds.
+		Weight::from_parts(32_267_000, 1493)
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
@@ -136,56 +391,296 @@ impl WeightInfo for () {
 	/// Storage: `RingSigVoting::PollCount` (r:1 w:1)
 	/// Proof: `RingSigVoting::PollCount` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	/// Storage: `RingSigVoting::PollVotes` (r:0 w:1)
-	/// Proof: `RingSigVoting::PollVotes` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
-	/// Storage: `RingSigVoting::EncryptedVotes` (r:0 w:1)
-	/// Proof: `RingSigVoting::EncryptedVotes` (`max_values`: None, `max_size`: Some(8503018), added: 8505493, mode: `MaxEncodedLen`)
+	/// Proof: `RingSigVoting::PollVotes` (`max_values`: None, `max_size`: Some(607), added: 3082, mode: `MaxEncodedLen`)
 	/// Storage: `RingSigVoting::PollRingId` (r:0 w:1)
 	/// Proof: `RingSigVoting::PollRingId` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
 	/// Storage: `RingSigVoting::Polls` (r:0 w:1)
-	/// Proof: `RingSigVoting::Polls` (`max_values`: None, `max_size`: Some(426), added: 2901, mode: `MaxEncodedLen`)
+	/// Proof: `RingSigVoting::Polls` (`max_values`: None, `max_size`: Some(780), added: 3255, mode: `MaxEncodedLen`)
 	fn create_poll() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `177`
 		//  Estimated: `7707`
-		// Minimum execution time: 26_845_000 picoseconds.
-		Weight::from_parts(31_972_000, 7707)
+		// Minimum execution time: 28_605_000 picoseconds.
+		Weight::from_parts(33_818_000, 7707)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: `RingSigVoting::Polls` (r:1 w:1)
-	/// Proof: `RingSigVoting::Polls` (`max_values`: None, `max_size`: Some(426), added: 2901, mode: `MaxEncodedLen`)
-	/// Storage: `RingSigVoting::PollVotes` (r:0 w:1)
-	/// Proof: `RingSigVoting::PollVotes` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
-	/// Storage: `RingSigVoting::PollMetadata` (r:0 w:1)
-	/// Proof: `RingSigVoting::PollMetadata` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
-	/// Storage: `RingSigVoting::PollRingId` (r:0 w:1)
-	/// Proof: `RingSigVoting::PollRingId` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Proof: `RingSigVoting::Polls` (`max_values`: None, `max_size`: Some(780), added: 3255, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::LegacyPolls` (r:1 w:0)
+	/// Proof: `RingSigVoting::LegacyPolls` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::BallotCounts` (r:1 w:0)
+	/// Proof: `RingSigVoting::BallotCounts` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::TallyCursors` (r:0 w:1)
+	/// Proof: `RingSigVoting::TallyCursors` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
 	fn close_poll() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `403`
-		//  Estimated: `3891`
-		// Minimum execution time: 131_009_000 picoseconds.
-		Weight::from_parts(138_399_000, 3891)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+		//  Measured:  `430`
+		//  Estimated: `4245`
+		// Minimum execution time: 70_594_000 picoseconds.
+		Weight::from_parts(76_802_000, 4245)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `RingSigVoting::Polls` (r:1 w:0)
-	/// Proof: `RingSigVoting::Polls` (`max_values`: None, `max_size`: Some(426), added: 2901, mode: `MaxEncodedLen`)
+	/// Proof: `RingSigVoting::Polls` (`max_values`: None, `max_size`: Some(780), added: 3255, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::LegacyPolls` (r:1 w:0)
+	/// Proof: `RingSigVoting::LegacyPolls` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
 	/// Storage: `RingSigVoting::PollRingId` (r:1 w:0)
 	/// Proof: `RingSigVoting::PollRingId` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
 	/// Storage: `RingSigVoting::RingGroups` (r:1 w:0)
 	/// Proof: `RingSigVoting::RingGroups` (`max_values`: None, `max_size`: Some(4242), added: 6717, mode: `MaxEncodedLen`)
 	/// Storage: `RingSigVoting::UsedKeyImages` (r:1 w:1)
 	/// Proof: `RingSigVoting::UsedKeyImages` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
-	/// Storage: `RingSigVoting::EncryptedVotes` (r:1 w:1)
-	/// Proof: `RingSigVoting::EncryptedVotes` (`max_values`: None, `max_size`: Some(8503018), added: 8505493, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::BallotCounts` (r:1 w:1)
+	/// Proof: `RingSigVoting::BallotCounts` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::EncryptedVotes` (r:0 w:1)
+	/// Proof: `RingSigVoting::EncryptedVotes` (`max_values`: None, `max_size`: Some(8531), added: 11006, mode: `MaxEncodedLen`)
 	fn anonymous_vote() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `4653`
-		//  Estimated: `8506483`
-		// Minimum execution time: 172_131_194_000 picoseconds.
-		Weight::from_parts(175_714_502_000, 8506483)
+		//  Measured:  `4639`
+		//  Estimated: `7707`
+		// Minimum execution time: 138_375_517_000 picoseconds.
+		Weight::from_parts(160_886_160_000, 7707)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `RingSigVoting::Polls` (r:1 w:0)
+	/// Proof: `RingSigVoting::Polls` (`max_values`: None, `max_size`: Some(780), added: 3255, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::PollComments` (r:1 w:1)
+	/// Proof: `RingSigVoting::PollComments` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::PollOutcomes` (r:1 w:0)
+	/// Proof: `RingSigVoting::PollOutcomes` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::StatusFor` (r:1 w:0)
+	/// Proof: `Preimage::StatusFor` (`max_values`: None, `max_size`: Some(91), added: 2566, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::RequestStatusFor` (r:1 w:0)
+	/// Proof: `Preimage::RequestStatusFor` (`max_values`: None, `max_size`: Some(91), added: 2566, mode: `MaxEncodedLen`)
+	fn publish_comments() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `583`
+		//  Estimated: `4245`
+		// Minimum execution time: 57_716_000 picoseconds.
+		Weight::from_parts(89_348_000, 4245)
 			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `RingSigVoting::Polls` (r:1 w:1)
+	/// Proof: `RingSigVoting::Polls` (`max_values`: None, `max_size`: Some(780), added: 3255, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::BallotCounts` (r:1 w:0)
+	/// Proof: `RingSigVoting::BallotCounts` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::TallyCursors` (r:1 w:1)
+	/// Proof: `RingSigVoting::TallyCursors` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::PollVotes` (r:1 w:1)
+	/// Proof: `RingSigVoting::PollVotes` (`max_values`: None, `max_size`: Some(607), added: 3082, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::SpoiledBallots` (r:1 w:0)
+	/// Proof: `RingSigVoting::SpoiledBallots` (`max_values`: None, `max_size`: Some(5018), added: 7493, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::EncryptedVotes` (r:999 w:0)
+	/// Proof: `RingSigVoting::EncryptedVotes` (`max_values`: None, `max_size`: Some(8531), added: 11006, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::PollRingId` (r:1 w:1)
+	/// Proof: `RingSigVoting::PollRingId` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::RingGroups` (r:1 w:0)
+	/// Proof: `RingSigVoting::RingGroups` (`max_values`: None, `max_size`: Some(4242), added: 6717, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::PollProposals` (r:1 w:0)
+	/// Proof: `RingSigVoting::PollProposals` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::PollMetadata` (r:0 w:1)
+	/// Proof: `RingSigVoting::PollMetadata` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::PollOutcomes` (r:0 w:1)
+	/// Proof: `RingSigVoting::PollOutcomes` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 1000]`.
+	fn tally_batch(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4855 + n * (8522 ±0)`
+		//  Estimated: `8483 + n * (11006 ±0)`
+		// Minimum execution time: 292_330_000 picoseconds.
+		Weight::from_parts(653_496_332, 8483)
+			// Standard Error: 1_195_972
+			.saturating_add(Weight::from_parts(166_759_195, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(Weight::from_parts(0, 11006).saturating_mul(n.into()))
+	}
+	/// Storage: `RingSigVoting::RingGroups` (r:1 w:0)
+	/// Proof: `RingSigVoting::RingGroups` (`max_values`: None, `max_size`: Some(4242), added: 6717, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::TopicCount` (r:1 w:1)
+	/// Proof: `RingSigVoting::TopicCount` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::Topics` (r:0 w:1)
+	/// Proof: `RingSigVoting::Topics` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	fn create_topic() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `177`
+		//  Estimated: `7707`
+		// Minimum execution time: 33_815_000 picoseconds.
+		Weight::from_parts(35_767_000, 7707)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `RingSigVoting::Topics` (r:1 w:1)
+	/// Proof: `RingSigVoting::Topics` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::RingGroups` (r:1 w:0)
+	/// Proof: `RingSigVoting::RingGroups` (`max_values`: None, `max_size`: Some(4242), added: 6717, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::PostWindows` (r:1 w:1)
+	/// Proof: `RingSigVoting::PostWindows` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[2, 128]`.
+	fn post_message(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `267 + r * (33 ±0)`
+		//  Estimated: `7707`
+		// Minimum execution time: 363_389_000 picoseconds.
+		Weight::from_parts(112_617_859, 7707)
+			// Standard Error: 1_682_507
+			.saturating_add(Weight::from_parts(194_152_137, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
+	/// Storage: `Sudo::Key` (r:1 w:0)
+	/// Proof: `Sudo::Key` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::RingGroups` (r:1 w:0)
+	/// Proof: `RingSigVoting::RingGroups` (`max_values`: None, `max_size`: Some(4242), added: 6717, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::StatusFor` (r:1 w:0)
+	/// Proof: `Preimage::StatusFor` (`max_values`: None, `max_size`: Some(91), added: 2566, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::RequestStatusFor` (r:1 w:1)
+	/// Proof: `Preimage::RequestStatusFor` (`max_values`: None, `max_size`: Some(91), added: 2566, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::PetitionCount` (r:1 w:1)
+	/// Proof: `RingSigVoting::PetitionCount` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::Petitions` (r:0 w:1)
+	/// Proof: `RingSigVoting::Petitions` (`max_values`: None, `max_size`: Some(327), added: 2802, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::PetitionProposals` (r:0 w:1)
+	/// Proof: `RingSigVoting::PetitionProposals` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	fn create_petition() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4538`
+		//  Estimated: `7707`
+		// Minimum execution time: 40_991_000 picoseconds.
+		Weight::from_parts(80_077_000, 7707)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `RingSigVoting::Petitions` (r:1 w:1)
+	/// Proof: `RingSigVoting::Petitions` (`max_values`: None, `max_size`: Some(327), added: 2802, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::RingGroups` (r:1 w:0)
+	/// Proof: `RingSigVoting::RingGroups` (`max_values`: None, `max_size`: Some(4242), added: 6717, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::PetitionSigners` (r:1 w:1)
+	/// Proof: `RingSigVoting::PetitionSigners` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::PetitionProposals` (r:1 w:1)
+	/// Proof: `RingSigVoting::PetitionProposals` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Agenda` (r:1 w:1)
+	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(398862), added: 401337, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::StatusFor` (r:1 w:0)
+	/// Proof: `Preimage::StatusFor` (`max_values`: None, `max_size`: Some(91), added: 2566, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::RequestStatusFor` (r:1 w:1)
+	/// Proof: `Preimage::RequestStatusFor` (`max_values`: None, `max_size`: Some(91), added: 2566, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[2, 128]`.
+	fn sign_petition(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `461 + r * (33 ±0)`
+		//  Estimated: `402327`
+		// Minimum execution time: 714_148_000 picoseconds.
+		Weight::from_parts(742_366_000, 402327)
+			// Standard Error: 9_739_439
+			.saturating_add(Weight::from_parts(1_005_669_524, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `RingSigVoting::CommitteeKey` (r:0 w:1)
+	/// Proof: `RingSigVoting::CommitteeKey` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	fn set_committee_key() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 15_300_000 picoseconds.
+		Weight::from_parts(18_749_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `RingSigVoting::CommitteeKey` (r:1 w:0)
+	/// Proof: `RingSigVoting::CommitteeKey` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::RingGroups` (r:1 w:0)
+	/// Proof: `RingSigVoting::RingGroups` (`max_values`: None, `max_size`: Some(4242), added: 6717, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::UsedReplyKeys` (r:1 w:1)
+	/// Proof: `RingSigVoting::UsedReplyKeys` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::OpenReports` (r:1 w:1)
+	/// Proof: `RingSigVoting::OpenReports` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::ReportCount` (r:1 w:1)
+	/// Proof: `RingSigVoting::ReportCount` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::Reports` (r:0 w:1)
+	/// Proof: `RingSigVoting::Reports` (`max_values`: None, `max_size`: Some(4270), added: 6745, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[2, 128]`.
+	fn submit_report(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `241 + r * (33 ±0)`
+		//  Estimated: `7707`
+		// Minimum execution time: 463_328_000 picoseconds.
+		Weight::from_parts(476_901_000, 7707)
+			// Standard Error: 1_165_425
+			.saturating_add(Weight::from_parts(199_716_430, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `RingSigVoting::Reports` (r:1 w:1)
+	/// Proof: `RingSigVoting::Reports` (`max_values`: None, `max_size`: Some(4270), added: 6745, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::OpenReports` (r:1 w:1)
+	/// Proof: `RingSigVoting::OpenReports` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	fn acknowledge_report() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4415`
+		//  Estimated: `7735`
+		// Minimum execution time: 30_526_000 picoseconds.
+		Weight::from_parts(36_487_000, 7735)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `RingSigVoting::Polls` (r:1 w:1)
+	/// Proof: `RingSigVoting::Polls` (`max_values`: None, `max_size`: Some(780), added: 3255, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::LegacyPolls` (r:1 w:1)
+	/// Proof: `RingSigVoting::LegacyPolls` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::BallotCounts` (r:1 w:0)
+	/// Proof: `RingSigVoting::BallotCounts` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::EncryptedVotes` (r:1 w:1001)
+	/// Proof: `RingSigVoting::EncryptedVotes` (`max_values`: None, `max_size`: Some(8531), added: 11006, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::PollRingId` (r:1 w:1)
+	/// Proof: `RingSigVoting::PollRingId` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::RingGroups` (r:1 w:0)
+	/// Proof: `RingSigVoting::RingGroups` (`max_values`: None, `max_size`: Some(4242), added: 6717, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::SpoiledBallots` (r:1 w:0)
+	/// Proof: `RingSigVoting::SpoiledBallots` (`max_values`: None, `max_size`: Some(5018), added: 7493, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::PollProposals` (r:1 w:0)
+	/// Proof: `RingSigVoting::PollProposals` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::PollVotes` (r:0 w:1)
+	/// Proof: `RingSigVoting::PollVotes` (`max_values`: None, `max_size`: Some(607), added: 3082, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::PollMetadata` (r:0 w:1)
+	/// Proof: `RingSigVoting::PollMetadata` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::TallyCursors` (r:0 w:1)
+	/// Proof: `RingSigVoting::TallyCursors` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::PollOutcomes` (r:0 w:1)
+	/// Proof: `RingSigVoting::PollOutcomes` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 1000]`.
+	fn close_legacy_poll(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4762 + n * (8503 ±0)`
+		//  Estimated: `11996`
+		// Minimum execution time: 204_561_000 picoseconds.
+		Weight::from_parts(2_621_130_617, 11996)
+			// Standard Error: 611_740
+			.saturating_add(Weight::from_parts(33_776_106, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `RingSigVoting::LegacyPolls` (r:1 w:0)
+	/// Proof: `RingSigVoting::LegacyPolls` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `RingSigVoting::EncryptedVotes` (r:1 w:1000)
+	/// Proof: `RingSigVoting::EncryptedVotes` (`max_values`: None, `max_size`: Some(8531), added: 11006, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 1000]`.
+	fn migrate_legacy_ballots(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `124 + n * (8503 ±0)`
+		//  Estimated: `11996`
+		// Minimum execution time: 40_311_000 picoseconds.
+		Weight::from_parts(41_403_000, 11996)
+			// Standard Error: 102_363
+			.saturating_add(Weight::from_parts(30_170_740, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
 }
@@ -28,28 +28,47 @@ use alloc::vec::Vec;
 
 use polkadot_sdk::*;
 
+use codec::{Decode, Encode};
 use frame_support::{
     genesis_builder_helper::{build_state, get_preset},
+    traits::PalletInfoAccess,
     weights::Weight,
 };
 use pallet_aura::Authorities;
+use scale_info::TypeInfo;
 use sp_api::impl_runtime_apis;
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_core::{crypto::KeyTypeId, OpaqueMetadata};
 use sp_runtime::{
     traits::Block as BlockT,
     transaction_validity::{TransactionSource, TransactionValidity},
-    ApplyExtrinsicResult,
+    ApplyExtrinsicResult, RuntimeDebug,
 };
 use sp_version::RuntimeVersion;
 
 // Local module imports
 use super::{
-    AccountId, Balance, Block, ConsensusHook, Executive, InherentDataExt, Nonce, ParachainSystem,
-    Runtime, RuntimeCall, RuntimeGenesisConfig, SessionKeys, System, TransactionPayment,
-    SLOT_DURATION, VERSION,
+    AccountId, Balance, Block, ConsensusHook, CourseEvaluation, Executive, InherentDataExt, Nonce,
+    ParachainSystem, RingSigVoting, Runtime, RuntimeCall, RuntimeGenesisConfig, SessionKeys,
+    SimpleVoting, Surveys, System, TransactionPayment, SLOT_DURATION, VERSION,
 };
 
+/// Tally returned by `RingSigVotingApi`, tagged with the voting instance it came from.
+#[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, RuntimeDebug)]
+pub enum RingSigVotingTally {
+    /// Tally of a `SimpleVoting` poll.
+    Simple(ring_sig_voting::simple_voting::Tally),
+    /// Tally of a `RingSigVoting` or `CourseEvaluation` poll.
+    Evaluative(ring_sig_voting::evaluative_voting::Tally),
+    /// Tally of a `Surveys` poll, one entry per question of the poll's schema.
+    Schema(ring_sig_voting::schema_voting::Tally),
+}
+
+/// Whether `pallet_index` is the index of pallet `P` in `construct_runtime`.
+fn is_pallet<P: PalletInfoAccess>(pallet_index: u8) -> bool {
+    P::index() == pallet_index as usize
+}
+
 // we move some impls outside so we can easily use them with `docify`.
 impl Runtime {
     #[docify::export]
@@ -196,6 +215,98 @@ impl_runtime_apis! {
         }
     }
 
+    impl ring_sig_voting::runtime_api::RingSigVotingApi<Block, RingSigVotingTally> for Runtime {
+        fn poll_tally(
+            pallet_index: u8,
+            poll_id: ring_sig_voting::PollId,
+        ) -> Option<RingSigVotingTally> {
+            if is_pallet::<RingSigVoting>(pallet_index) {
+                RingSigVoting::closed_tally(poll_id).map(RingSigVotingTally::Evaluative)
+            } else if is_pallet::<CourseEvaluation>(pallet_index) {
+                CourseEvaluation::closed_tally(poll_id).map(RingSigVotingTally::Evaluative)
+            } else if is_pallet::<SimpleVoting>(pallet_index) {
+                SimpleVoting::closed_tally(poll_id).map(RingSigVotingTally::Simple)
+            } else if is_pallet::<Surveys>(pallet_index) {
+                Surveys::closed_tally(poll_id).map(RingSigVotingTally::Schema)
+            } else {
+                None
+            }
+        }
+        fn poll_outcome(
+            pallet_index: u8,
+            poll_id: ring_sig_voting::PollId,
+        ) -> Option<ring_sig_voting::Outcome> {
+            if is_pallet::<RingSigVoting>(pallet_index) {
+                RingSigVoting::poll_outcomes(poll_id)
+            } else if is_pallet::<CourseEvaluation>(pallet_index) {
+                CourseEvaluation::poll_outcomes(poll_id)
+            } else if is_pallet::<SimpleVoting>(pallet_index) {
+                SimpleVoting::poll_outcomes(poll_id)
+            } else if is_pallet::<Surveys>(pallet_index) {
+                Surveys::poll_outcomes(poll_id)
+            } else {
+                None
+            }
+        }
+    }
+
+    impl ring_sig_voting::runtime_api::RingMembershipApi<Block> for Runtime {
+        fn verify_membership(
+            pallet_index: u8,
+            ring_id: ring_sig_voting::RingId,
+            domain: Vec<u8>,
+            nonce: Vec<u8>,
+            message: Vec<u8>,
+            challenge: sp_core::H256,
+            responses: Vec<sp_core::H256>,
+            key_image: sp_core::H256,
+        ) -> Option<sp_core::H256> {
+            if is_pallet::<RingSigVoting>(pallet_index) {
+                RingSigVoting::verify_membership(
+                    ring_id,
+                    &domain,
+                    &nonce,
+                    &message,
+                    challenge,
+                    responses,
+                    key_image,
+                )
+            } else if is_pallet::<CourseEvaluation>(pallet_index) {
+                CourseEvaluation::verify_membership(
+                    ring_id,
+                    &domain,
+                    &nonce,
+                    &message,
+                    challenge,
+                    responses,
+                    key_image,
+                )
+            } else if is_pallet::<SimpleVoting>(pallet_index) {
+                SimpleVoting::verify_membership(
+                    ring_id,
+                    &domain,
+                    &nonce,
+                    &message,
+                    challenge,
+                    responses,
+                    key_image,
+                )
+            } else if is_pallet::<Surveys>(pallet_index) {
+                Surveys::verify_membership(
+                    ring_id,
+                    &domain,
+                    &nonce,
+                    &message,
+                    challenge,
+                    responses,
+                    key_image,
+                )
+            } else {
+                None
+            }
+        }
+    }
+
     impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentCallApi<Block, Balance, RuntimeCall>
         for Runtime
     {
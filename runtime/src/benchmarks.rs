@@ -35,4 +35,7 @@ polkadot_sdk::frame_benchmarking::define_benchmarks!(
     [cumulus_pallet_xcmp_queue, XcmpQueue]
     [cumulus_pallet_weight_reclaim, WeightReclaim]
     [ring_sig_voting, RingSigVoting]
+    [ring_sig_voting, CourseEvaluation]
+    [ring_sig_voting, SimpleVoting]
+    [ring_sig_voting, Surveys]
 );
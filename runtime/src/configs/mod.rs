@@ -413,34 +413,225 @@ impl pallet_scheduler::Config for Runtime {
 //     >;
 // }
 
-#[cfg(feature = "runtime-benchmarks")]
-use ring_sig_voting::simple_voting::{Tally, TallyHandler, Vote};
-#[cfg(not(feature = "runtime-benchmarks"))]
-use ring_sig_voting::evaluative_voting ::{Tally, TallyHandler, Vote};
-
 parameter_types! {
 	pub const SubmissionDeposit: Balance = 10;
 	pub const ClosureIncentive: Balance = 1000;
+	pub const EnactmentDelay: BlockNumber = HOURS;
+	pub EnactmentOrigin: OriginCaller = frame_system::RawOrigin::Root.into();
+}
+
+/// `pallet_sudo` 不公开 sudo 账户的存储项，这里按其存储位置读取
+#[frame_support::storage_alias(pallet_name)]
+type Key = StorageValue<super::Sudo, AccountId, frame_support::pallet_prelude::OptionQuery>;
+
+/// 当前的 sudo 账户。附带调用会以 Root 执行，只有它可以把调用附在投票上
+pub struct SudoAccount;
+
+impl frame_support::traits::SortedMembers<AccountId> for SudoAccount {
+    fn sorted_members() -> alloc::vec::Vec<AccountId> {
+        Key::get().into_iter().collect()
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn add(who: &AccountId) {
+        Key::put(who);
+    }
 }
 
+// 评分投票：沿用升级前的位置和计票方式，已有投票的计票结果和选票仍按评分解读
 impl ring_sig_voting::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type Currency = Balances;
     type Preimages = pallet_preimage::Pallet<Self>;
+    type Time = pallet_timestamp::Pallet<Self>;
     type SubmissionDeposit = SubmissionDeposit;
     type CreatePollOrigin = frame_system::EnsureSigned<Self::AccountId>;
+    type ProposalOrigin = frame_system::EnsureSignedBy<SudoAccount, Self::AccountId>;
     type ClosePollOrigin = EnsureRoot<u64>;
     type RingAdminOrigin = frame_system::EnsureSigned<Self::AccountId>;
-    type Vote = Vote;
-    type Tally = Tally;
-    type TallyHandler = TallyHandler;
+    type ReportCommitteeOrigin = EnsureRoot<u64>;
+    type Scheduler = pallet_scheduler::Pallet<Self>;
+    type EnactmentOrigin = EnactmentOrigin;
+    type EnactmentDelay = EnactmentDelay;
+    type Vote = ring_sig_voting::evaluative_voting::Vote;
+    type Tally = ring_sig_voting::evaluative_voting::Tally;
+    type TallyHandler = ring_sig_voting::evaluative_voting::TallyHandler;
+    type BallotCipher = ring_sig_voting::ecies::Ecies;
     type MaxDescriptionLength = ConstU32<256>;
     type MaxMembersInRing = ConstU32<128>;
     type NumRingLayers = ConstU32<1>;
     type ClosureIncentive = ClosureIncentive;
     type MaxVoteSize = ConstU32<64>;
     type MaxVotesPerPoll = ConstU32<1000>;
+    // 与升级前一样，关闭投票时总是公开计票结果
+    type MinBallotsForBreakdown = ConstU32<0>;
+    type MaxMessageLength = ConstU32<512>;
+    type MaxReportSize = ConstU32<4096>;
+    type MaxOpenReports = ConstU32<3>;
     type WeightInfo = ring_sig_voting::weights::SubstrateWeight<Runtime>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = RingSigVotingBenchmarkHelper;
+}
+
+/// 基准测试用的选票：评分填满的评分选票、赞成票、评分和评论都填满的课程评价选票，
+/// 以及符合基准测试问卷的问卷选票
+#[cfg(feature = "runtime-benchmarks")]
+pub struct RingSigVotingBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl ring_sig_voting::BenchmarkHelper<ring_sig_voting::evaluative_voting::Vote>
+    for RingSigVotingBenchmarkHelper
+{
+    fn vote() -> ring_sig_voting::evaluative_voting::Vote {
+        use ring_sig_voting::evaluative_voting::Score;
+
+        frame_support::BoundedVec::truncate_from(alloc::vec![Score::Five; 10])
+    }
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl ring_sig_voting::BenchmarkHelper<ring_sig_voting::simple_voting::Vote>
+    for RingSigVotingBenchmarkHelper
+{
+    fn vote() -> ring_sig_voting::simple_voting::Vote {
+        ring_sig_voting::simple_voting::Vote::Yea
+    }
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl ring_sig_voting::BenchmarkHelper<ring_sig_voting::schema_voting::Vote>
+    for RingSigVotingBenchmarkHelper
+{
+    fn vote() -> ring_sig_voting::schema_voting::Vote {
+        // 对应 `ring_sig_voting::mock::gen_poll_config` 的单选题和数值题
+        frame_support::BoundedVec::truncate_from(alloc::vec![0, 1])
+    }
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl ring_sig_voting::BenchmarkHelper<ring_sig_voting::evaluative_voting::Ballot>
+    for RingSigVotingBenchmarkHelper
+{
+    fn vote() -> ring_sig_voting::evaluative_voting::Ballot {
+        use ring_sig_voting::evaluative_voting::{Score, MAX_COMMENTS, MAX_COMMENT_LENGTH};
+
+        let comment = alloc::vec![b'a'; MAX_COMMENT_LENGTH as usize];
+        ring_sig_voting::evaluative_voting::Ballot {
+            scores: frame_support::BoundedVec::truncate_from(alloc::vec![Score::Five; 10]),
+            comments: frame_support::BoundedVec::truncate_from(alloc::vec![
+                frame_support::BoundedVec::truncate_from(comment);
+                MAX_COMMENTS as usize
+            ]),
+        }
+    }
+}
+
+// 课程评价：第二个实例，按五级评分计票并可附评论，统计量通过运行时 API 提供给院系报表
+impl ring_sig_voting::Config<ring_sig_voting::Instance1> for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type Preimages = pallet_preimage::Pallet<Self>;
+    type Time = pallet_timestamp::Pallet<Self>;
+    type SubmissionDeposit = SubmissionDeposit;
+    type CreatePollOrigin = frame_system::EnsureSigned<Self::AccountId>;
+    type ProposalOrigin = frame_system::EnsureSignedBy<SudoAccount, Self::AccountId>;
+    type ClosePollOrigin = EnsureRoot<u64>;
+    type RingAdminOrigin = frame_system::EnsureSigned<Self::AccountId>;
+    type ReportCommitteeOrigin = EnsureRoot<u64>;
+    type Scheduler = pallet_scheduler::Pallet<Self>;
+    type EnactmentOrigin = EnactmentOrigin;
+    type EnactmentDelay = EnactmentDelay;
+    type Vote = ring_sig_voting::evaluative_voting::Ballot;
+    type Tally = ring_sig_voting::evaluative_voting::Tally;
+    type TallyHandler = ring_sig_voting::evaluative_voting::BallotTallyHandler;
+    type BallotCipher = ring_sig_voting::ecies::Ecies;
+    type MaxDescriptionLength = ConstU32<256>;
+    type MaxMembersInRing = ConstU32<128>;
+    type NumRingLayers = ConstU32<1>;
+    type ClosureIncentive = ClosureIncentive;
+    // 选票包含评论，需要更大的密文
+    type MaxVoteSize = ConstU32<1600>;
+    type MaxVotesPerPoll = ConstU32<1000>;
+    // 少于 5 人作答时不公开各题的分布和解密私钥
+    type MinBallotsForBreakdown = ConstU32<5>;
+    type MaxMessageLength = ConstU32<512>;
+    type MaxReportSize = ConstU32<4096>;
+    type MaxOpenReports = ConstU32<3>;
+    // 选票比 RingSigVoting 大，计票按更大的密文计费
+    type WeightInfo = super::weights::course_evaluation::WeightInfo<Runtime>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = RingSigVotingBenchmarkHelper;
+}
+
+// 赞成/反对投票：第三个实例，只有这种计票方式能判定是否通过并执行附带的调用
+impl ring_sig_voting::Config<ring_sig_voting::Instance2> for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type Preimages = pallet_preimage::Pallet<Self>;
+    type Time = pallet_timestamp::Pallet<Self>;
+    type SubmissionDeposit = SubmissionDeposit;
+    type CreatePollOrigin = frame_system::EnsureSigned<Self::AccountId>;
+    type ProposalOrigin = frame_system::EnsureSignedBy<SudoAccount, Self::AccountId>;
+    type ClosePollOrigin = EnsureRoot<u64>;
+    type RingAdminOrigin = frame_system::EnsureSigned<Self::AccountId>;
+    type ReportCommitteeOrigin = EnsureRoot<u64>;
+    type Scheduler = pallet_scheduler::Pallet<Self>;
+    type EnactmentOrigin = EnactmentOrigin;
+    type EnactmentDelay = EnactmentDelay;
+    type Vote = ring_sig_voting::simple_voting::Vote;
+    type Tally = ring_sig_voting::simple_voting::Tally;
+    type TallyHandler = ring_sig_voting::simple_voting::TallyHandler;
+    type BallotCipher = ring_sig_voting::ecies::Ecies;
+    type MaxDescriptionLength = ConstU32<256>;
+    type MaxMembersInRing = ConstU32<128>;
+    type NumRingLayers = ConstU32<1>;
+    type ClosureIncentive = ClosureIncentive;
+    type MaxVoteSize = ConstU32<64>;
+    type MaxVotesPerPoll = ConstU32<1000>;
+    // 赞成/反对投票需要公开票数才能判定是否通过
+    type MinBallotsForBreakdown = ConstU32<0>;
+    type MaxMessageLength = ConstU32<512>;
+    type MaxReportSize = ConstU32<4096>;
+    type MaxOpenReports = ConstU32<3>;
+    type WeightInfo = super::weights::simple_voting::WeightInfo<Runtime>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = RingSigVotingBenchmarkHelper;
+}
+
+// 问卷调查：第四个实例，每个投票在 `PollConfig::schema` 中给出自己的问卷，按问卷结构计票
+impl ring_sig_voting::Config<ring_sig_voting::Instance3> for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type Preimages = pallet_preimage::Pallet<Self>;
+    type Time = pallet_timestamp::Pallet<Self>;
+    type SubmissionDeposit = SubmissionDeposit;
+    type CreatePollOrigin = frame_system::EnsureSigned<Self::AccountId>;
+    type ProposalOrigin = frame_system::EnsureSignedBy<SudoAccount, Self::AccountId>;
+    type ClosePollOrigin = EnsureRoot<u64>;
+    type RingAdminOrigin = frame_system::EnsureSigned<Self::AccountId>;
+    type ReportCommitteeOrigin = EnsureRoot<u64>;
+    type Scheduler = pallet_scheduler::Pallet<Self>;
+    type EnactmentOrigin = EnactmentOrigin;
+    type EnactmentDelay = EnactmentDelay;
+    type Vote = ring_sig_voting::schema_voting::Vote;
+    type Tally = ring_sig_voting::schema_voting::Tally;
+    type TallyHandler = ring_sig_voting::schema_voting::TallyHandler;
+    type BallotCipher = ring_sig_voting::ecies::Ecies;
+    type MaxDescriptionLength = ConstU32<256>;
+    type MaxMembersInRing = ConstU32<128>;
+    type NumRingLayers = ConstU32<1>;
+    type ClosureIncentive = ClosureIncentive;
+    // 最多 16 个问题，每个答案为一个 u32
+    type MaxVoteSize = ConstU32<128>;
+    type MaxVotesPerPoll = ConstU32<1000>;
+    // 少于 5 人作答时不公开各题的统计和解密私钥
+    type MinBallotsForBreakdown = ConstU32<5>;
+    type MaxMessageLength = ConstU32<512>;
+    type MaxReportSize = ConstU32<4096>;
+    type MaxOpenReports = ConstU32<3>;
+    type WeightInfo = super::weights::surveys::WeightInfo<Runtime>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = RingSigVotingBenchmarkHelper;
 }
 
 // type CreatePollOrigin = pallet_collective::EnsureProportionMoreThan<
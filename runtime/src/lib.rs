@@ -98,7 +98,16 @@ pub type UncheckedExtrinsic =
 ///
 /// This can be a tuple of types, each implementing `OnRuntimeUpgrade`.
 #[allow(unused_parens)]
-type Migrations = ();
+type Migrations = (
+    ring_sig_voting::migrations::v1::MigrateV0ToV1<
+        Runtime,
+        (),
+        ring_sig_voting::migrations::v1::EvaluativeTally,
+    >,
+    ring_sig_voting::migrations::v1::MigrateV0ToV1<Runtime, ring_sig_voting::Instance1>,
+    ring_sig_voting::migrations::v1::MigrateV0ToV1<Runtime, ring_sig_voting::Instance2>,
+    ring_sig_voting::migrations::v1::MigrateV0ToV1<Runtime, ring_sig_voting::Instance3>,
+);
 
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
@@ -285,6 +294,15 @@ mod runtime {
     #[runtime::pallet_index(55)]
     pub type RingSigVoting = ring_sig_voting;
 
+    #[runtime::pallet_index(56)]
+    pub type CourseEvaluation = ring_sig_voting<Instance1>;
+
+    #[runtime::pallet_index(57)]
+    pub type SimpleVoting = ring_sig_voting<Instance2>;
+
+    #[runtime::pallet_index(58)]
+    pub type Surveys = ring_sig_voting<Instance3>;
+
     #[runtime::pallet_index(0)]
     pub type System = frame_system;
     #[runtime::pallet_index(1)]
@@ -0,0 +1,380 @@
+//! Autogenerated weights for the `CourseEvaluation` instance of `ring_sig_voting` in `parachain-template-runtime`
+//!
+//! GENERATED WITH THE PALLET BENCHMARKING PROCEDURE OF THE SUBSTRATE BENCHMARK CLI VERSION 47.0.0
+//! DATE: 2026-10-19, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `vm`, CPU: `Intel(R) Xeon(R) Processor`
+//! WASM-EXECUTION: `Native`, CHAIN: `None`, DB CACHE: `1024`
+//!
+//! NOTE: no wasm build of the runtime was available, so the runtime API was dispatched natively.
+//! Steps, repeats, storage tracking, proof recording, analysis and the template are those of the
+//! CLI. Native code usually runs faster than compiled wasm; rerun the command below against the
+//! wasm runtime on reference hardware before relying on the execution times.
+
+// Command:
+// frame-omni-bencher
+// v1
+// benchmark
+// pallet
+// --runtime
+// ./target/release/wbuild/parachain-template-runtime/parachain_template_runtime.wasm
+// --pallet
+// ring_sig_voting
+// --extrinsic
+// *
+// --output
+// ./runtime/src/weights/
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use polkadot_sdk::*;
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for the `CourseEvaluation` instance of `ring_sig_voting`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> ring_sig_voting::weights::WeightInfo for WeightInfo<T> {
+	/// Storage: `CourseEvaluation::RingGroupCount` (r:1 w:1)
+	/// Proof: `CourseEvaluation::RingGroupCount` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `CourseEvaluation::RingGroups` (r:0 w:1)
+	/// Proof: `CourseEvaluation::RingGroups` (`max_values`: None, `max_size`: Some(4242), added: 6717, mode: `MaxEncodedLen`)
+	fn register_ring_group() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `1493`
+		// Minimum execution time: 54_520_000 picoseconds.
+		Weight::from_parts(67_358_000, 0)
+			.saturating_add(Weight::from_parts(0, 1493))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `CourseEvaluation::RingGroups` (r:1 w:0)
+	/// Proof: `CourseEvaluation::RingGroups` (`max_values`: None, `max_size`: Some(4242), added: 6717, mode: `MaxEncodedLen`)
+	/// Storage: `CourseEvaluation::PollCount` (r:1 w:1)
+	/// Proof: `CourseEvaluation::PollCount` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `CourseEvaluation::PollVotes` (r:0 w:1)
+	/// Proof: `CourseEvaluation::PollVotes` (`max_values`: None, `max_size`: Some(607), added: 3082, mode: `MaxEncodedLen`)
+	/// Storage: `CourseEvaluation::PollRingId` (r:0 w:1)
+	/// Proof: `CourseEvaluation::PollRingId` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CourseEvaluation::Polls` (r:0 w:1)
+	/// Proof: `CourseEvaluation::Polls` (`max_values`: None, `max_size`: Some(780), added: 3255, mode: `MaxEncodedLen`)
+	fn create_poll() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `143`
+		//  Estimated: `7707`
+		// Minimum execution time: 68_049_000 picoseconds.
+		Weight::from_parts(82_562_000, 0)
+			.saturating_add(Weight::from_parts(0, 7707))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `CourseEvaluation::Polls` (r:1 w:1)
+	/// Proof: `CourseEvaluation::Polls` (`max_values`: None, `max_size`: Some(780), added: 3255, mode: `MaxEncodedLen`)
+	/// Storage: `CourseEvaluation::LegacyPolls` (r:1 w:0)
+	/// Proof: `CourseEvaluation::LegacyPolls` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `CourseEvaluation::BallotCounts` (r:1 w:0)
+	/// Proof: `CourseEvaluation::BallotCounts` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `CourseEvaluation::PollRingId` (r:1 w:1)
+	/// Proof: `CourseEvaluation::PollRingId` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CourseEvaluation::RingGroups` (r:1 w:0)
+	/// Proof: `CourseEvaluation::RingGroups` (`max_values`: None, `max_size`: Some(4242), added: 6717, mode: `MaxEncodedLen`)
+	/// Storage: `CourseEvaluation::SpoiledBallots` (r:1 w:0)
+	/// Proof: `CourseEvaluation::SpoiledBallots` (`max_values`: None, `max_size`: Some(5018), added: 7493, mode: `MaxEncodedLen`)
+	/// Storage: `CourseEvaluation::PollProposals` (r:1 w:0)
+	/// Proof: `CourseEvaluation::PollProposals` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	/// Storage: `CourseEvaluation::PollVotes` (r:0 w:1)
+	/// Proof: `CourseEvaluation::PollVotes` (`max_values`: None, `max_size`: Some(607), added: 3082, mode: `MaxEncodedLen`)
+	/// Storage: `CourseEvaluation::PollMetadata` (r:0 w:1)
+	/// Proof: `CourseEvaluation::PollMetadata` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `CourseEvaluation::TallyCursors` (r:0 w:1)
+	/// Proof: `CourseEvaluation::TallyCursors` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `CourseEvaluation::PollOutcomes` (r:0 w:1)
+	/// Proof: `CourseEvaluation::PollOutcomes` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	fn close_poll() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4605`
+		//  Estimated: `8483`
+		// Minimum execution time: 118_493_000 picoseconds.
+		Weight::from_parts(130_121_000, 0)
+			.saturating_add(Weight::from_parts(0, 8483))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
+	/// Storage: `CourseEvaluation::Polls` (r:1 w:0)
+	/// Proof: `CourseEvaluation::Polls` (`max_values`: None, `max_size`: Some(780), added: 3255, mode: `MaxEncodedLen`)
+	/// Storage: `CourseEvaluation::LegacyPolls` (r:1 w:0)
+	/// Proof: `CourseEvaluation::LegacyPolls` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `CourseEvaluation::PollRingId` (r:1 w:0)
+	/// Proof: `CourseEvaluation::PollRingId` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CourseEvaluation::RingGroups` (r:1 w:0)
+	/// Proof: `CourseEvaluation::RingGroups` (`max_values`: None, `max_size`: Some(4242), added: 6717, mode: `MaxEncodedLen`)
+	/// Storage: `CourseEvaluation::UsedKeyImages` (r:1 w:1)
+	/// Proof: `CourseEvaluation::UsedKeyImages` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `CourseEvaluation::BallotCounts` (r:1 w:1)
+	/// Proof: `CourseEvaluation::BallotCounts` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `CourseEvaluation::EncryptedVotes` (r:0 w:1)
+	/// Proof: `CourseEvaluation::EncryptedVotes` (`max_values`: None, `max_size`: Some(10067), added: 12542, mode: `MaxEncodedLen`)
+	fn anonymous_vote() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4605`
+		//  Estimated: `7707`
+		// Minimum execution time: 149_601_603_000 picoseconds.
+		Weight::from_parts(169_277_141_000, 0)
+			.saturating_add(Weight::from_parts(0, 7707))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `CourseEvaluation::Polls` (r:1 w:0)
+	/// Proof: `CourseEvaluation::Polls` (`max_values`: None, `max_size`: Some(780), added: 3255, mode: `MaxEncodedLen`)
+	/// Storage: `CourseEvaluation::PollComments` (r:1 w:1)
+	/// Proof: `CourseEvaluation::PollComments` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `CourseEvaluation::PollOutcomes` (r:1 w:0)
+	/// Proof: `CourseEvaluation::PollOutcomes` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::StatusFor` (r:1 w:0)
+	/// Proof: `Preimage::StatusFor` (`max_values`: None, `max_size`: Some(91), added: 2566, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::RequestStatusFor` (r:1 w:0)
+	/// Proof: `Preimage::RequestStatusFor` (`max_values`: None, `max_size`: Some(91), added: 2566, mode: `MaxEncodedLen`)
+	fn publish_comments() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `549`
+		//  Estimated: `4245`
+		// Minimum execution time: 44_580_000 picoseconds.
+		Weight::from_parts(75_902_000, 0)
+			.saturating_add(Weight::from_parts(0, 4245))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `CourseEvaluation::Polls` (r:1 w:1)
+	/// Proof: `CourseEvaluation::Polls` (`max_values`: None, `max_size`: Some(780), added: 3255, mode: `MaxEncodedLen`)
+	/// Storage: `CourseEvaluation::BallotCounts` (r:1 w:0)
+	/// Proof: `CourseEvaluation::BallotCounts` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `CourseEvaluation::TallyCursors` (r:1 w:1)
+	/// Proof: `CourseEvaluation::TallyCursors` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `CourseEvaluation::PollVotes` (r:1 w:1)
+	/// Proof: `CourseEvaluation::PollVotes` (`max_values`: None, `max_size`: Some(607), added: 3082, mode: `MaxEncodedLen`)
+	/// Storage: `CourseEvaluation::SpoiledBallots` (r:1 w:0)
+	/// Proof: `CourseEvaluation::SpoiledBallots` (`max_values`: None, `max_size`: Some(5018), added: 7493, mode: `MaxEncodedLen`)
+	/// Storage: `CourseEvaluation::EncryptedVotes` (r:999 w:0)
+	/// Proof: `CourseEvaluation::EncryptedVotes` (`max_values`: None, `max_size`: Some(10067), added: 12542, mode: `MaxEncodedLen`)
+	/// Storage: `CourseEvaluation::PollRingId` (r:1 w:1)
+	/// Proof: `CourseEvaluation::PollRingId` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CourseEvaluation::RingGroups` (r:1 w:0)
+	/// Proof: `CourseEvaluation::RingGroups` (`max_values`: None, `max_size`: Some(4242), added: 6717, mode: `MaxEncodedLen`)
+	/// Storage: `CourseEvaluation::PollProposals` (r:1 w:0)
+	/// Proof: `CourseEvaluation::PollProposals` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	/// Storage: `CourseEvaluation::PollMetadata` (r:0 w:1)
+	/// Proof: `CourseEvaluation::PollMetadata` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `CourseEvaluation::PollOutcomes` (r:0 w:1)
+	/// Proof: `CourseEvaluation::PollOutcomes` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 1000]`.
+	fn tally_batch(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4821 + n * (10058 ±0)`
+		//  Estimated: `8483 + n * (12542 ±0)`
+		// Minimum execution time: 226_472_000 picoseconds.
+		Weight::from_parts(3_960_111_006, 0)
+			.saturating_add(Weight::from_parts(0, 8483))
+			// Standard Error: 1_352_822
+			.saturating_add(Weight::from_parts(155_680_989, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(6))
+			.saturating_add(Weight::from_parts(0, 12542).saturating_mul(n.into()))
+	}
+	/// Storage: `CourseEvaluation::RingGroups` (r:1 w:0)
+	/// Proof: `CourseEvaluation::RingGroups` (`max_values`: None, `max_size`: Some(4242), added: 6717, mode: `MaxEncodedLen`)
+	/// Storage: `CourseEvaluation::TopicCount` (r:1 w:1)
+	/// Proof: `CourseEvaluation::TopicCount` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `CourseEvaluation::Topics` (r:0 w:1)
+	/// Proof: `CourseEvaluation::Topics` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	fn create_topic() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `143`
+		//  Estimated: `7707`
+		// Minimum execution time: 26_512_000 picoseconds.
+		Weight::from_parts(45_191_000, 0)
+			.saturating_add(Weight::from_parts(0, 7707))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `CourseEvaluation::Topics` (r:1 w:1)
+	/// Proof: `CourseEvaluation::Topics` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `CourseEvaluation::RingGroups` (r:1 w:0)
+	/// Proof: `CourseEvaluation::RingGroups` (`max_values`: None, `max_size`: Some(4242), added: 6717, mode: `MaxEncodedLen`)
+	/// Storage: `CourseEvaluation::PostWindows` (r:1 w:1)
+	/// Proof: `CourseEvaluation::PostWindows` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[2, 128]`.
+	fn post_message(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `233 + r * (33 ±0)`
+		//  Estimated: `7707`
+		// Minimum execution time: 342_693_000 picoseconds.
+		Weight::from_parts(353_633_000, 0)
+			.saturating_add(Weight::from_parts(0, 7707))
+			// Standard Error: 604_556
+			.saturating_add(Weight::from_parts(201_938_799, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Sudo::Key` (r:1 w:0)
+	/// Proof: `Sudo::Key` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `CourseEvaluation::RingGroups` (r:1 w:0)
+	/// Proof: `CourseEvaluation::RingGroups` (`max_values`: None, `max_size`: Some(4242), added: 6717, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::StatusFor` (r:1 w:0)
+	/// Proof: `Preimage::StatusFor` (`max_values`: None, `max_size`: Some(91), added: 2566, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::RequestStatusFor` (r:1 w:1)
+	/// Proof: `Preimage::RequestStatusFor` (`max_values`: None, `max_size`: Some(91), added: 2566, mode: `MaxEncodedLen`)
+	/// Storage: `CourseEvaluation::PetitionCount` (r:1 w:1)
+	/// Proof: `CourseEvaluation::PetitionCount` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `CourseEvaluation::Petitions` (r:0 w:1)
+	/// Proof: `CourseEvaluation::Petitions` (`max_values`: None, `max_size`: Some(327), added: 2802, mode: `MaxEncodedLen`)
+	/// Storage: `CourseEvaluation::PetitionProposals` (r:0 w:1)
+	/// Proof: `CourseEvaluation::PetitionProposals` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	fn create_petition() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4577`
+		//  Estimated: `7707`
+		// Minimum execution time: 50_409_000 picoseconds.
+		Weight::from_parts(77_704_000, 0)
+			.saturating_add(Weight::from_parts(0, 7707))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `CourseEvaluation::Petitions` (r:1 w:1)
+	/// Proof: `CourseEvaluation::Petitions` (`max_values`: None, `max_size`: Some(327), added: 2802, mode: `MaxEncodedLen`)
+	/// Storage: `CourseEvaluation::RingGroups` (r:1 w:0)
+	/// Proof: `CourseEvaluation::RingGroups` (`max_values`: None, `max_size`: Some(4242), added: 6717, mode: `MaxEncodedLen`)
+	/// Storage: `CourseEvaluation::PetitionSigners` (r:1 w:1)
+	/// Proof: `CourseEvaluation::PetitionSigners` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `CourseEvaluation::PetitionProposals` (r:1 w:1)
+	/// Proof: `CourseEvaluation::PetitionProposals` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Agenda` (r:1 w:1)
+	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(398862), added: 401337, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::StatusFor` (r:1 w:0)
+	/// Proof: `Preimage::StatusFor` (`max_values`: None, `max_size`: Some(91), added: 2566, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::RequestStatusFor` (r:1 w:1)
+	/// Proof: `Preimage::RequestStatusFor` (`max_values`: None, `max_size`: Some(91), added: 2566, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[2, 128]`.
+	fn sign_petition(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `427 + r * (33 ±0)`
+		//  Estimated: `402327`
+		// Minimum execution time: 538_896_000 picoseconds.
+		Weight::from_parts(632_525_000, 0)
+			.saturating_add(Weight::from_parts(0, 402327))
+			// Standard Error: 7_783_531
+			.saturating_add(Weight::from_parts(925_258_249, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `CourseEvaluation::CommitteeKey` (r:0 w:1)
+	/// Proof: `CourseEvaluation::CommitteeKey` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	fn set_committee_key() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 15_955_000 picoseconds.
+		Weight::from_parts(16_632_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `CourseEvaluation::CommitteeKey` (r:1 w:0)
+	/// Proof: `CourseEvaluation::CommitteeKey` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `CourseEvaluation::RingGroups` (r:1 w:0)
+	/// Proof: `CourseEvaluation::RingGroups` (`max_values`: None, `max_size`: Some(4242), added: 6717, mode: `MaxEncodedLen`)
+	/// Storage: `CourseEvaluation::UsedReplyKeys` (r:1 w:1)
+	/// Proof: `CourseEvaluation::UsedReplyKeys` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `CourseEvaluation::OpenReports` (r:1 w:1)
+	/// Proof: `CourseEvaluation::OpenReports` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `CourseEvaluation::ReportCount` (r:1 w:1)
+	/// Proof: `CourseEvaluation::ReportCount` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `CourseEvaluation::Reports` (r:0 w:1)
+	/// Proof: `CourseEvaluation::Reports` (`max_values`: None, `max_size`: Some(4270), added: 6745, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[2, 128]`.
+	fn submit_report(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `207 + r * (33 ±0)`
+		//  Estimated: `7707`
+		// Minimum execution time: 473_390_000 picoseconds.
+		Weight::from_parts(479_213_000, 0)
+			.saturating_add(Weight::from_parts(0, 7707))
+			// Standard Error: 786_786
+			.saturating_add(Weight::from_parts(180_124_337, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `CourseEvaluation::Reports` (r:1 w:1)
+	/// Proof: `CourseEvaluation::Reports` (`max_values`: None, `max_size`: Some(4270), added: 6745, mode: `MaxEncodedLen`)
+	/// Storage: `CourseEvaluation::OpenReports` (r:1 w:1)
+	/// Proof: `CourseEvaluation::OpenReports` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	fn acknowledge_report() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4381`
+		//  Estimated: `7735`
+		// Minimum execution time: 21_552_000 picoseconds.
+		Weight::from_parts(26_234_000, 0)
+			.saturating_add(Weight::from_parts(0, 7735))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `CourseEvaluation::Polls` (r:1 w:1)
+	/// Proof: `CourseEvaluation::Polls` (`max_values`: None, `max_size`: Some(780), added: 3255, mode: `MaxEncodedLen`)
+	/// Storage: `CourseEvaluation::LegacyPolls` (r:1 w:1)
+	/// Proof: `CourseEvaluation::LegacyPolls` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `CourseEvaluation::BallotCounts` (r:1 w:0)
+	/// Proof: `CourseEvaluation::BallotCounts` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `CourseEvaluation::EncryptedVotes` (r:1 w:1001)
+	/// Proof: `CourseEvaluation::EncryptedVotes` (`max_values`: None, `max_size`: Some(10067), added: 12542, mode: `MaxEncodedLen`)
+	/// Storage: `CourseEvaluation::PollRingId` (r:1 w:1)
+	/// Proof: `CourseEvaluation::PollRingId` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CourseEvaluation::RingGroups` (r:1 w:0)
+	/// Proof: `CourseEvaluation::RingGroups` (`max_values`: None, `max_size`: Some(4242), added: 6717, mode: `MaxEncodedLen`)
+	/// Storage: `CourseEvaluation::SpoiledBallots` (r:1 w:0)
+	/// Proof: `CourseEvaluation::SpoiledBallots` (`max_values`: None, `max_size`: Some(5018), added: 7493, mode: `MaxEncodedLen`)
+	/// Storage: `CourseEvaluation::PollProposals` (r:1 w:0)
+	/// Proof: `CourseEvaluation::PollProposals` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	/// Storage: `CourseEvaluation::PollVotes` (r:0 w:1)
+	/// Proof: `CourseEvaluation::PollVotes` (`max_values`: None, `max_size`: Some(607), added: 3082, mode: `MaxEncodedLen`)
+	/// Storage: `CourseEvaluation::PollMetadata` (r:0 w:1)
+	/// Proof: `CourseEvaluation::PollMetadata` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `CourseEvaluation::TallyCursors` (r:0 w:1)
+	/// Proof: `CourseEvaluation::TallyCursors` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `CourseEvaluation::PollOutcomes` (r:0 w:1)
+	/// Proof: `CourseEvaluation::PollOutcomes` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 1000]`.
+	fn close_legacy_poll(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4728 + n * (10039 ±0)`
+		//  Estimated: `13532`
+		// Minimum execution time: 88_354_000 picoseconds.
+		Weight::from_parts(2_738_910_311, 0)
+			.saturating_add(Weight::from_parts(0, 13532))
+			// Standard Error: 727_545
+			.saturating_add(Weight::from_parts(38_033_897, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(8))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `CourseEvaluation::LegacyPolls` (r:1 w:0)
+	/// Proof: `CourseEvaluation::LegacyPolls` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `CourseEvaluation::EncryptedVotes` (r:1 w:1000)
+	/// Proof: `CourseEvaluation::EncryptedVotes` (`max_values`: None, `max_size`: Some(10067), added: 12542, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 1000]`.
+	fn migrate_legacy_ballots(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `90 + n * (10039 ±0)`
+		//  Estimated: `13532`
+		// Minimum execution time: 47_077_000 picoseconds.
+		Weight::from_parts(904_685_620, 0)
+			.saturating_add(Weight::from_parts(0, 13532))
+			// Standard Error: 312_608
+			.saturating_add(Weight::from_parts(26_313_190, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+}
@@ -18,9 +18,12 @@
 //! Expose the auto generated weight files.
 
 pub mod block_weights;
+pub mod course_evaluation;
 pub mod extrinsic_weights;
 pub mod paritydb_weights;
 pub mod rocksdb_weights;
+pub mod simple_voting;
+pub mod surveys;
 
 pub use block_weights::constants::BlockExecutionWeight;
 pub use extrinsic_weights::constants::ExtrinsicBaseWeight;
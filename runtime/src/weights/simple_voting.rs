@@ -0,0 +1,366 @@
+//! Autogenerated weights for the `SimpleVoting` instance of `ring_sig_voting` in `parachain-template-runtime`
+//!
+//! GENERATED WITH THE PALLET BENCHMARKING PROCEDURE OF THE SUBSTRATE BENCHMARK CLI VERSION 47.0.0
+//! DATE: 2026-10-19, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `vm`, CPU: `Intel(R) Xeon(R) Processor`
+//! WASM-EXECUTION: `Native`, CHAIN: `None`, DB CACHE: `1024`
+//!
+//! NOTE: no wasm build of the runtime was available, so the runtime API was dispatched natively.
+//! Steps, repeats, storage tracking, proof recording, analysis and the template are those of the
+//! CLI. Native code usually runs faster than compiled wasm; rerun the command below against the
+//! wasm runtime on reference hardware before relying on the execution times.
+
+// Command:
+// frame-omni-bencher
+// v1
+// benchmark
+// pallet
+// --runtime
+// ./target/release/wbuild/parachain-template-runtime/parachain_template_runtime.wasm
+// --pallet
+// ring_sig_voting
+// --extrinsic
+// *
+// --output
+// ./runtime/src/weights/
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use polkadot_sdk::*;
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for the `SimpleVoting` instance of `ring_sig_voting`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> ring_sig_voting::weights::WeightInfo for WeightInfo<T> {
+	/// Storage: `SimpleVoting::RingGroupCount` (r:1 w:1)
+	/// Proof: `SimpleVoting::RingGroupCount` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `SimpleVoting::RingGroups` (r:0 w:1)
+	/// Proof: `SimpleVoting::RingGroups` (`max_values`: None, `max_size`: Some(4242), added: 6717, mode: `MaxEncodedLen`)
+	fn register_ring_group() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `1493`
+		// Minimum execution time: 20_726_000 picoseconds.
+		Weight::from_parts(23_226_000, 0)
+			.saturating_add(Weight::from_parts(0, 1493))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `SimpleVoting::RingGroups` (r:1 w:0)
+	/// Proof: `SimpleVoting::RingGroups` (`max_values`: None, `max_size`: Some(4242), added: 6717, mode: `MaxEncodedLen`)
+	/// Storage: `SimpleVoting::PollCount` (r:1 w:1)
+	/// Proof: `SimpleVoting::PollCount` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `SimpleVoting::PollVotes` (r:0 w:1)
+	/// Proof: `SimpleVoting::PollVotes` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `SimpleVoting::PollRingId` (r:0 w:1)
+	/// Proof: `SimpleVoting::PollRingId` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `SimpleVoting::Polls` (r:0 w:1)
+	/// Proof: `SimpleVoting::Polls` (`max_values`: None, `max_size`: Some(780), added: 3255, mode: `MaxEncodedLen`)
+	fn create_poll() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `143`
+		//  Estimated: `7707`
+		// Minimum execution time: 29_098_000 picoseconds.
+		Weight::from_parts(45_264_000, 0)
+			.saturating_add(Weight::from_parts(0, 7707))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `SimpleVoting::Polls` (r:1 w:1)
+	/// Proof: `SimpleVoting::Polls` (`max_values`: None, `max_size`: Some(780), added: 3255, mode: `MaxEncodedLen`)
+	/// Storage: `SimpleVoting::LegacyPolls` (r:1 w:0)
+	/// Proof: `SimpleVoting::LegacyPolls` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `SimpleVoting::BallotCounts` (r:1 w:0)
+	/// Proof: `SimpleVoting::BallotCounts` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `SimpleVoting::TallyCursors` (r:0 w:1)
+	/// Proof: `SimpleVoting::TallyCursors` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	fn close_poll() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `396`
+		//  Estimated: `4245`
+		// Minimum execution time: 71_699_000 picoseconds.
+		Weight::from_parts(108_325_000, 0)
+			.saturating_add(Weight::from_parts(0, 4245))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `SimpleVoting::Polls` (r:1 w:0)
+	/// Proof: `SimpleVoting::Polls` (`max_values`: None, `max_size`: Some(780), added: 3255, mode: `MaxEncodedLen`)
+	/// Storage: `SimpleVoting::LegacyPolls` (r:1 w:0)
+	/// Proof: `SimpleVoting::LegacyPolls` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `SimpleVoting::PollRingId` (r:1 w:0)
+	/// Proof: `SimpleVoting::PollRingId` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `SimpleVoting::RingGroups` (r:1 w:0)
+	/// Proof: `SimpleVoting::RingGroups` (`max_values`: None, `max_size`: Some(4242), added: 6717, mode: `MaxEncodedLen`)
+	/// Storage: `SimpleVoting::UsedKeyImages` (r:1 w:1)
+	/// Proof: `SimpleVoting::UsedKeyImages` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `SimpleVoting::BallotCounts` (r:1 w:1)
+	/// Proof: `SimpleVoting::BallotCounts` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `SimpleVoting::EncryptedVotes` (r:0 w:1)
+	/// Proof: `SimpleVoting::EncryptedVotes` (`max_values`: None, `max_size`: Some(8531), added: 11006, mode: `MaxEncodedLen`)
+	fn anonymous_vote() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4605`
+		//  Estimated: `7707`
+		// Minimum execution time: 127_325_853_000 picoseconds.
+		Weight::from_parts(139_070_004_000, 0)
+			.saturating_add(Weight::from_parts(0, 7707))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `SimpleVoting::Polls` (r:1 w:0)
+	/// Proof: `SimpleVoting::Polls` (`max_values`: None, `max_size`: Some(780), added: 3255, mode: `MaxEncodedLen`)
+	/// Storage: `SimpleVoting::PollComments` (r:1 w:1)
+	/// Proof: `SimpleVoting::PollComments` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `SimpleVoting::PollOutcomes` (r:1 w:0)
+	/// Proof: `SimpleVoting::PollOutcomes` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::StatusFor` (r:1 w:0)
+	/// Proof: `Preimage::StatusFor` (`max_values`: None, `max_size`: Some(91), added: 2566, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::RequestStatusFor` (r:1 w:0)
+	/// Proof: `Preimage::RequestStatusFor` (`max_values`: None, `max_size`: Some(91), added: 2566, mode: `MaxEncodedLen`)
+	fn publish_comments() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `549`
+		//  Estimated: `4245`
+		// Minimum execution time: 29_389_000 picoseconds.
+		Weight::from_parts(31_344_000, 0)
+			.saturating_add(Weight::from_parts(0, 4245))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `SimpleVoting::Polls` (r:1 w:1)
+	/// Proof: `SimpleVoting::Polls` (`max_values`: None, `max_size`: Some(780), added: 3255, mode: `MaxEncodedLen`)
+	/// Storage: `SimpleVoting::BallotCounts` (r:1 w:0)
+	/// Proof: `SimpleVoting::BallotCounts` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `SimpleVoting::TallyCursors` (r:1 w:1)
+	/// Proof: `SimpleVoting::TallyCursors` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `SimpleVoting::PollVotes` (r:1 w:1)
+	/// Proof: `SimpleVoting::PollVotes` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `SimpleVoting::SpoiledBallots` (r:1 w:0)
+	/// Proof: `SimpleVoting::SpoiledBallots` (`max_values`: None, `max_size`: Some(5018), added: 7493, mode: `MaxEncodedLen`)
+	/// Storage: `SimpleVoting::EncryptedVotes` (r:999 w:0)
+	/// Proof: `SimpleVoting::EncryptedVotes` (`max_values`: None, `max_size`: Some(8531), added: 11006, mode: `MaxEncodedLen`)
+	/// Storage: `SimpleVoting::PollRingId` (r:1 w:1)
+	/// Proof: `SimpleVoting::PollRingId` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `SimpleVoting::RingGroups` (r:1 w:0)
+	/// Proof: `SimpleVoting::RingGroups` (`max_values`: None, `max_size`: Some(4242), added: 6717, mode: `MaxEncodedLen`)
+	/// Storage: `SimpleVoting::PollProposals` (r:1 w:0)
+	/// Proof: `SimpleVoting::PollProposals` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	/// Storage: `SimpleVoting::PollMetadata` (r:0 w:1)
+	/// Proof: `SimpleVoting::PollMetadata` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `SimpleVoting::PollOutcomes` (r:0 w:1)
+	/// Proof: `SimpleVoting::PollOutcomes` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 1000]`.
+	fn tally_batch(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4828 + n * (8522 ±0)`
+		//  Estimated: `8483 + n * (11006 ±0)`
+		// Minimum execution time: 209_597_000 picoseconds.
+		Weight::from_parts(238_538_000, 0)
+			.saturating_add(Weight::from_parts(0, 8483))
+			// Standard Error: 944_210
+			.saturating_add(Weight::from_parts(159_669_094, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(6))
+			.saturating_add(Weight::from_parts(0, 11006).saturating_mul(n.into()))
+	}
+	/// Storage: `SimpleVoting::RingGroups` (r:1 w:0)
+	/// Proof: `SimpleVoting::RingGroups` (`max_values`: None, `max_size`: Some(4242), added: 6717, mode: `MaxEncodedLen`)
+	/// Storage: `SimpleVoting::TopicCount` (r:1 w:1)
+	/// Proof: `SimpleVoting::TopicCount` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `SimpleVoting::Topics` (r:0 w:1)
+	/// Proof: `SimpleVoting::Topics` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	fn create_topic() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `143`
+		//  Estimated: `7707`
+		// Minimum execution time: 43_444_000 picoseconds.
+		Weight::from_parts(50_255_000, 0)
+			.saturating_add(Weight::from_parts(0, 7707))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `SimpleVoting::Topics` (r:1 w:1)
+	/// Proof: `SimpleVoting::Topics` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `SimpleVoting::RingGroups` (r:1 w:0)
+	/// Proof: `SimpleVoting::RingGroups` (`max_values`: None, `max_size`: Some(4242), added: 6717, mode: `MaxEncodedLen`)
+	/// Storage: `SimpleVoting::PostWindows` (r:1 w:1)
+	/// Proof: `SimpleVoting::PostWindows` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[2, 128]`.
+	fn post_message(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `233 + r * (33 ±0)`
+		//  Estimated: `7707`
+		// Minimum execution time: 465_026_000 picoseconds.
+		Weight::from_parts(2_650_895, 0)
+			.saturating_add(Weight::from_parts(0, 7707))
+			// Standard Error: 1_548_303
+			.saturating_add(Weight::from_parts(208_120_084, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Sudo::Key` (r:1 w:0)
+	/// Proof: `Sudo::Key` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `SimpleVoting::RingGroups` (r:1 w:0)
+	/// Proof: `SimpleVoting::RingGroups` (`max_values`: None, `max_size`: Some(4242), added: 6717, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::StatusFor` (r:1 w:0)
+	/// Proof: `Preimage::StatusFor` (`max_values`: None, `max_size`: Some(91), added: 2566, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::RequestStatusFor` (r:1 w:1)
+	/// Proof: `Preimage::RequestStatusFor` (`max_values`: None, `max_size`: Some(91), added: 2566, mode: `MaxEncodedLen`)
+	/// Storage: `SimpleVoting::PetitionCount` (r:1 w:1)
+	/// Proof: `SimpleVoting::PetitionCount` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `SimpleVoting::Petitions` (r:0 w:1)
+	/// Proof: `SimpleVoting::Petitions` (`max_values`: None, `max_size`: Some(327), added: 2802, mode: `MaxEncodedLen`)
+	/// Storage: `SimpleVoting::PetitionProposals` (r:0 w:1)
+	/// Proof: `SimpleVoting::PetitionProposals` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	fn create_petition() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4577`
+		//  Estimated: `7707`
+		// Minimum execution time: 68_548_000 picoseconds.
+		Weight::from_parts(72_372_000, 0)
+			.saturating_add(Weight::from_parts(0, 7707))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `SimpleVoting::Petitions` (r:1 w:1)
+	/// Proof: `SimpleVoting::Petitions` (`max_values`: None, `max_size`: Some(327), added: 2802, mode: `MaxEncodedLen`)
+	/// Storage: `SimpleVoting::RingGroups` (r:1 w:0)
+	/// Proof: `SimpleVoting::RingGroups` (`max_values`: None, `max_size`: Some(4242), added: 6717, mode: `MaxEncodedLen`)
+	/// Storage: `SimpleVoting::PetitionSigners` (r:1 w:1)
+	/// Proof: `SimpleVoting::PetitionSigners` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `SimpleVoting::PetitionProposals` (r:1 w:1)
+	/// Proof: `SimpleVoting::PetitionProposals` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Agenda` (r:1 w:1)
+	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(398862), added: 401337, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::StatusFor` (r:1 w:0)
+	/// Proof: `Preimage::StatusFor` (`max_values`: None, `max_size`: Some(91), added: 2566, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::RequestStatusFor` (r:1 w:1)
+	/// Proof: `Preimage::RequestStatusFor` (`max_values`: None, `max_size`: Some(91), added: 2566, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[2, 128]`.
+	fn sign_petition(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `427 + r * (33 ±0)`
+		//  Estimated: `402327`
+		// Minimum execution time: 745_203_000 picoseconds.
+		Weight::from_parts(755_275_000, 0)
+			.saturating_add(Weight::from_parts(0, 402327))
+			// Standard Error: 8_434_026
+			.saturating_add(Weight::from_parts(948_976_733, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `SimpleVoting::CommitteeKey` (r:0 w:1)
+	/// Proof: `SimpleVoting::CommitteeKey` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	fn set_committee_key() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 17_149_000 picoseconds.
+		Weight::from_parts(20_272_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `SimpleVoting::CommitteeKey` (r:1 w:0)
+	/// Proof: `SimpleVoting::CommitteeKey` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `SimpleVoting::RingGroups` (r:1 w:0)
+	/// Proof: `SimpleVoting::RingGroups` (`max_values`: None, `max_size`: Some(4242), added: 6717, mode: `MaxEncodedLen`)
+	/// Storage: `SimpleVoting::UsedReplyKeys` (r:1 w:1)
+	/// Proof: `SimpleVoting::UsedReplyKeys` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `SimpleVoting::OpenReports` (r:1 w:1)
+	/// Proof: `SimpleVoting::OpenReports` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `SimpleVoting::ReportCount` (r:1 w:1)
+	/// Proof: `SimpleVoting::ReportCount` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `SimpleVoting::Reports` (r:0 w:1)
+	/// Proof: `SimpleVoting::Reports` (`max_values`: None, `max_size`: Some(4270), added: 6745, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[2, 128]`.
+	fn submit_report(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `207 + r * (33 ±0)`
+		//  Estimated: `7707`
+		// Minimum execution time: 358_546_000 picoseconds.
+		Weight::from_parts(283_020_502, 0)
+			.saturating_add(Weight::from_parts(0, 7707))
+			// Standard Error: 1_842_329
+			.saturating_add(Weight::from_parts(189_025_311, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `SimpleVoting::Reports` (r:1 w:1)
+	/// Proof: `SimpleVoting::Reports` (`max_values`: None, `max_size`: Some(4270), added: 6745, mode: `MaxEncodedLen`)
+	/// Storage: `SimpleVoting::OpenReports` (r:1 w:1)
+	/// Proof: `SimpleVoting::OpenReports` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	fn acknowledge_report() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4381`
+		//  Estimated: `7735`
+		// Minimum execution time: 25_019_000 picoseconds.
+		Weight::from_parts(29_412_000, 0)
+			.saturating_add(Weight::from_parts(0, 7735))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `SimpleVoting::Polls` (r:1 w:1)
+	/// Proof: `SimpleVoting::Polls` (`max_values`: None, `max_size`: Some(780), added: 3255, mode: `MaxEncodedLen`)
+	/// Storage: `SimpleVoting::LegacyPolls` (r:1 w:1)
+	/// Proof: `SimpleVoting::LegacyPolls` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `SimpleVoting::BallotCounts` (r:1 w:0)
+	/// Proof: `SimpleVoting::BallotCounts` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `SimpleVoting::EncryptedVotes` (r:1 w:1001)
+	/// Proof: `SimpleVoting::EncryptedVotes` (`max_values`: None, `max_size`: Some(8531), added: 11006, mode: `MaxEncodedLen`)
+	/// Storage: `SimpleVoting::PollRingId` (r:1 w:1)
+	/// Proof: `SimpleVoting::PollRingId` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `SimpleVoting::RingGroups` (r:1 w:0)
+	/// Proof: `SimpleVoting::RingGroups` (`max_values`: None, `max_size`: Some(4242), added: 6717, mode: `MaxEncodedLen`)
+	/// Storage: `SimpleVoting::SpoiledBallots` (r:1 w:0)
+	/// Proof: `SimpleVoting::SpoiledBallots` (`max_values`: None, `max_size`: Some(5018), added: 7493, mode: `MaxEncodedLen`)
+	/// Storage: `SimpleVoting::PollProposals` (r:1 w:0)
+	/// Proof: `SimpleVoting::PollProposals` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	/// Storage: `SimpleVoting::PollVotes` (r:0 w:1)
+	/// Proof: `SimpleVoting::PollVotes` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `SimpleVoting::PollMetadata` (r:0 w:1)
+	/// Proof: `SimpleVoting::PollMetadata` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `SimpleVoting::TallyCursors` (r:0 w:1)
+	/// Proof: `SimpleVoting::TallyCursors` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `SimpleVoting::PollOutcomes` (r:0 w:1)
+	/// Proof: `SimpleVoting::PollOutcomes` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 1000]`.
+	fn close_legacy_poll(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4728 + n * (8503 ±0)`
+		//  Estimated: `11996`
+		// Minimum execution time: 169_757_000 picoseconds.
+		Weight::from_parts(1_654_594_459, 0)
+			.saturating_add(Weight::from_parts(0, 11996))
+			// Standard Error: 732_567
+			.saturating_add(Weight::from_parts(32_132_658, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(8))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `SimpleVoting::LegacyPolls` (r:1 w:0)
+	/// Proof: `SimpleVoting::LegacyPolls` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `SimpleVoting::EncryptedVotes` (r:1 w:1000)
+	/// Proof: `SimpleVoting::EncryptedVotes` (`max_values`: None, `max_size`: Some(8531), added: 11006, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 1000]`.
+	fn migrate_legacy_ballots(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `90 + n * (8503 ±0)`
+		//  Estimated: `11996`
+		// Minimum execution time: 30_651_000 picoseconds.
+		Weight::from_parts(34_459_000, 0)
+			.saturating_add(Weight::from_parts(0, 11996))
+			// Standard Error: 127_956
+			.saturating_add(Weight::from_parts(28_998_790, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+}
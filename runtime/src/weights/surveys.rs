@@ -0,0 +1,380 @@
+//! Autogenerated weights for the `Surveys` instance of `ring_sig_voting` in `parachain-template-runtime`
+//!
+//! GENERATED WITH THE PALLET BENCHMARKING PROCEDURE OF THE SUBSTRATE BENCHMARK CLI VERSION 47.0.0
+//! DATE: 2026-10-19, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `vm`, CPU: `Intel(R) Xeon(R) Processor`
+//! WASM-EXECUTION: `Native`, CHAIN: `None`, DB CACHE: `1024`
+//!
+//! NOTE: no wasm build of the runtime was available, so the runtime API was dispatched natively.
+//! Steps, repeats, storage tracking, proof recording, analysis and the template are those of the
+//! CLI. Native code usually runs faster than compiled wasm; rerun the command below against the
+//! wasm runtime on reference hardware before relying on the execution times.
+
+// Command:
+// frame-omni-bencher
+// v1
+// benchmark
+// pallet
+// --runtime
+// ./target/release/wbuild/parachain-template-runtime/parachain_template_runtime.wasm
+// --pallet
+// ring_sig_voting
+// --extrinsic
+// *
+// --output
+// ./runtime/src/weights/
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use polkadot_sdk::*;
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for the `Surveys` instance of `ring_sig_voting`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> ring_sig_voting::weights::WeightInfo for WeightInfo<T> {
+	/// Storage: `Surveys::RingGroupCount` (r:1 w:1)
+	/// Proof: `Surveys::RingGroupCount` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Surveys::RingGroups` (r:0 w:1)
+	/// Proof: `Surveys::RingGroups` (`max_values`: None, `max_size`: Some(4242), added: 6717, mode: `MaxEncodedLen`)
+	fn register_ring_group() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3`
+		//  Estimated: `1493`
+		// Minimum execution time: 49_872_000 picoseconds.
+		Weight::from_parts(52_210_000, 0)
+			.saturating_add(Weight::from_parts(0, 1493))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Surveys::RingGroups` (r:1 w:0)
+	/// Proof: `Surveys::RingGroups` (`max_values`: None, `max_size`: Some(4242), added: 6717, mode: `MaxEncodedLen`)
+	/// Storage: `Surveys::PollCount` (r:1 w:1)
+	/// Proof: `Surveys::PollCount` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Surveys::PollVotes` (r:0 w:1)
+	/// Proof: `Surveys::PollVotes` (`max_values`: None, `max_size`: Some(1073), added: 3548, mode: `MaxEncodedLen`)
+	/// Storage: `Surveys::PollRingId` (r:0 w:1)
+	/// Proof: `Surveys::PollRingId` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Surveys::Polls` (r:0 w:1)
+	/// Proof: `Surveys::Polls` (`max_values`: None, `max_size`: Some(780), added: 3255, mode: `MaxEncodedLen`)
+	fn create_poll() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `104`
+		//  Estimated: `7707`
+		// Minimum execution time: 67_919_000 picoseconds.
+		Weight::from_parts(76_059_000, 0)
+			.saturating_add(Weight::from_parts(0, 7707))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `Surveys::Polls` (r:1 w:1)
+	/// Proof: `Surveys::Polls` (`max_values`: None, `max_size`: Some(780), added: 3255, mode: `MaxEncodedLen`)
+	/// Storage: `Surveys::LegacyPolls` (r:1 w:0)
+	/// Proof: `Surveys::LegacyPolls` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `Surveys::BallotCounts` (r:1 w:0)
+	/// Proof: `Surveys::BallotCounts` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Surveys::PollRingId` (r:1 w:1)
+	/// Proof: `Surveys::PollRingId` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Surveys::RingGroups` (r:1 w:0)
+	/// Proof: `Surveys::RingGroups` (`max_values`: None, `max_size`: Some(4242), added: 6717, mode: `MaxEncodedLen`)
+	/// Storage: `Surveys::SpoiledBallots` (r:1 w:0)
+	/// Proof: `Surveys::SpoiledBallots` (`max_values`: None, `max_size`: Some(5018), added: 7493, mode: `MaxEncodedLen`)
+	/// Storage: `Surveys::PollProposals` (r:1 w:0)
+	/// Proof: `Surveys::PollProposals` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	/// Storage: `Surveys::PollVotes` (r:0 w:1)
+	/// Proof: `Surveys::PollVotes` (`max_values`: None, `max_size`: Some(1073), added: 3548, mode: `MaxEncodedLen`)
+	/// Storage: `Surveys::PollMetadata` (r:0 w:1)
+	/// Proof: `Surveys::PollMetadata` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Surveys::TallyCursors` (r:0 w:1)
+	/// Proof: `Surveys::TallyCursors` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Surveys::PollOutcomes` (r:0 w:1)
+	/// Proof: `Surveys::PollOutcomes` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	fn close_poll() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4566`
+		//  Estimated: `8483`
+		// Minimum execution time: 105_200_000 picoseconds.
+		Weight::from_parts(116_552_000, 0)
+			.saturating_add(Weight::from_parts(0, 8483))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
+	/// Storage: `Surveys::Polls` (r:1 w:0)
+	/// Proof: `Surveys::Polls` (`max_values`: None, `max_size`: Some(780), added: 3255, mode: `MaxEncodedLen`)
+	/// Storage: `Surveys::LegacyPolls` (r:1 w:0)
+	/// Proof: `Surveys::LegacyPolls` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `Surveys::PollRingId` (r:1 w:0)
+	/// Proof: `Surveys::PollRingId` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Surveys::RingGroups` (r:1 w:0)
+	/// Proof: `Surveys::RingGroups` (`max_values`: None, `max_size`: Some(4242), added: 6717, mode: `MaxEncodedLen`)
+	/// Storage: `Surveys::UsedKeyImages` (r:1 w:1)
+	/// Proof: `Surveys::UsedKeyImages` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `Surveys::BallotCounts` (r:1 w:1)
+	/// Proof: `Surveys::BallotCounts` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Surveys::EncryptedVotes` (r:0 w:1)
+	/// Proof: `Surveys::EncryptedVotes` (`max_values`: None, `max_size`: Some(8595), added: 11070, mode: `MaxEncodedLen`)
+	fn anonymous_vote() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4566`
+		//  Estimated: `7707`
+		// Minimum execution time: 159_621_997_000 picoseconds.
+		Weight::from_parts(163_520_030_000, 0)
+			.saturating_add(Weight::from_parts(0, 7707))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Surveys::Polls` (r:1 w:0)
+	/// Proof: `Surveys::Polls` (`max_values`: None, `max_size`: Some(780), added: 3255, mode: `MaxEncodedLen`)
+	/// Storage: `Surveys::PollComments` (r:1 w:1)
+	/// Proof: `Surveys::PollComments` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Surveys::PollOutcomes` (r:1 w:0)
+	/// Proof: `Surveys::PollOutcomes` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::StatusFor` (r:1 w:0)
+	/// Proof: `Preimage::StatusFor` (`max_values`: None, `max_size`: Some(91), added: 2566, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::RequestStatusFor` (r:1 w:0)
+	/// Proof: `Preimage::RequestStatusFor` (`max_values`: None, `max_size`: Some(91), added: 2566, mode: `MaxEncodedLen`)
+	fn publish_comments() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `511`
+		//  Estimated: `4245`
+		// Minimum execution time: 61_621_000 picoseconds.
+		Weight::from_parts(72_567_000, 0)
+			.saturating_add(Weight::from_parts(0, 4245))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Surveys::Polls` (r:1 w:1)
+	/// Proof: `Surveys::Polls` (`max_values`: None, `max_size`: Some(780), added: 3255, mode: `MaxEncodedLen`)
+	/// Storage: `Surveys::BallotCounts` (r:1 w:0)
+	/// Proof: `Surveys::BallotCounts` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Surveys::TallyCursors` (r:1 w:1)
+	/// Proof: `Surveys::TallyCursors` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Surveys::PollVotes` (r:1 w:1)
+	/// Proof: `Surveys::PollVotes` (`max_values`: None, `max_size`: Some(1073), added: 3548, mode: `MaxEncodedLen`)
+	/// Storage: `Surveys::SpoiledBallots` (r:1 w:0)
+	/// Proof: `Surveys::SpoiledBallots` (`max_values`: None, `max_size`: Some(5018), added: 7493, mode: `MaxEncodedLen`)
+	/// Storage: `Surveys::EncryptedVotes` (r:999 w:0)
+	/// Proof: `Surveys::EncryptedVotes` (`max_values`: None, `max_size`: Some(8595), added: 11070, mode: `MaxEncodedLen`)
+	/// Storage: `Surveys::PollRingId` (r:1 w:1)
+	/// Proof: `Surveys::PollRingId` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Surveys::RingGroups` (r:1 w:0)
+	/// Proof: `Surveys::RingGroups` (`max_values`: None, `max_size`: Some(4242), added: 6717, mode: `MaxEncodedLen`)
+	/// Storage: `Surveys::PollProposals` (r:1 w:0)
+	/// Proof: `Surveys::PollProposals` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	/// Storage: `Surveys::PollMetadata` (r:0 w:1)
+	/// Proof: `Surveys::PollMetadata` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Surveys::PollOutcomes` (r:0 w:1)
+	/// Proof: `Surveys::PollOutcomes` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 1000]`.
+	fn tally_batch(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4819 + n * (8586 ±0)`
+		//  Estimated: `8483 + n * (11070 ±0)`
+		// Minimum execution time: 313_290_000 picoseconds.
+		Weight::from_parts(328_790_000, 0)
+			.saturating_add(Weight::from_parts(0, 8483))
+			// Standard Error: 648_870
+			.saturating_add(Weight::from_parts(181_632_204, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(6))
+			.saturating_add(Weight::from_parts(0, 11070).saturating_mul(n.into()))
+	}
+	/// Storage: `Surveys::RingGroups` (r:1 w:0)
+	/// Proof: `Surveys::RingGroups` (`max_values`: None, `max_size`: Some(4242), added: 6717, mode: `MaxEncodedLen`)
+	/// Storage: `Surveys::TopicCount` (r:1 w:1)
+	/// Proof: `Surveys::TopicCount` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Surveys::Topics` (r:0 w:1)
+	/// Proof: `Surveys::Topics` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	fn create_topic() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `104`
+		//  Estimated: `7707`
+		// Minimum execution time: 32_062_000 picoseconds.
+		Weight::from_parts(47_660_000, 0)
+			.saturating_add(Weight::from_parts(0, 7707))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Surveys::Topics` (r:1 w:1)
+	/// Proof: `Surveys::Topics` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `Surveys::RingGroups` (r:1 w:0)
+	/// Proof: `Surveys::RingGroups` (`max_values`: None, `max_size`: Some(4242), added: 6717, mode: `MaxEncodedLen`)
+	/// Storage: `Surveys::PostWindows` (r:1 w:1)
+	/// Proof: `Surveys::PostWindows` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[2, 128]`.
+	fn post_message(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `194 + r * (33 ±0)`
+		//  Estimated: `7707`
+		// Minimum execution time: 456_818_000 picoseconds.
+		Weight::from_parts(1_069_273_700, 0)
+			.saturating_add(Weight::from_parts(0, 7707))
+			// Standard Error: 1_370_901
+			.saturating_add(Weight::from_parts(199_247_529, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Sudo::Key` (r:1 w:0)
+	/// Proof: `Sudo::Key` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `Surveys::RingGroups` (r:1 w:0)
+	/// Proof: `Surveys::RingGroups` (`max_values`: None, `max_size`: Some(4242), added: 6717, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::StatusFor` (r:1 w:0)
+	/// Proof: `Preimage::StatusFor` (`max_values`: None, `max_size`: Some(91), added: 2566, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::RequestStatusFor` (r:1 w:1)
+	/// Proof: `Preimage::RequestStatusFor` (`max_values`: None, `max_size`: Some(91), added: 2566, mode: `MaxEncodedLen`)
+	/// Storage: `Surveys::PetitionCount` (r:1 w:1)
+	/// Proof: `Surveys::PetitionCount` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Surveys::Petitions` (r:0 w:1)
+	/// Proof: `Surveys::Petitions` (`max_values`: None, `max_size`: Some(327), added: 2802, mode: `MaxEncodedLen`)
+	/// Storage: `Surveys::PetitionProposals` (r:0 w:1)
+	/// Proof: `Surveys::PetitionProposals` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	fn create_petition() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4539`
+		//  Estimated: `7707`
+		// Minimum execution time: 30_931_000 picoseconds.
+		Weight::from_parts(35_720_000, 0)
+			.saturating_add(Weight::from_parts(0, 7707))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `Surveys::Petitions` (r:1 w:1)
+	/// Proof: `Surveys::Petitions` (`max_values`: None, `max_size`: Some(327), added: 2802, mode: `MaxEncodedLen`)
+	/// Storage: `Surveys::RingGroups` (r:1 w:0)
+	/// Proof: `Surveys::RingGroups` (`max_values`: None, `max_size`: Some(4242), added: 6717, mode: `MaxEncodedLen`)
+	/// Storage: `Surveys::PetitionSigners` (r:1 w:1)
+	/// Proof: `Surveys::PetitionSigners` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `Surveys::PetitionProposals` (r:1 w:1)
+	/// Proof: `Surveys::PetitionProposals` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Agenda` (r:1 w:1)
+	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(398862), added: 401337, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::StatusFor` (r:1 w:0)
+	/// Proof: `Preimage::StatusFor` (`max_values`: None, `max_size`: Some(91), added: 2566, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::RequestStatusFor` (r:1 w:1)
+	/// Proof: `Preimage::RequestStatusFor` (`max_values`: None, `max_size`: Some(91), added: 2566, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[2, 128]`.
+	fn sign_petition(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `389 + r * (33 ±0)`
+		//  Estimated: `402327`
+		// Minimum execution time: 691_610_000 picoseconds.
+		Weight::from_parts(714_650_000, 0)
+			.saturating_add(Weight::from_parts(0, 402327))
+			// Standard Error: 10_053_265
+			.saturating_add(Weight::from_parts(1_020_180_000, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `Surveys::CommitteeKey` (r:0 w:1)
+	/// Proof: `Surveys::CommitteeKey` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	fn set_committee_key() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 16_422_000 picoseconds.
+		Weight::from_parts(17_109_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Surveys::CommitteeKey` (r:1 w:0)
+	/// Proof: `Surveys::CommitteeKey` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `Surveys::RingGroups` (r:1 w:0)
+	/// Proof: `Surveys::RingGroups` (`max_values`: None, `max_size`: Some(4242), added: 6717, mode: `MaxEncodedLen`)
+	/// Storage: `Surveys::UsedReplyKeys` (r:1 w:1)
+	/// Proof: `Surveys::UsedReplyKeys` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `Surveys::OpenReports` (r:1 w:1)
+	/// Proof: `Surveys::OpenReports` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Surveys::ReportCount` (r:1 w:1)
+	/// Proof: `Surveys::ReportCount` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Surveys::Reports` (r:0 w:1)
+	/// Proof: `Surveys::Reports` (`max_values`: None, `max_size`: Some(4270), added: 6745, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[2, 128]`.
+	fn submit_report(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `168 + r * (33 ±0)`
+		//  Estimated: `7707`
+		// Minimum execution time: 500_099_000 picoseconds.
+		Weight::from_parts(223_781_483, 0)
+			.saturating_add(Weight::from_parts(0, 7707))
+			// Standard Error: 1_393_997
+			.saturating_add(Weight::from_parts(216_021_501, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `Surveys::Reports` (r:1 w:1)
+	/// Proof: `Surveys::Reports` (`max_values`: None, `max_size`: Some(4270), added: 6745, mode: `MaxEncodedLen`)
+	/// Storage: `Surveys::OpenReports` (r:1 w:1)
+	/// Proof: `Surveys::OpenReports` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	fn acknowledge_report() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4342`
+		//  Estimated: `7735`
+		// Minimum execution time: 21_584_000 picoseconds.
+		Weight::from_parts(23_196_000, 0)
+			.saturating_add(Weight::from_parts(0, 7735))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Surveys::Polls` (r:1 w:1)
+	/// Proof: `Surveys::Polls` (`max_values`: None, `max_size`: Some(780), added: 3255, mode: `MaxEncodedLen`)
+	/// Storage: `Surveys::LegacyPolls` (r:1 w:1)
+	/// Proof: `Surveys::LegacyPolls` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `Surveys::BallotCounts` (r:1 w:0)
+	/// Proof: `Surveys::BallotCounts` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Surveys::EncryptedVotes` (r:1 w:1001)
+	/// Proof: `Surveys::EncryptedVotes` (`max_values`: None, `max_size`: Some(8595), added: 11070, mode: `MaxEncodedLen`)
+	/// Storage: `Surveys::PollRingId` (r:1 w:1)
+	/// Proof: `Surveys::PollRingId` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Surveys::RingGroups` (r:1 w:0)
+	/// Proof: `Surveys::RingGroups` (`max_values`: None, `max_size`: Some(4242), added: 6717, mode: `MaxEncodedLen`)
+	/// Storage: `Surveys::SpoiledBallots` (r:1 w:0)
+	/// Proof: `Surveys::SpoiledBallots` (`max_values`: None, `max_size`: Some(5018), added: 7493, mode: `MaxEncodedLen`)
+	/// Storage: `Surveys::PollProposals` (r:1 w:0)
+	/// Proof: `Surveys::PollProposals` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	/// Storage: `Surveys::PollVotes` (r:0 w:1)
+	/// Proof: `Surveys::PollVotes` (`max_values`: None, `max_size`: Some(1073), added: 3548, mode: `MaxEncodedLen`)
+	/// Storage: `Surveys::PollMetadata` (r:0 w:1)
+	/// Proof: `Surveys::PollMetadata` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Surveys::TallyCursors` (r:0 w:1)
+	/// Proof: `Surveys::TallyCursors` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Surveys::PollOutcomes` (r:0 w:1)
+	/// Proof: `Surveys::PollOutcomes` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 1000]`.
+	fn close_legacy_poll(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4689 + n * (8567 ±0)`
+		//  Estimated: `12060`
+		// Minimum execution time: 92_821_000 picoseconds.
+		Weight::from_parts(649_146_950, 0)
+			.saturating_add(Weight::from_parts(0, 12060))
+			// Standard Error: 617_979
+			.saturating_add(Weight::from_parts(40_774_382, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(8))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `Surveys::LegacyPolls` (r:1 w:0)
+	/// Proof: `Surveys::LegacyPolls` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `Surveys::EncryptedVotes` (r:1 w:1000)
+	/// Proof: `Surveys::EncryptedVotes` (`max_values`: None, `max_size`: Some(8595), added: 11070, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 1000]`.
+	fn migrate_legacy_ballots(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `51 + n * (8567 ±0)`
+		//  Estimated: `12060`
+		// Minimum execution time: 38_669_000 picoseconds.
+		Weight::from_parts(235_682_033, 0)
+			.saturating_add(Weight::from_parts(0, 12060))
+			// Standard Error: 558_635
+			.saturating_add(Weight::from_parts(30_528_493, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+}